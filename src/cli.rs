@@ -244,6 +244,18 @@ pub enum Commands {
         /// Prompt for a short insight to save to bd memories
         #[arg(long)]
         remember: bool,
+
+        /// Author of the handoff (default: $WAI_AGENT, then git user.name)
+        #[arg(long)]
+        author: Option<String>,
+
+        /// Session ID the handoff belongs to (default: $WAI_SESSION_ID)
+        #[arg(long)]
+        session: Option<String>,
+
+        /// Run `wai reflect` for the project after writing the handoff
+        #[arg(long)]
+        reflect: bool,
     },
 
     /// Orient yourself at session start: project, phase, last handoff, and suggested next step
@@ -251,6 +263,10 @@ pub enum Commands {
         /// Project name (auto-detected when only one project exists)
         #[arg(short, long)]
         project: Option<String>,
+
+        /// Assemble a ranked context pack that fits in this many tokens
+        #[arg(long, value_name = "TOKENS")]
+        budget: Option<usize>,
     },
 
     /// Manage project context
@@ -280,6 +296,22 @@ pub enum Commands {
         /// Stop scanning after this many seconds and show results found so far (default: 10)
         #[arg(short, long, default_value_t = 10)]
         timeout: u64,
+
+        /// Also show last handoff, pipeline step, stale artifacts, health and reflect debt
+        #[arg(long)]
+        detail: bool,
+
+        /// Sort projects by this column (default: name)
+        #[arg(long, value_enum, default_value_t = LsSort::Name)]
+        sort: LsSort,
+
+        /// Only show projects in these phases (comma-separated)
+        #[arg(long, value_name = "PHASE", value_delimiter = ',')]
+        phase: Vec<String>,
+
+        /// Only show projects with stale artifacts
+        #[arg(long)]
+        stale: bool,
     },
 
     /// Ask why a decision was made (LLM-powered reasoning oracle)
@@ -350,7 +382,8 @@ pub enum Commands {
               both       — Write to both CLAUDE.md and AGENTS.md\n\
               (default: whichever target files already exist in the repo root)\n\n\
             CONTEXT SOURCES (ranked by richness)\n\
-              1. Conversation transcript (--conversation <file>) — raw session detail\n\
+              1. Conversation transcript (--conversation <file>) — raw session detail;\n\
+                 otherwise recent local agent traces (see `wai trace list`)\n\
               2. Handoff artifacts — session summaries and next steps\n\
               3. Research/design/plan artifacts — curated decisions\n\n\
             Reuses the [llm] config from .wai/config.toml — no separate setup."
@@ -364,6 +397,10 @@ pub enum Commands {
         #[arg(short, long, value_name = "FILE")]
         conversation: Option<PathBuf>,
 
+        /// Don't auto-select local agent traces when no transcript is given
+        #[arg(long)]
+        no_traces: bool,
+
         /// Output target: claude.md, agents.md, or both (default: auto-detect)
         #[arg(short, long, value_name = "TARGET")]
         output: Option<String>,
@@ -385,6 +422,11 @@ pub enum Commands {
         /// Store top-level bullet points from the generated reflection as bd memories
         #[arg(long)]
         save_memories: bool,
+
+        /// Reject proposed bullet changes by ID (from the review list or --json);
+        /// all other changes are accepted. Rejections are remembered.
+        #[arg(long, value_name = "ID", value_delimiter = ',')]
+        reject: Vec<usize>,
     },
 
     /// File an issue against wai's upstream repo, with context attached.
@@ -469,6 +511,26 @@ pub enum Commands {
     #[command(subcommand)]
     Artifacts(ArtifactsCommands),
 
+    /// Discover and import local agent session traces
+    #[command(
+        about = "Discover and import local agent session traces",
+        long_about = "Finds session logs recorded for this repository by local agent tools and\n\
+            normalizes them into turns and tool calls.\n\n\
+            SOURCES\n\
+              claude-code  ~/.claude/projects/<encoded-path>/*.jsonl   (full-transcript)\n\
+              codex        ~/.codex/sessions/**/rollout-*.jsonl       (full or partial)\n\
+              gemini       ~/.gemini/tmp/<project-hash>/              (full or partial)\n\
+              amp          ~/.amp/file-changes/<thread>/              (diff-only)\n\n\
+            EXAMPLES\n\
+              wai trace list\n\
+              wai trace show claude-code:0b7c\n\
+              wai trace import --latest\n\n\
+            ENVIRONMENT\n\
+              WAI_TRACE_HOME  Resolve every tool store under this directory instead of $HOME"
+    )]
+    #[command(subcommand)]
+    Trace(TraceCommands),
+
     /// Generate shell completions
     Completions {
         /// Shell to generate completions for (bash, zsh, fish, powershell, elvish)
@@ -480,6 +542,35 @@ pub enum Commands {
     External(Vec<String>),
 }
 
+#[derive(Subcommand)]
+pub enum TraceCommands {
+    /// List traces recorded for this repository, newest first
+    List {
+        /// Only show traces from this tool (claude-code, codex, gemini, amp)
+        #[arg(long)]
+        source: Option<String>,
+
+        /// Maximum number of traces to show
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Print a normalized trace
+    Show {
+        /// Trace ID (`<source>:<session>`) or a unique session-ID prefix
+        id: String,
+    },
+    /// Import a trace into .wai/resources/traces/
+    Import {
+        /// Trace ID (`<source>:<session>`) or a unique session-ID prefix
+        #[arg(required_unless_present = "latest", conflicts_with = "latest")]
+        id: Option<String>,
+
+        /// Import the most recent trace for this repository
+        #[arg(long)]
+        latest: bool,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum ArtifactsCommands {
     /// Report stale and untracked decision artifacts
@@ -492,6 +583,24 @@ pub enum ArtifactsCommands {
 
 /// Kind of feedback an agent or human can file via `wai feedback`.
 ///
+/// Column `wai ls` sorts by. Everything except `name` puts the projects that
+/// most need attention first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LsSort {
+    /// Project name, A–Z.
+    Name,
+    /// Workflow phase, research first.
+    Phase,
+    /// Most stale artifacts first.
+    Stale,
+    /// Oldest last handoff first.
+    Handoff,
+    /// Most doctor failures, then warnings, first.
+    Health,
+    /// Most handoffs since the last reflection first.
+    Debt,
+}
+
 /// The verb is `feedback` (not `report`) — `report` is reserved in pretender
 /// and espectacular. Each kind maps to a label set applied to the filed issue.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
@@ -786,6 +895,22 @@ pub enum HandoffCommands {
     Create {
         /// Project name
         project: String,
+
+        /// Pre-fill sections from git activity, pipeline state, beads and reviews
+        #[arg(long)]
+        draft: bool,
+
+        /// Summarize the drafted work with the configured LLM (implies --draft)
+        #[arg(long)]
+        summarize: bool,
+
+        /// Author of the handoff (default: $WAI_AGENT, then git user.name)
+        #[arg(long)]
+        author: Option<String>,
+
+        /// Session ID the handoff belongs to (default: $WAI_SESSION_ID)
+        #[arg(long)]
+        session: Option<String>,
     },
 }
 
@@ -823,7 +948,7 @@ pub enum PluginCommands {
         #[arg(long, value_name = "DIGEST", conflicts_with = "hook")]
         revoke: Option<String>,
 
-        /// Approve only this specific hook (e.g. "on_status")
+        /// Approve only this specific hook or check (e.g. "on_status", "check:codeowners")
         #[arg(long, requires = "name")]
         hook: Option<String>,
     },
@@ -855,9 +980,49 @@ pub enum ResourceCommands {
 
     /// Export skills to a tar.gz archive for sharing
     ///
+    /// Archives embed a SHA-256 manifest of their contents. With --sign the
+    /// manifest is also signed with your ed25519 key (created on first use).
+    ///
     /// EXAMPLES
     ///   wai resource export issue/gather impl/run --output skills.tar.gz
+    ///   wai resource export issue/gather --output skills.tar.gz --sign
     Export(ResourceExportArgs),
+
+    /// Check skills for common mistakes and run golden projection tests
+    ///
+    /// Validates SKILL.md frontmatter, description length, `wai` command
+    /// references, linked files, and pipeline `[pipeline.metadata] skills`
+    /// entries. Golden fixtures in `.wai/resources/agent-config/skill-fixtures/
+    /// <skill>/<target>.md` are compared against a fresh render of each
+    /// projection target (agents, claude-code).
+    ///
+    /// EXAMPLES
+    ///   wai resource lint
+    ///   wai resource lint issue/gather --json
+    ///   wai resource lint issue/gather --update-golden
+    Lint(ResourceLintArgs),
+
+    /// Manage public keys trusted to sign skill archives
+    ///
+    /// Trust a signer:      wai resource trust <PUBLIC_KEY> --label <name>
+    /// List trusted keys:   wai resource trust --list
+    /// Revoke a key:        wai resource trust --revoke <PUBLIC_KEY>
+    Trust {
+        /// Hex-encoded ed25519 public key to trust (printed by `wai resource export --sign`)
+        public_key: Option<String>,
+
+        /// Human-readable name for the key (e.g. a team or person)
+        #[arg(long, requires = "public_key")]
+        label: Option<String>,
+
+        /// List all trusted signing keys
+        #[arg(long, conflicts_with_all = ["public_key", "revoke"])]
+        list: bool,
+
+        /// Stop trusting a signing key
+        #[arg(long, value_name = "PUBLIC_KEY", conflicts_with = "public_key")]
+        revoke: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -907,9 +1072,14 @@ pub enum ResourceImportCommands {
 
     /// Import skills from a tar.gz archive
     ///
+    /// The archive manifest is verified before anything is written: tampered
+    /// archives are always rejected. Unsigned archives, or archives signed by a
+    /// key not trusted via `wai resource trust`, require confirmation.
+    ///
     /// EXAMPLES
     ///   wai resource import archive skills.tar.gz
     ///   wai resource import archive skills.tar.gz --yes
+    ///   wai resource import archive legacy.tar.gz --allow-untrusted
     Archive {
         /// Path to the tar.gz archive to import
         file: String,
@@ -917,6 +1087,10 @@ pub enum ResourceImportCommands {
         /// Overwrite existing skills without prompting
         #[arg(long)]
         yes: bool,
+
+        /// Import unsigned archives or archives signed by an untrusted key without prompting
+        #[arg(long)]
+        allow_untrusted: bool,
     },
 }
 
@@ -948,6 +1122,25 @@ pub struct ResourceExportArgs {
     /// Output archive file path (e.g. skills.tar.gz)
     #[arg(long, value_name = "FILE")]
     pub output: String,
+
+    /// Sign the archive manifest with your ed25519 signing key
+    #[arg(long)]
+    pub sign: bool,
+}
+
+#[derive(Args)]
+pub struct ResourceLintArgs {
+    /// Skills to lint (default: all skills in the current project)
+    #[arg(value_name = "SKILL")]
+    pub skills: Vec<String>,
+
+    /// Write golden fixtures from the current projections instead of comparing
+    #[arg(long)]
+    pub update_golden: bool,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
 }
 
 #[derive(Subcommand)]
@@ -6,26 +6,32 @@ use crate::context::{current_context, require_safe_mode};
 use crate::plugin;
 use crate::plugin::{detect_main_worktree_root, store_memory};
 
-use super::handoff::create_handoff;
-use super::reflect::{count_handoffs_since, read_reflect_meta};
+use super::handoff::session::record_pending_resume;
+use super::handoff::{SessionIdentity, create_handoff};
+use super::reflect::{self, ReflectArgs, reflect_debt};
 use super::{require_project, resolve_project};
 
-pub fn run(project: Option<String>, remember: bool) -> Result<()> {
+pub fn run(
+    project: Option<String>,
+    remember: bool,
+    author: Option<String>,
+    session: Option<String>,
+    reflect: bool,
+) -> Result<()> {
     let project_root = require_project()?;
     require_safe_mode("create handoff")?;
 
     let resolved = resolve_project(&project_root, project.as_deref())?;
     let project_name = resolved.name;
 
-    let handoff_path = create_handoff(&project_root, &project_name)?;
+    let identity = SessionIdentity::resolve(&project_root, author.as_deref(), session.as_deref());
+    let handoff_path = create_handoff(&project_root, &project_name, &identity)?;
 
-    // Write .pending-resume signal so wai prime can detect a mid-task resume
+    // Record this session in .pending-resume so wai prime can detect a mid-task
+    // resume. Other sessions' entries are kept.
     let proj_dir = projects_dir(&project_root).join(&project_name);
     if let Ok(relative) = handoff_path.strip_prefix(&proj_dir) {
-        let _ = std::fs::write(
-            proj_dir.join(".pending-resume"),
-            relative.to_string_lossy().as_bytes(),
-        );
+        let _ = record_pending_resume(&proj_dir, relative);
     }
 
     // If the active pipeline run is complete, clear its pointers so `wai status`/
@@ -89,17 +95,11 @@ pub fn run(project: Option<String>, remember: bool) -> Result<()> {
             );
         }
 
-        // 5.1–5.3: Reflect nudge — show if 5+ handoffs since last reflect.
-        if !context.json {
-            let proj_dir = projects_dir(&project_root).join(&project_name);
-            let handoffs_dir = proj_dir.join("handoffs");
-            let meta = read_reflect_meta(&proj_dir).unwrap_or(None);
-            let last_reflected = meta
-                .as_ref()
-                .map(|m| m.last_reflected.as_str())
-                .unwrap_or("");
-            let session_count = count_handoffs_since(&handoffs_dir, last_reflected);
-            if session_count >= 5 {
+        // Reflect nudge — show once the project's [reflect] policy says a
+        // reflection is due, unless --reflect is about to run one.
+        if !context.json && !reflect {
+            let debt = reflect_debt(&project_root, &project_name);
+            if debt.is_due() {
                 // Determine which target files exist.
                 let has_claude = project_root.join("CLAUDE.md").exists();
                 let has_agents = project_root.join("AGENTS.md").exists();
@@ -110,8 +110,10 @@ pub fn run(project: Option<String>, remember: bool) -> Result<()> {
                     (false, false) => "CLAUDE.md",
                 };
                 println!(
-                    "→ {} sessions since last reflect — run `wai reflect` to update {}",
-                    session_count, target_hint
+                    "→ {} sessions since last reflect — run `wai reflect` to update {} ({})",
+                    debt.handoffs,
+                    target_hint,
+                    debt.reason()
                 );
             }
         }
@@ -138,6 +140,23 @@ pub fn run(project: Option<String>, remember: bool) -> Result<()> {
         }
     }
 
+    // --reflect: fold the session (and everything since the last reflection)
+    // into the project's reflection right after the handoff is written.
+    if reflect {
+        reflect::run(ReflectArgs {
+            project: Some(project_name),
+            conversation: None,
+            no_traces: false,
+            output: None,
+            dry_run: false,
+            yes: context.yes,
+            inject_content: None,
+            verbose: 0,
+            save_memories: false,
+            reject: Vec::new(),
+        })?;
+    }
+
     Ok(())
 }
 
@@ -255,6 +255,54 @@ pub(super) fn check_version(project_root: &Path) -> WaiCheckEntry {
     }
 }
 
+/// Doctor checks contributed by JSON-protocol `on_doctor` plugin hooks.
+pub(super) fn check_plugin_hook_checks(project_root: &Path) -> Vec<WaiCheckEntry> {
+    plugin::run_hooks(project_root, "on_doctor")
+        .into_iter()
+        .filter_map(|output| output.data.map(|data| (output.plugin, data)))
+        .flat_map(|(plugin_name, data)| {
+            data.doctor_checks
+                .into_iter()
+                .map(move |check| WaiCheckEntry {
+                    name: format!("{}: {}", plugin_name, check.name),
+                    status: match check.status {
+                        plugin::HookCheckStatus::Pass => CheckStatus::Pass,
+                        plugin::HookCheckStatus::Warn => CheckStatus::Warn,
+                        plugin::HookCheckStatus::Fail => CheckStatus::Fail,
+                    },
+                    message: check.message,
+                    fix: check.fix,
+                    fix_fn: None,
+                })
+        })
+        .collect()
+}
+
+/// Checks declared under `[[checks]]` (kind = "doctor") by custom plugins.
+pub(super) fn check_plugin_checks(project_root: &Path) -> Vec<WaiCheckEntry> {
+    plugin::run_plugin_checks(project_root, plugin::PluginCheckKind::Doctor)
+        .into_iter()
+        .map(|result| {
+            let fix_fn = result.fix.clone().map(|fix| {
+                Box::new(move |project_root: &Path| {
+                    plugin::run_check_fix(project_root, &fix).map_err(|e| miette::miette!(e))
+                }) as Box<dyn FnOnce(&Path) -> miette::Result<()>>
+            });
+            WaiCheckEntry {
+                name: format!("{}: {}", result.plugin, result.name),
+                status: match result.status {
+                    plugin::HookCheckStatus::Pass => CheckStatus::Pass,
+                    plugin::HookCheckStatus::Warn => CheckStatus::Warn,
+                    plugin::HookCheckStatus::Fail => CheckStatus::Fail,
+                },
+                message: result.message,
+                fix: result.fix.map(|fix| format!("Run `{}`", fix)),
+                fix_fn,
+            }
+        })
+        .collect()
+}
+
 pub(super) fn check_plugin_tools(project_root: &Path) -> Vec<WaiCheckEntry> {
     let plugins = plugin::detect_plugins(project_root);
     let mut results = Vec::new();
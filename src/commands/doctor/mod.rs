@@ -125,6 +125,16 @@ pub(crate) fn build_doctor_runner() -> genesis::doctor::DoctorRunner {
             description: "Check custom plugin definitions",
             func: |root| checks_basic::check_custom_plugins(root),
         }),
+        Box::new(WaiCheckAdapter {
+            name: "plugin-hook-checks",
+            description: "Run checks contributed by plugin on_doctor hooks",
+            func: |root| checks_basic::check_plugin_hook_checks(root),
+        }),
+        Box::new(WaiCheckAdapter {
+            name: "plugin-checks",
+            description: "Run checks declared by custom plugins",
+            func: |root| checks_basic::check_plugin_checks(root),
+        }),
         Box::new(WaiCheckAdapter {
             name: "agent-instructions",
             description: "Check that AGENTS.md / CLAUDE.md contain required managed blocks",
@@ -180,6 +190,11 @@ pub(crate) fn build_doctor_runner() -> genesis::doctor::DoctorRunner {
             description: "Check pipeline run utilization",
             func: |root| check_pipeline_utilization(root),
         }),
+        Box::new(WaiCheckAdapter {
+            name: "reflect-cadence",
+            description: "Check reflection debt against the [reflect] policy",
+            func: |root| check_reflect_cadence(root),
+        }),
         Box::new(WaiCheckAdapter {
             name: "pi-session-hook",
             description: "Check Pi agent session hook",
@@ -205,6 +220,8 @@ fn collect_checks(project_root: &Path) -> Vec<WaiCheckEntry> {
     checks.extend(check_agent_tool_coverage(project_root));
     checks.extend(checks_basic::check_project_state(project_root));
     checks.extend(checks_basic::check_custom_plugins(project_root));
+    checks.extend(checks_basic::check_plugin_hook_checks(project_root));
+    checks.extend(checks_basic::check_plugin_checks(project_root));
     checks.extend(check_agent_instructions(project_root));
     checks.extend(check_managed_block_staleness(project_root));
     checks.extend(check_pipeline_definitions(project_root));
@@ -218,6 +235,7 @@ fn collect_checks(project_root: &Path) -> Vec<WaiCheckEntry> {
     checks.extend(check_artifact_locks(project_root));
     checks.extend(check_dont_drift_signals(project_root));
     checks.extend(check_pipeline_utilization(project_root));
+    checks.extend(check_reflect_cadence(project_root));
     checks.extend(check_pi_session_hook(project_root));
     checks
 }
@@ -1036,7 +1054,8 @@ fn check_agent_instructions(project_root: &Path) -> Vec<WaiCheckEntry> {
 /// Check managed block staleness by comparing generated vs actual content.
 fn check_managed_block_staleness(project_root: &Path) -> Vec<WaiCheckEntry> {
     use crate::managed_block::{
-        inject_managed_block, read_managed_block, wai_block_content, wai_detailed_content,
+        BlockOptions, DETAILED_TARGET, inject_managed_block, inject_target_block, managed_targets,
+        read_target_block, target_block_content, wai_detailed_content,
     };
     use crate::workspace::{detect_installed_pipelines, detect_installed_skill_names};
 
@@ -1059,46 +1078,58 @@ fn check_managed_block_staleness(project_root: &Path) -> Vec<WaiCheckEntry> {
 
     let project_root = project_root.to_path_buf();
 
-    // Check root CLAUDE.md / AGENTS.md against slim block
-    let expected = wai_block_content(
-        &project_root,
-        &plugin_names,
-        &skill_name_refs,
-        &installed_pipelines,
-    );
-
-    for filename in &["CLAUDE.md", "AGENTS.md"] {
-        let path = project_root.join(filename);
-        if let Some(actual) = read_managed_block(&path)
-            && actual != expected
-        {
-            let fname = filename.to_string();
-            let owned_plugins = owned_plugins.clone();
-            let owned_skills = owned_skills.clone();
-            let owned_pipelines = owned_pipelines.clone();
-            results.push(WaiCheckEntry {
-                name: format!("Managed block staleness: {}", filename),
-                status: CheckStatus::Warn,
-                message: format!(
-                    "{} managed block outdated — run 'wai init' to refresh",
-                    filename
-                ),
-                fix: Some("Run: wai init".to_string()),
-                fix_fn: Some(Box::new(move |root| {
-                    let root = root.to_path_buf();
-                    let plugin_refs: Vec<&str> = owned_plugins.iter().map(|s| s.as_str()).collect();
-                    let skill_refs: Vec<&str> = owned_skills.iter().map(|s| s.as_str()).collect();
-                    inject_managed_block(
-                        &root.join(&fname),
-                        &plugin_refs,
-                        &skill_refs,
-                        &owned_pipelines,
-                    )
+    // Check CLAUDE.md / AGENTS.md and configured targets against the block
+    // configured for each. Root instruction files are only checked once they
+    // carry a block; configured targets must have one.
+    for target in managed_targets(&project_root) {
+        let required = !matches!(target.as_str(), "CLAUDE.md" | "AGENTS.md");
+        let expected = match target_block_content(
+            &project_root,
+            &target,
+            &plugin_names,
+            &skill_name_refs,
+            &installed_pipelines,
+        ) {
+            Ok(expected) => expected,
+            Err(e) => {
+                results.push(WaiCheckEntry {
+                    name: format!("Managed block staleness: {}", target),
+                    status: CheckStatus::Fail,
+                    message: format!("Cannot render managed block for {}: {}", target, e),
+                    fix: Some("Fix the source under [managed_block.targets]".to_string()),
+                    fix_fn: None,
+                });
+                continue;
+            }
+        };
+        let message = match read_target_block(&project_root, &target) {
+            Some(actual) if actual == expected => continue,
+            Some(_) => format!(
+                "{} managed block outdated — run 'wai init' to refresh",
+                target
+            ),
+            None if required => format!(
+                "{} managed block missing — run 'wai init' to add it",
+                target
+            ),
+            None => continue,
+        };
+        let owned_plugins = owned_plugins.clone();
+        let owned_skills = owned_skills.clone();
+        let owned_pipelines = owned_pipelines.clone();
+        results.push(WaiCheckEntry {
+            name: format!("Managed block staleness: {}", target),
+            status: CheckStatus::Warn,
+            message,
+            fix: Some("Run: wai init".to_string()),
+            fix_fn: Some(Box::new(move |root| {
+                let plugin_refs: Vec<&str> = owned_plugins.iter().map(|s| s.as_str()).collect();
+                let skill_refs: Vec<&str> = owned_skills.iter().map(|s| s.as_str()).collect();
+                inject_target_block(root, &target, &plugin_refs, &skill_refs, &owned_pipelines)
                     .map(|_| ())
-                    .map_err(|e| miette::miette!("Failed to fix {}: {}", fname, e))
-                })),
-            });
-        }
+                    .map_err(|e| miette::miette!("Failed to fix {}: {}", target, e))
+            })),
+        });
     }
 
     // Check .wai/AGENTS.md against detailed content
@@ -1109,6 +1140,7 @@ fn check_managed_block_staleness(project_root: &Path) -> Vec<WaiCheckEntry> {
             &plugin_names,
             &skill_name_refs,
             &installed_pipelines,
+            &BlockOptions::for_target(&project_root, DETAILED_TARGET),
         );
         if let Ok(actual_detailed) = std::fs::read_to_string(&detailed_path)
             && actual_detailed != expected_detailed
@@ -1537,6 +1569,48 @@ fn check_pipeline_utilization(project_root: &Path) -> Vec<WaiCheckEntry> {
     }]
 }
 
+/// Check each project's reflection debt against its `[reflect]` policy.
+///
+/// Warns for every project whose handoff or day limit has been reached.
+/// There is no automatic fix: reflecting needs an LLM (or an agent) and a
+/// review of the result, so the fix text points at `wai reflect`.
+fn check_reflect_cadence(project_root: &Path) -> Vec<WaiCheckEntry> {
+    let debts = super::reflect::workspace_debts(project_root);
+    if debts.is_empty() {
+        return vec![];
+    }
+
+    let due: Vec<WaiCheckEntry> = debts
+        .iter()
+        .filter(|debt| debt.is_due())
+        .map(|debt| WaiCheckEntry {
+            name: "Reflection cadence".to_string(),
+            status: CheckStatus::Warn,
+            message: format!("Reflection due for '{}': {}", debt.project, debt.reason()),
+            fix: Some(format!(
+                "Run 'wai reflect -p {}' (or 'wai close --reflect' at the end of the next session)",
+                debt.project
+            )),
+            fix_fn: None,
+        })
+        .collect();
+
+    if due.is_empty() {
+        vec![WaiCheckEntry {
+            name: "Reflection cadence".to_string(),
+            status: CheckStatus::Pass,
+            message: format!(
+                "All {} project(s) within their reflection policy",
+                debts.len()
+            ),
+            fix: None,
+            fix_fn: None,
+        }]
+    } else {
+        due
+    }
+}
+
 // ─── pi session hook ─────────────────────────────────────────────────────────
 
 /// Recommended fix text: a minimal pi extension that runs `wai prime` on
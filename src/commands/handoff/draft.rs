@@ -0,0 +1,498 @@
+//! Pre-filled handoff sections for `wai handoff create --draft`.
+//!
+//! Everything here is best-effort: a missing git repo, pipeline, `bd` binary
+//! or review directory simply leaves the corresponding section empty so the
+//! handoff falls back to its placeholder.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Command;
+
+use chrono::{DateTime, Utc};
+use cliclack::log;
+use miette::{IntoDiagnostic, Result};
+use serde::Deserialize;
+
+use crate::commands::pipeline::pipeline_current_status;
+use crate::config::{HANDOFFS_DIR, ProjectConfig, REVIEWS_DIR};
+use crate::context::current_context;
+use crate::llm::{AGENT_SENTINEL, LlmClient, LlmError, detect_backend};
+use crate::plugin;
+use crate::trace;
+
+/// Comment placed at the top of every drafted section.
+pub(super) const DRAFT_MARKER: &str =
+    "<!-- Drafted by wai from git, pipeline, beads and review state. Edit freely. -->";
+
+/// Commit subjects listed when there is no previous handoff to anchor on.
+const FIRST_HANDOFF_COMMITS: usize = 10;
+/// Upper bound on commit subjects listed since the previous handoff.
+const MAX_COMMITS: usize = 50;
+const MAX_TRACES: usize = 5;
+const MAX_READY_ISSUES: usize = 5;
+/// Finding lines quoted from each unresolved review.
+const MAX_FINDINGS_PER_REVIEW: usize = 3;
+
+/// Section content gathered for a drafted handoff.
+#[derive(Debug, Default)]
+pub struct HandoffDraft {
+    /// Commit subjects and agent sessions since the previous handoff.
+    pub done: Vec<String>,
+    /// LLM-written summary that replaces `done` when present.
+    pub summary: Option<String>,
+    /// `git diff --stat` output since the previous handoff.
+    pub diffstat: Option<String>,
+    pub next_steps: Vec<String>,
+    pub open_questions: Vec<String>,
+}
+
+impl HandoffDraft {
+    pub fn render_done(&self) -> Option<String> {
+        let mut out = match &self.summary {
+            Some(summary) => format!("{}\n", summary.trim_end()),
+            None => bullets(&self.done),
+        };
+        if let Some(stat) = &self.diffstat {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("```\n{}\n```\n", stat.trim_end()));
+        }
+        (!out.is_empty()).then(|| format!("{}\n", out))
+    }
+
+    pub fn render_next_steps(&self) -> Option<String> {
+        let out = numbered(&self.next_steps);
+        (!out.is_empty()).then(|| format!("{}\n", out))
+    }
+
+    pub fn render_open_questions(&self) -> Option<String> {
+        let out = bullets(&self.open_questions);
+        (!out.is_empty()).then(|| format!("{}\n", out))
+    }
+}
+
+fn bullets(items: &[String]) -> String {
+    items.iter().map(|i| format!("- {}\n", i)).collect()
+}
+
+fn numbered(items: &[String]) -> String {
+    items
+        .iter()
+        .enumerate()
+        .map(|(n, i)| format!("{}. {}\n", n + 1, i))
+        .collect()
+}
+
+/// Collect draft content for `project`, anchored on the previous handoff
+/// other than `replacing` (the file this draft will overwrite).
+pub fn gather_draft(project_root: &Path, proj_dir: &Path, replacing: &str) -> HandoffDraft {
+    let since = previous_handoff_time(&proj_dir.join(HANDOFFS_DIR), replacing);
+
+    let mut done = git_commits_since(project_root, since);
+    done.extend(traces_since(project_root, since));
+
+    let mut next_steps = pipeline_next_steps(project_root);
+    next_steps.extend(
+        plugin::fetch_ready_issues(project_root, MAX_READY_ISSUES)
+            .into_iter()
+            .map(|(id, title)| match title.is_empty() {
+                true => format!("`{}`", id),
+                false => format!("`{}` {}", id, title),
+            }),
+    );
+
+    HandoffDraft {
+        done,
+        summary: None,
+        diffstat: since.and_then(|s| git_diffstat_since(project_root, s)),
+        next_steps,
+        open_questions: unresolved_reviews(&proj_dir.join(REVIEWS_DIR)),
+    }
+}
+
+/// Modification time of the newest handoff other than `exclude` (the file
+/// about to be overwritten).
+fn previous_handoff_time(handoffs_dir: &Path, exclude: &str) -> Option<DateTime<Utc>> {
+    std::fs::read_dir(handoffs_dir)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            name.ends_with(".md") && name != exclude
+        })
+        .filter_map(|e| e.metadata().ok()?.modified().ok())
+        .max()
+        .map(DateTime::<Utc>::from)
+}
+
+fn git_output(project_root: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(project_root)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+fn git_commits_since(project_root: &Path, since: Option<DateTime<Utc>>) -> Vec<String> {
+    let since_arg = since.map(|s| format!("--since={}", s.to_rfc3339()));
+    let limit = match since {
+        Some(_) => MAX_COMMITS,
+        None => FIRST_HANDOFF_COMMITS,
+    }
+    .to_string();
+    let mut args = vec!["log", "--no-merges", "--pretty=format:%h %s", "-n", &limit];
+    if let Some(arg) = &since_arg {
+        args.push(arg);
+    }
+    git_output(project_root, &args)
+        .map(|out| {
+            out.lines()
+                .filter_map(|line| line.split_once(' '))
+                .map(|(hash, subject)| format!("{} (`{}`)", subject, hash))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Diffstat from the last commit before `since` to the working tree, so
+/// uncommitted work is included.
+fn git_diffstat_since(project_root: &Path, since: DateTime<Utc>) -> Option<String> {
+    let before = format!("--before={}", since.to_rfc3339());
+    let base = git_output(project_root, &["rev-list", "-1", &before, "HEAD"])?;
+    git_output(
+        project_root,
+        &["diff", "--stat=100", "--stat-count=20", base.trim()],
+    )
+}
+
+fn traces_since(project_root: &Path, since: Option<DateTime<Utc>>) -> Vec<String> {
+    trace::discover_traces(project_root)
+        .iter()
+        .filter(|t| match (since, t.ended()) {
+            (Some(since), Some(ended)) => ended >= since,
+            (Some(_), None) => false,
+            (None, _) => true,
+        })
+        .take(MAX_TRACES)
+        .map(|t| {
+            let title = t
+                .title()
+                .map(|title| format!(" — {}", title))
+                .unwrap_or_default();
+            format!(
+                "Agent session `{}`{} ({} turns, {} tool calls)",
+                t.id(),
+                title,
+                t.turns.len(),
+                t.tool_call_count()
+            )
+        })
+        .collect()
+}
+
+fn pipeline_next_steps(project_root: &Path) -> Vec<String> {
+    let Some(status) = pipeline_current_status(project_root).ok().flatten() else {
+        return Vec::new();
+    };
+    let Some(step) = status.step.filter(|_| status.active) else {
+        return Vec::new();
+    };
+    let prompt = step.prompt.lines().next().unwrap_or_default().trim();
+    let mut line = format!(
+        "Pipeline `{}` step {}/{} `{}`",
+        status.pipeline.unwrap_or_default(),
+        step.index,
+        step.total,
+        step.id
+    );
+    if !prompt.is_empty() {
+        line.push_str(&format!(": {}", prompt));
+    }
+    if let Some(cmd) = status.next_command {
+        line.push_str(&format!(" (then `{}`)", cmd));
+    }
+    vec![line]
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ReviewMeta {
+    reviews: Option<String>,
+    verdict: Option<String>,
+    #[serde(default)]
+    severity: BTreeMap<String, u32>,
+}
+
+impl ReviewMeta {
+    fn count(&self, level: &str) -> u32 {
+        self.severity.get(level).copied().unwrap_or(0)
+    }
+
+    fn is_unresolved(&self) -> bool {
+        matches!(self.verdict.as_deref(), Some("fail") | Some("needs-work"))
+            || self.count("critical") > 0
+            || self.count("high") > 0
+    }
+}
+
+fn parse_review(content: &str) -> Option<(ReviewMeta, String)> {
+    let rest = content.trim_start().strip_prefix("---")?;
+    let (fm, body) = rest.split_once("\n---")?;
+    let meta = serde_yml::from_str(fm).ok()?;
+    Some((meta, body.trim().to_string()))
+}
+
+/// Reviews whose latest verdict for a target still has open findings.
+///
+/// Reviews are named with a date prefix, so a later passing review of the same
+/// target supersedes an earlier failing one.
+fn unresolved_reviews(reviews_dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(reviews_dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<_> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().and_then(|x| x.to_str()) == Some("md"))
+        .collect();
+    files.sort();
+
+    let mut latest: BTreeMap<String, (ReviewMeta, String)> = BTreeMap::new();
+    for path in files {
+        let Some((meta, body)) = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|c| parse_review(&c))
+        else {
+            continue;
+        };
+        let target = meta.reviews.clone().unwrap_or_else(|| {
+            path.file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string()
+        });
+        latest.insert(target, (meta, body));
+    }
+
+    latest
+        .into_iter()
+        .filter(|(_, (meta, _))| meta.is_unresolved())
+        .map(|(target, (meta, body))| {
+            let mut detail = Vec::new();
+            if let Some(verdict) = &meta.verdict {
+                detail.push(verdict.clone());
+            }
+            for level in ["critical", "high"] {
+                if meta.count(level) > 0 {
+                    detail.push(format!("{} {}", meta.count(level), level));
+                }
+            }
+            let mut item = format!(
+                "Review of `{}` is unresolved ({})",
+                target,
+                detail.join(", ")
+            );
+            for finding in body
+                .lines()
+                .filter_map(|l| l.strip_prefix("- ").or_else(|| l.strip_prefix("* ")))
+                .take(MAX_FINDINGS_PER_REVIEW)
+            {
+                item.push_str(&format!("\n  - {}", finding.trim()));
+            }
+            item
+        })
+        .collect()
+}
+
+// ── LLM summarization ─────────────────────────────────────────────────────────
+
+fn summary_prompt(project: &str, draft: &HandoffDraft) -> String {
+    let mut prompt = format!(
+        "You are writing the \"What Was Done\" section of a session handoff for the \
+         project '{}'. Summarize the work below as 3-6 concise markdown bullets \
+         grouped by theme. Mention concrete components, not commit hashes. \
+         Do not invent work that is not listed. Reply with the bullets only.\n\n\
+         # Commits and sessions\n\n{}",
+        project,
+        bullets(&draft.done)
+    );
+    if let Some(stat) = &draft.diffstat {
+        prompt.push_str(&format!("\n# Diffstat\n\n{}\n", stat));
+    }
+    prompt
+}
+
+/// Replace the raw commit list with an LLM-written summary.
+///
+/// Returns `Ok(false)` when there was nothing to summarize or the backend
+/// handed the prompt to the enclosing agent instead of answering.
+pub fn summarize_draft(
+    client: &dyn LlmClient,
+    project: &str,
+    draft: &mut HandoffDraft,
+) -> std::result::Result<bool, LlmError> {
+    if draft.done.is_empty() {
+        return Ok(false);
+    }
+    let response = client.complete(&summary_prompt(project, draft))?;
+    if response == AGENT_SENTINEL || response.trim().is_empty() {
+        return Ok(false);
+    }
+    draft.summary = Some(response.trim().to_string());
+    Ok(true)
+}
+
+/// Run the optional summarization pass, keeping the raw draft on any failure.
+pub(super) fn summarize(
+    project_root: &Path,
+    project: &str,
+    draft: &mut HandoffDraft,
+) -> Result<()> {
+    let llm_cfg = ProjectConfig::load(project_root)
+        .map(|c| c.llm_config().into_owned())
+        .unwrap_or_default();
+    let quiet = current_context().quiet;
+
+    let Some(backend) = detect_backend(&llm_cfg) else {
+        if !quiet {
+            log::warning("No LLM available; keeping the unsummarized draft").into_diagnostic()?;
+        }
+        return Ok(());
+    };
+    if let Err(e) = summarize_draft(backend.as_ref(), project, draft)
+        && !quiet
+    {
+        log::warning(format!(
+            "LLM summarization failed ({}); keeping the unsummarized draft",
+            e
+        ))
+        .into_diagnostic()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    struct FakeLlm {
+        response: String,
+        prompts: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl LlmClient for FakeLlm {
+        fn complete(&self, prompt: &str) -> std::result::Result<String, LlmError> {
+            self.prompts.lock().unwrap().push(prompt.to_string());
+            Ok(self.response.clone())
+        }
+        fn is_available(&self) -> bool {
+            true
+        }
+        fn name(&self) -> &str {
+            "fake"
+        }
+        fn model_id(&self) -> &str {
+            "fake"
+        }
+    }
+
+    fn fake(response: &str) -> FakeLlm {
+        FakeLlm {
+            response: response.to_string(),
+            prompts: Default::default(),
+        }
+    }
+
+    #[test]
+    fn summary_replaces_commit_list_but_keeps_diffstat() {
+        let llm = fake("- Reworked the parser\n");
+        let mut draft = HandoffDraft {
+            done: vec!["Fix parser (`abc123`)".to_string()],
+            diffstat: Some(" src/parser.rs | 4 ++--".to_string()),
+            ..Default::default()
+        };
+        assert!(summarize_draft(&llm, "demo", &mut draft).unwrap());
+        assert!(llm.prompts.lock().unwrap()[0].contains("Fix parser"));
+
+        let done = draft.render_done().unwrap();
+        assert!(done.starts_with("- Reworked the parser\n"));
+        assert!(!done.contains("abc123"));
+        assert!(done.contains("src/parser.rs"));
+    }
+
+    #[test]
+    fn agent_sentinel_and_empty_draft_keep_raw_content() {
+        let llm = fake(AGENT_SENTINEL);
+        let mut draft = HandoffDraft {
+            done: vec!["Fix parser (`abc123`)".to_string()],
+            ..Default::default()
+        };
+        assert!(!summarize_draft(&llm, "demo", &mut draft).unwrap());
+        assert!(draft.summary.is_none());
+
+        let llm = fake("- x");
+        let mut empty = HandoffDraft::default();
+        assert!(!summarize_draft(&llm, "demo", &mut empty).unwrap());
+        assert!(llm.prompts.lock().unwrap().is_empty());
+        assert!(empty.render_done().is_none());
+    }
+
+    #[test]
+    fn unresolved_reviews_respect_later_passes() {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path();
+        std::fs::write(
+            dir.join("2026-10-01-design-review.md"),
+            "---\nreviews: 2026-09-30-design.md\nverdict: fail\n---\n\n- Missing rollback plan\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("2026-10-02-design-review.md"),
+            "---\nreviews: 2026-09-30-design.md\nverdict: pass\n---\n\nLooks good.\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("2026-10-03-plan-review.md"),
+            "---\nreviews: 2026-10-03-plan.md\nseverity: {critical: 1, high: 0, medium: 2, low: 0}\n---\n\n\
+             - Race in lock acquisition\n- Unbounded retry\n- Typo\n- Another nit\n",
+        )
+        .unwrap();
+
+        let open = unresolved_reviews(dir);
+        assert_eq!(open.len(), 1);
+        assert!(open[0].starts_with("Review of `2026-10-03-plan.md` is unresolved (1 critical)"));
+        assert!(open[0].contains("\n  - Race in lock acquisition"));
+        assert!(!open[0].contains("Another nit"));
+    }
+
+    #[test]
+    fn previous_handoff_time_skips_the_file_being_replaced() {
+        let tmp = TempDir::new().unwrap();
+        assert!(previous_handoff_time(tmp.path(), "today.md").is_none());
+        std::fs::write(tmp.path().join("today.md"), "x").unwrap();
+        assert!(previous_handoff_time(tmp.path(), "today.md").is_none());
+        std::fs::write(tmp.path().join("earlier.md"), "x").unwrap();
+        assert!(previous_handoff_time(tmp.path(), "today.md").is_some());
+    }
+
+    #[test]
+    fn render_next_steps_numbers_items() {
+        let draft = HandoffDraft {
+            next_steps: vec!["First".to_string(), "Second".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            draft.render_next_steps().unwrap(),
+            "1. First\n2. Second\n\n"
+        );
+        assert!(draft.render_open_questions().is_none());
+    }
+}
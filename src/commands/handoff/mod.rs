@@ -0,0 +1,153 @@
+use chrono::Local;
+use cliclack::log;
+use miette::{IntoDiagnostic, Result};
+use std::path::{Path, PathBuf};
+
+use crate::cli::HandoffCommands;
+use crate::config::{HANDOFFS_DIR, STATE_FILE, projects_dir};
+use crate::context::{current_context, require_safe_mode};
+use crate::error::WaiError;
+use crate::plugin;
+use crate::state::ProjectState;
+
+use super::require_project;
+
+mod draft;
+pub mod session;
+pub mod template;
+
+pub use draft::HandoffDraft;
+pub use session::SessionIdentity;
+
+pub fn run(cmd: HandoffCommands) -> Result<()> {
+    let project_root = require_project()?;
+
+    match cmd {
+        HandoffCommands::Create {
+            project,
+            draft,
+            summarize,
+            author,
+            session,
+        } => {
+            require_safe_mode("create handoff")?;
+            let identity =
+                SessionIdentity::resolve(&project_root, author.as_deref(), session.as_deref());
+            let proj_dir = projects_dir(&project_root).join(&project);
+            let drafted = if (draft || summarize) && proj_dir.exists() {
+                let mut drafted = draft::gather_draft(
+                    &project_root,
+                    &proj_dir,
+                    &identity.handoff_filename(&today()),
+                );
+                if summarize {
+                    draft::summarize(&project_root, &project, &mut drafted)?;
+                }
+                Some(drafted)
+            } else {
+                None
+            };
+            let path = create_handoff_with(&project_root, &project, &identity, drafted.as_ref())?;
+            if !current_context().quiet {
+                let filename = path.file_name().unwrap_or_default().to_string_lossy();
+                log::success(format!(
+                    "Created handoff for '{}' at handoffs/{}",
+                    project, filename
+                ))
+                .into_diagnostic()?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn today() -> String {
+    Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// Create a handoff document for the given project and return the path to the created file.
+///
+/// The handoff is named after `identity`, so each author/session gets its own
+/// document per day. The caller is responsible for calling
+/// `require_safe_mode("create handoff")` before invoking this function.
+pub fn create_handoff(
+    project_root: &Path,
+    project: &str,
+    identity: &SessionIdentity,
+) -> Result<PathBuf> {
+    create_handoff_with(project_root, project, identity, None)
+}
+
+/// Like [`create_handoff`], but pre-fills sections from `draft` when given.
+///
+/// Drafted text goes into the sections the selected template names for it.
+/// Sections the draft has nothing for keep their HTML comment placeholder, so
+/// the result reads the same as an undrafted handoff wherever wai had no data.
+pub fn create_handoff_with(
+    project_root: &Path,
+    project: &str,
+    identity: &SessionIdentity,
+    draft: Option<&HandoffDraft>,
+) -> Result<PathBuf> {
+    let proj_dir = projects_dir(project_root).join(project);
+
+    if !proj_dir.exists() {
+        return Err(WaiError::ProjectNotFound {
+            name: project.to_string(),
+        }
+        .into());
+    }
+
+    let handoffs_dir = proj_dir.join(HANDOFFS_DIR);
+    std::fs::create_dir_all(&handoffs_dir).into_diagnostic()?;
+
+    // Load project state
+    let state_path = proj_dir.join(STATE_FILE);
+    let state = ProjectState::load(&state_path)?;
+
+    let date = today();
+    // If a same-day handoff from this author/session already exists, overwrite
+    // it in place rather than creating a numbered duplicate (e.g. session-end-1.md).
+    let final_filename = identity.handoff_filename(&date);
+
+    let phase = state.current.to_string();
+    let template = template::select_template(project_root, project, &phase)?;
+
+    // Gather plugin context via hook system
+    let hook_outputs =
+        plugin::run_project_hooks(project_root, "on_handoff_generate", Some(project));
+    let mut body = template.render(&template::TemplateVars {
+        date: &date,
+        project,
+        phase: &phase,
+        hook_outputs: &hook_outputs,
+    });
+
+    if let Some(draft) = draft {
+        let sections = &template.sections;
+        for (heading, text) in [
+            (&sections.done, draft.render_done()),
+            (&sections.open_questions, draft.render_open_questions()),
+            (&sections.next_steps, draft.render_next_steps()),
+        ] {
+            if let Some(text) = text {
+                template::fill_section(&mut body, heading, draft::DRAFT_MARKER, &text);
+            }
+        }
+    }
+
+    // Record a custom template so `wai prime` reads its section names.
+    let template_line = template
+        .name
+        .as_ref()
+        .map(|name| format!("template: {}\n", name))
+        .unwrap_or_default();
+    let content = format!(
+        "---\ndate: {date}\nproject: {project}\nphase: {phase}\n{identity}{template_line}---\n\n{body}",
+        identity = identity.frontmatter(),
+    );
+
+    let path = handoffs_dir.join(&final_filename);
+    std::fs::write(&path, &content).into_diagnostic()?;
+    Ok(path)
+}
@@ -0,0 +1,278 @@
+//! Who wrote a handoff and in which session.
+//!
+//! Handoffs are keyed by author and session ID so concurrent agents or people
+//! working on the same project in one day each keep their own document. A
+//! handoff without either (no flag, env var or git user) falls back to the
+//! legacy `<date>-session-end.md` name.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// File (inside the project directory) listing handoffs awaiting resume, one
+/// `handoffs/<file>` path per session.
+pub const PENDING_RESUME_FILE: &str = ".pending-resume";
+
+/// Characters of the session ID kept in file names.
+const SESSION_KEY_LEN: usize = 8;
+
+/// Author and session a handoff belongs to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionIdentity {
+    pub author: Option<String>,
+    pub session: Option<String>,
+}
+
+impl SessionIdentity {
+    /// Resolve from explicit flags, then `WAI_AGENT` / `WAI_SESSION_ID`, then
+    /// (for the author) `git config user.name`.
+    pub fn resolve(project_root: &Path, author: Option<&str>, session: Option<&str>) -> Self {
+        let non_empty = |v: String| {
+            let v = v.trim().to_string();
+            (!v.is_empty()).then_some(v)
+        };
+        let author = author
+            .map(str::to_string)
+            .and_then(non_empty)
+            .or_else(|| std::env::var("WAI_AGENT").ok().and_then(non_empty))
+            .or_else(|| git_user_name(project_root).and_then(non_empty));
+        let session = session
+            .map(str::to_string)
+            .and_then(non_empty)
+            .or_else(|| std::env::var("WAI_SESSION_ID").ok().and_then(non_empty));
+        Self { author, session }
+    }
+
+    /// Read the `author:` and `session:` fields from handoff front matter.
+    pub fn from_frontmatter(content: &str) -> Self {
+        let mut identity = Self::default();
+        let Some(rest) = content.trim_start().strip_prefix("---") else {
+            return identity;
+        };
+        let fm = rest.find("\n---").map(|end| &rest[..end]).unwrap_or("");
+        for line in fm.lines() {
+            let line = line.trim();
+            if let Some(v) = line.strip_prefix("author:") {
+                identity.author = Some(v.trim().to_string());
+            } else if let Some(v) = line.strip_prefix("session:") {
+                identity.session = Some(v.trim().to_string());
+            }
+        }
+        identity
+    }
+
+    /// Slug used in file names, or `None` for an anonymous handoff.
+    pub fn key(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            self.author.as_deref().map(slug::slugify),
+            self.session
+                .as_deref()
+                .map(|s| slug::slugify(s).chars().take(SESSION_KEY_LEN).collect()),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|p: &String| !p.is_empty())
+        .collect();
+        (!parts.is_empty()).then(|| parts.join("-"))
+    }
+
+    /// Handoff file name for `date`. Same-day handoffs from the same identity
+    /// share a name so they are updated in place.
+    pub fn handoff_filename(&self, date: &str) -> String {
+        match self.key() {
+            Some(key) => format!("{}-{}-session-end.md", date, key),
+            None => format!("{}-session-end.md", date),
+        }
+    }
+
+    /// Front matter lines recording this identity.
+    pub fn frontmatter(&self) -> String {
+        let mut out = String::new();
+        if let Some(author) = &self.author {
+            out.push_str(&format!("author: {}\n", author));
+        }
+        if let Some(session) = &self.session {
+            out.push_str(&format!("session: {}\n", session));
+        }
+        out
+    }
+
+    /// Short human label, e.g. `claude (session 1a2b3c4d)`.
+    pub fn label(&self) -> String {
+        let session = self
+            .session
+            .as_deref()
+            .map(|s| s.chars().take(SESSION_KEY_LEN).collect::<String>());
+        match (&self.author, session) {
+            (Some(a), Some(s)) => format!("{} (session {})", a, s),
+            (Some(a), None) => a.clone(),
+            (None, Some(s)) => format!("session {}", s),
+            (None, None) => "unattributed".to_string(),
+        }
+    }
+}
+
+fn git_user_name(project_root: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .args(["config", "user.name"])
+        .current_dir(project_root)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn identity_of(path: &Path) -> SessionIdentity {
+    std::fs::read_to_string(path)
+        .map(|c| SessionIdentity::from_frontmatter(&c))
+        .unwrap_or_default()
+}
+
+/// Handoffs listed in the project's pending-resume file that still exist.
+pub fn read_pending_resume(project_dir: &Path) -> Vec<PathBuf> {
+    let Ok(content) = std::fs::read_to_string(project_dir.join(PENDING_RESUME_FILE)) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| project_dir.join(l))
+        .filter(|p| p.exists())
+        .collect()
+}
+
+/// Record `handoff` (relative to the project directory) as awaiting resume,
+/// replacing any earlier entry from the same session and dropping entries
+/// whose handoff no longer exists.
+pub fn record_pending_resume(project_dir: &Path, handoff: &Path) -> std::io::Result<()> {
+    let identity = identity_of(&project_dir.join(handoff));
+    let relative = handoff.to_string_lossy().to_string();
+    let mut lines: Vec<String> = read_pending_resume(project_dir)
+        .into_iter()
+        .filter(|p| identity_of(p) != identity)
+        .filter_map(|p| {
+            p.strip_prefix(project_dir)
+                .ok()
+                .map(|r| r.to_string_lossy().to_string())
+        })
+        .filter(|r| *r != relative)
+        .collect();
+    lines.push(relative);
+    std::fs::write(
+        project_dir.join(PENDING_RESUME_FILE),
+        format!("{}\n", lines.join("\n")),
+    )
+}
+
+/// Rewrite the pending-resume file keeping only `keep`, deleting it when
+/// nothing is left.
+pub fn retain_pending_resume(project_dir: &Path, keep: &[PathBuf]) {
+    let path = project_dir.join(PENDING_RESUME_FILE);
+    let lines: Vec<String> = keep
+        .iter()
+        .filter_map(|p| p.strip_prefix(project_dir).ok())
+        .map(|r| r.to_string_lossy().to_string())
+        .collect();
+    if lines.is_empty() {
+        let _ = std::fs::remove_file(path);
+    } else {
+        let _ = std::fs::write(path, format!("{}\n", lines.join("\n")));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    #[serial_test::serial]
+    fn resolve_prefers_flags_then_env() {
+        let tmp = TempDir::new().unwrap();
+        let saved = (
+            std::env::var("WAI_AGENT").ok(),
+            std::env::var("WAI_SESSION_ID").ok(),
+        );
+        unsafe {
+            std::env::set_var("WAI_AGENT", "codex");
+            std::env::set_var("WAI_SESSION_ID", "0199a2b3-c4d5");
+        }
+        let from_env = SessionIdentity::resolve(tmp.path(), None, None);
+        let from_flags = SessionIdentity::resolve(tmp.path(), Some("alice"), Some("s1"));
+        unsafe {
+            match saved.0 {
+                Some(v) => std::env::set_var("WAI_AGENT", v),
+                None => std::env::remove_var("WAI_AGENT"),
+            }
+            match saved.1 {
+                Some(v) => std::env::set_var("WAI_SESSION_ID", v),
+                None => std::env::remove_var("WAI_SESSION_ID"),
+            }
+        }
+
+        assert_eq!(from_env.author.as_deref(), Some("codex"));
+        assert_eq!(
+            from_env.handoff_filename("2026-10-18"),
+            "2026-10-18-codex-0199a2b3-session-end.md"
+        );
+        assert_eq!(from_flags.key().as_deref(), Some("alice-s1"));
+        assert_eq!(from_flags.label(), "alice (session s1)");
+    }
+
+    #[test]
+    fn anonymous_identity_keeps_legacy_name() {
+        let anon = SessionIdentity::default();
+        assert_eq!(anon.key(), None);
+        assert_eq!(
+            anon.handoff_filename("2026-10-18"),
+            "2026-10-18-session-end.md"
+        );
+        assert_eq!(anon.frontmatter(), "");
+    }
+
+    #[test]
+    fn frontmatter_round_trips() {
+        let identity = SessionIdentity {
+            author: Some("Ada Lovelace".to_string()),
+            session: Some("abc".to_string()),
+        };
+        let content = format!(
+            "---\ndate: 2026-10-18\n{}---\n\n# x\n",
+            identity.frontmatter()
+        );
+        assert_eq!(SessionIdentity::from_frontmatter(&content), identity);
+        assert_eq!(identity.key().as_deref(), Some("ada-lovelace-abc"));
+    }
+
+    #[test]
+    fn pending_resume_tracks_one_entry_per_session() {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path();
+        std::fs::create_dir_all(dir.join("handoffs")).unwrap();
+        let write = |name: &str, author: &str| {
+            std::fs::write(
+                dir.join("handoffs").join(name),
+                format!("---\ndate: 2026-10-18\nauthor: {}\n---\n", author),
+            )
+            .unwrap();
+            record_pending_resume(dir, &Path::new("handoffs").join(name)).unwrap();
+        };
+
+        write("a.md", "alice");
+        write("b.md", "bob");
+        write("a2.md", "alice");
+
+        let pending = read_pending_resume(dir);
+        assert_eq!(
+            pending,
+            vec![dir.join("handoffs/b.md"), dir.join("handoffs/a2.md")]
+        );
+
+        retain_pending_resume(dir, &pending[..1]);
+        assert_eq!(read_pending_resume(dir), vec![dir.join("handoffs/b.md")]);
+        retain_pending_resume(dir, &[]);
+        assert!(!dir.join(PENDING_RESUME_FILE).exists());
+    }
+}
@@ -0,0 +1,416 @@
+//! Handoff templates under `.wai/resources/templates/handoff/`.
+//!
+//! A template is markdown with optional YAML front matter naming the sections
+//! wai reads and writes. The first file that exists wins:
+//!
+//! 1. `project-<project>.md`
+//! 2. `phase-<phase>.md`
+//! 3. `default.md`
+//!
+//! Without any of these the built-in template is used.
+
+use std::path::Path;
+use std::sync::LazyLock;
+
+use miette::{IntoDiagnostic, Result};
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::config::handoff_templates_dir;
+use crate::plugin::HookOutput;
+
+/// Body of the built-in template.
+const BUILTIN_BODY: &str = "# Session Handoff\n\n\
+     ## What Was Done\n\n\
+     <!-- Summary of completed work -->\n\n\
+     ## Key Decisions\n\n\
+     <!-- Decisions made and rationale -->\n\n\
+     ## Gotchas & Surprises\n\n\
+     <!-- What behaved unexpectedly? Non-obvious requirements? Hidden dependencies? -->\n\n\
+     ## What Took Longer Than Expected\n\n\
+     <!-- Steps that needed multiple attempts. Commands that failed before the right one. -->\n\n\
+     ## Open Questions\n\n\
+     <!-- Unresolved questions -->\n\n\
+     ## Next Steps\n\n\
+     <!-- Prioritized list of what to do next -->\n\n\
+     ## Context\n\n\
+     {{plugin_context}}";
+
+static PLACEHOLDER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{\s*([A-Za-z_]+(?::[A-Za-z0-9_.-]+)?)\s*\}\}").unwrap());
+
+/// Section headings (without the `## `) that wai reads and writes.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct HandoffSections {
+    /// Section whose first paragraph `wai prime` shows as the summary.
+    /// When unset, the first paragraph of the whole handoff is used.
+    pub summary: Option<String>,
+    /// Section pre-filled with commits and sessions by `--draft`.
+    pub done: String,
+    /// Section `wai prime` lists on resume, pre-filled by `--draft`.
+    pub next_steps: String,
+    /// Section pre-filled with unresolved reviews by `--draft`.
+    pub open_questions: String,
+}
+
+impl Default for HandoffSections {
+    fn default() -> Self {
+        Self {
+            summary: None,
+            done: "What Was Done".to_string(),
+            next_steps: "Next Steps".to_string(),
+            open_questions: "Open Questions".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct TemplateMeta {
+    sections: HandoffSections,
+}
+
+/// A resolved handoff template.
+#[derive(Debug)]
+pub struct HandoffTemplate {
+    /// File stem of the template, or `None` for the built-in one.
+    pub name: Option<String>,
+    pub sections: HandoffSections,
+    body: String,
+}
+
+/// Values substituted into template placeholders.
+pub struct TemplateVars<'a> {
+    pub date: &'a str,
+    pub project: &'a str,
+    pub phase: &'a str,
+    pub hook_outputs: &'a [HookOutput],
+}
+
+impl HandoffTemplate {
+    fn builtin() -> Self {
+        Self {
+            name: None,
+            sections: HandoffSections::default(),
+            body: BUILTIN_BODY.to_string(),
+        }
+    }
+
+    /// Substitute placeholders into the template body.
+    ///
+    /// Supported placeholders: `{{date}}`, `{{project}}`, `{{phase}}`,
+    /// `{{plugin_context}}` (every `on_handoff_generate` hook output) and
+    /// `{{plugin:<name>}}` (outputs of one plugin, matched by plugin name or
+    /// hook label).
+    pub fn render(&self, vars: &TemplateVars<'_>) -> String {
+        PLACEHOLDER
+            .replace_all(&self.body, |caps: &regex::Captures<'_>| {
+                match caps[1].split_once(':') {
+                    Some((_, key)) => {
+                        let text: String = vars
+                            .hook_outputs
+                            .iter()
+                            .filter(|o| o.plugin == key || o.label == key)
+                            .map(render_hook_output)
+                            .collect();
+                        if text.is_empty() {
+                            format!("<!-- No {} context available -->\n", key)
+                        } else {
+                            text
+                        }
+                    }
+                    None => match &caps[1] {
+                        "date" => vars.date.to_string(),
+                        "project" => vars.project.to_string(),
+                        "phase" => vars.phase.to_string(),
+                        _ => plugin_context(vars.hook_outputs),
+                    },
+                }
+            })
+            .into_owned()
+    }
+}
+
+/// Render every hook output under a `### <label>` heading.
+fn plugin_context(outputs: &[HookOutput]) -> String {
+    if outputs.is_empty() {
+        return "<!-- No plugin context available -->\n".to_string();
+    }
+    outputs
+        .iter()
+        .map(|o| format!("### {}\n\n{}", o.label, render_hook_output(o)))
+        .collect()
+}
+
+fn render_hook_output(output: &HookOutput) -> String {
+    let mut text = String::new();
+    if let Some(ref data) = output.data {
+        for entry in &data.status {
+            text.push_str(&format!("- **{}:** {}\n", entry.label, entry.value));
+        }
+        for memory in &data.memories {
+            text.push_str(&format!("- {}\n", memory));
+        }
+        if !data.status.is_empty() || !data.memories.is_empty() {
+            text.push('\n');
+        }
+    }
+    if !output.content.is_empty() {
+        text.push_str("```\n");
+        text.push_str(&output.content);
+        text.push_str("```\n\n");
+    }
+    text
+}
+
+/// Pick the template for `project` in `phase`, falling back to the built-in.
+pub fn select_template(project_root: &Path, project: &str, phase: &str) -> Result<HandoffTemplate> {
+    let dir = handoff_templates_dir(project_root);
+    for name in [
+        format!("project-{}", project),
+        format!("phase-{}", phase),
+        "default".to_string(),
+    ] {
+        let path = dir.join(format!("{}.md", name));
+        if path.is_file() {
+            return load_template(&path, &name);
+        }
+    }
+    Ok(HandoffTemplate::builtin())
+}
+
+fn load_template(path: &Path, name: &str) -> Result<HandoffTemplate> {
+    let content = std::fs::read_to_string(path).into_diagnostic()?;
+    let (meta, body) = match split_frontmatter(&content) {
+        Some((fm, body)) if fm.trim().is_empty() => {
+            (TemplateMeta::default(), body.trim_start_matches('\n'))
+        }
+        Some((fm, body)) => {
+            let meta: TemplateMeta = serde_yml::from_str(fm).map_err(|e| {
+                miette::miette!("Invalid front matter in {}: {}", path.display(), e)
+            })?;
+            (meta, body.trim_start_matches('\n'))
+        }
+        None => (TemplateMeta::default(), content.as_str()),
+    };
+
+    for caps in PLACEHOLDER.captures_iter(body) {
+        let key = &caps[1];
+        let known = matches!(key, "date" | "project" | "phase" | "plugin_context")
+            || key.starts_with("plugin:");
+        if !known {
+            miette::bail!(
+                "Unknown placeholder '{}' in {}. Supported: {{{{date}}}}, {{{{project}}}}, \
+                 {{{{phase}}}}, {{{{plugin_context}}}}, {{{{plugin:<name>}}}}",
+                &caps[0],
+                path.display()
+            );
+        }
+    }
+
+    Ok(HandoffTemplate {
+        name: Some(name.to_string()),
+        sections: meta.sections,
+        body: body.to_string(),
+    })
+}
+
+/// Split `---`-delimited front matter from the rest of the document.
+pub fn split_frontmatter(content: &str) -> Option<(&str, &str)> {
+    let rest = content.trim_start().strip_prefix("---")?;
+    let end = rest.find("\n---")?;
+    let after = &rest[end + 4..];
+    Some((&rest[..end], after.strip_prefix('\n').unwrap_or(after)))
+}
+
+/// Section names for an existing handoff, read from the template recorded in
+/// its `template:` front matter field.
+///
+/// Falls back to the built-in names when the handoff has no template or the
+/// template no longer loads.
+pub fn sections_for_handoff(project_root: &Path, content: &str) -> HandoffSections {
+    let name = split_frontmatter(content).and_then(|(fm, _)| {
+        fm.lines()
+            .find_map(|l| l.trim().strip_prefix("template:"))
+            .map(|v| v.trim().to_string())
+    });
+    name.and_then(|name| {
+        let path = handoff_templates_dir(project_root).join(format!("{}.md", name));
+        load_template(&path, &name).ok()
+    })
+    .map(|t| t.sections)
+    .unwrap_or_default()
+}
+
+/// Byte range of the body of section `## <heading>`: from the end of the
+/// heading line up to the next `## ` heading or EOF.
+pub fn section_range(content: &str, heading: &str) -> Option<std::ops::Range<usize>> {
+    let marker = format!("## {}", heading);
+    let mut offset = 0;
+    let mut start = None;
+    for line in content.split_inclusive('\n') {
+        if let Some(s) = start {
+            if line.starts_with("## ") {
+                return Some(s..offset);
+            }
+        } else if line.starts_with(&marker) {
+            start = Some(offset + line.len());
+        }
+        offset += line.len();
+    }
+    start.map(|s| s..content.len())
+}
+
+/// Put drafted `text` into section `heading`.
+///
+/// A section holding only HTML comment placeholders is replaced; one with
+/// written guidance keeps it and gets the draft appended. Missing sections are
+/// left alone.
+pub fn fill_section(content: &mut String, heading: &str, marker: &str, text: &str) {
+    let Some(range) = section_range(content, heading) else {
+        return;
+    };
+    let existing = &content[range.clone()];
+    let placeholder_only = existing
+        .lines()
+        .map(str::trim)
+        .all(|l| l.is_empty() || (l.starts_with("<!--") && l.ends_with("-->")));
+    let replacement = if placeholder_only {
+        format!("\n{}\n{}", marker, text)
+    } else {
+        format!("{}\n\n{}\n{}", existing.trim_end(), marker, text)
+    };
+    content.replace_range(range, &replacement);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn vars(outputs: &[HookOutput]) -> TemplateVars<'_> {
+        TemplateVars {
+            date: "2026-10-18",
+            project: "demo",
+            phase: "implement",
+            hook_outputs: outputs,
+        }
+    }
+
+    fn write_template(root: &Path, name: &str, content: &str) {
+        let dir = handoff_templates_dir(root);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(format!("{}.md", name)), content).unwrap();
+    }
+
+    #[test]
+    fn project_template_beats_phase_and_default() {
+        let tmp = TempDir::new().unwrap();
+        assert!(
+            select_template(tmp.path(), "demo", "research")
+                .unwrap()
+                .name
+                .is_none()
+        );
+
+        write_template(tmp.path(), "default", "## Notes\n");
+        write_template(tmp.path(), "phase-research", "## Hypotheses tested\n");
+        let t = select_template(tmp.path(), "demo", "research").unwrap();
+        assert_eq!(t.name.as_deref(), Some("phase-research"));
+
+        write_template(tmp.path(), "project-demo", "## Incidents\n");
+        let t = select_template(tmp.path(), "demo", "research").unwrap();
+        assert_eq!(t.name.as_deref(), Some("project-demo"));
+        assert_eq!(
+            select_template(tmp.path(), "other", "plan")
+                .unwrap()
+                .name
+                .as_deref(),
+            Some("default")
+        );
+    }
+
+    #[test]
+    fn front_matter_declares_sections_and_rejects_unknown_placeholders() {
+        let tmp = TempDir::new().unwrap();
+        write_template(
+            tmp.path(),
+            "default",
+            "---\nsections:\n  summary: Incidents\n  next_steps: Follow-ups\n---\n\n# On-call\n\n## Incidents\n\n{{plugin:beads}}",
+        );
+        let t = select_template(tmp.path(), "demo", "plan").unwrap();
+        assert_eq!(t.sections.summary.as_deref(), Some("Incidents"));
+        assert_eq!(t.sections.next_steps, "Follow-ups");
+        assert_eq!(t.sections.done, "What Was Done");
+        assert_eq!(
+            t.render(&vars(&[])),
+            "# On-call\n\n## Incidents\n\n<!-- No beads context available -->\n"
+        );
+
+        write_template(tmp.path(), "default", "## Notes\n\n{{author}}\n");
+        let err = select_template(tmp.path(), "demo", "plan").unwrap_err();
+        assert!(err.to_string().contains("Unknown placeholder '{{author}}'"));
+
+        write_template(
+            tmp.path(),
+            "default",
+            "---\nsections: [Notes]\n---\n## Notes\n",
+        );
+        assert!(select_template(tmp.path(), "demo", "plan").is_err());
+    }
+
+    #[test]
+    fn render_substitutes_plugin_outputs() {
+        let outputs = vec![HookOutput {
+            plugin: "beads".to_string(),
+            label: "beads_ready".to_string(),
+            content: "bd-1 ready\n".to_string(),
+            data: None,
+        }];
+        let t = HandoffTemplate {
+            name: None,
+            sections: HandoffSections::default(),
+            body:
+                "{{project}} @ {{phase}} {{date}}\n{{plugin_context}}--\n{{ plugin:beads_ready }}"
+                    .to_string(),
+        };
+        assert_eq!(
+            t.render(&vars(&outputs)),
+            "demo @ implement 2026-10-18\n### beads_ready\n\n```\nbd-1 ready\n```\n\n--\n```\nbd-1 ready\n```\n\n"
+        );
+    }
+
+    #[test]
+    fn fill_section_replaces_placeholders_or_appends() {
+        let mut content =
+            "## Done\n\n<!-- what happened -->\n\n## Notes\n\nKeep this.\n".to_string();
+        fill_section(&mut content, "Done", "<!-- m -->", "- a\n\n");
+        fill_section(&mut content, "Notes", "<!-- m -->", "- b\n");
+        fill_section(&mut content, "Missing", "<!-- m -->", "- c\n");
+        assert_eq!(
+            content,
+            "## Done\n\n<!-- m -->\n- a\n\n## Notes\n\nKeep this.\n\n<!-- m -->\n- b\n"
+        );
+    }
+
+    #[test]
+    fn sections_for_handoff_follow_recorded_template() {
+        let tmp = TempDir::new().unwrap();
+        write_template(
+            tmp.path(),
+            "phase-research",
+            "---\nsections:\n  next_steps: Next experiments\n---\n## Next experiments\n",
+        );
+        let with = "---\ndate: 2026-10-18\ntemplate: phase-research\n---\n\n# x\n";
+        let without = "---\ndate: 2026-10-18\n---\n\n# x\n";
+        assert_eq!(
+            sections_for_handoff(tmp.path(), with).next_steps,
+            "Next experiments"
+        );
+        assert_eq!(
+            sections_for_handoff(tmp.path(), without).next_steps,
+            "Next Steps"
+        );
+    }
+}
@@ -165,6 +165,8 @@ pub fn run(name: Option<String>) -> Result<()> {
         plugins: vec![],
         llm: Some(LlmConfig::default()),
         why: None,
+        managed_block: None,
+        reflect: None,
     };
 
     // Save config (creates .wai directory)
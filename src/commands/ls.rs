@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use miette::Result;
 use owo_colors::OwoColorize;
 use std::collections::HashMap;
@@ -7,10 +8,20 @@ use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use walkdir::WalkDir;
 
-use crate::config::{STATE_FILE, projects_dir};
+use crate::cli::LsSort;
+use crate::config::{STATE_FILE, last_run_path, pipelines_dir, projects_dir, wai_dir};
+use crate::context::current_context;
+use crate::freshness::scan_freshness;
+use crate::json::{BeadsSummary, LsHealth, LsPayload, LsPipeline, LsProject};
+use crate::output::print_envelope_ok;
 use crate::state::ProjectState;
 
-/// Maximum number of concurrent `bd stats --json` subprocesses.
+use super::doctor::health_summary;
+use super::pipeline::{PipelineRun, load_pipeline_toml};
+use super::prime::{find_latest_handoffs, read_handoff_summary};
+use super::reflect::{ReflectDebt, reflect_debt};
+
+/// Maximum number of workspaces probed at once (`bd stats --json`, doctor).
 const MAX_PARALLEL_BD: usize = 8;
 
 struct Row {
@@ -18,9 +29,48 @@ struct Row {
     workspace: PathBuf,
     phase: String,
     counts: Option<(u64, u64)>,
+    detail: Option<Detail>,
+}
+
+/// Extra per-project columns shown by `--detail` and `--json`.
+struct Detail {
+    last_handoff: Option<NaiveDate>,
+    pipeline: Option<LsPipeline>,
+    stale: usize,
+    health: LsHealth,
+    debt: ReflectDebt,
+}
+
+/// Facts gathered once per workspace and shared by its projects.
+struct WorkspaceDetail {
+    pipeline: Option<LsPipeline>,
+    /// Stale artifact count keyed by project name.
+    stale: HashMap<String, usize>,
+    health: LsHealth,
+}
+
+pub struct LsArgs {
+    pub root: Option<PathBuf>,
+    pub depth: Option<usize>,
+    pub timeout_secs: u64,
+    pub detail: bool,
+    pub sort: LsSort,
+    pub phases: Vec<String>,
+    pub stale_only: bool,
 }
 
-pub fn run(root: Option<PathBuf>, depth: Option<usize>, timeout_secs: u64) -> Result<()> {
+pub fn run(args: LsArgs) -> Result<()> {
+    let LsArgs {
+        root,
+        depth,
+        timeout_secs,
+        detail,
+        sort,
+        phases,
+        stale_only,
+    } = args;
+    let json = current_context().json;
+
     // Resolve root
     let explicit_root = root.is_some();
     let root = match root {
@@ -56,28 +106,25 @@ pub fn run(root: Option<PathBuf>, depth: Option<usize>, timeout_secs: u64) -> Re
     let mut any_beads = false;
 
     // Fetch beads stats for all workspaces in parallel, capped at MAX_PARALLEL_BD.
-    let beads_results: HashMap<PathBuf, Option<(u64, u64)>> = {
-        let semaphore = Arc::new(Semaphore::new(MAX_PARALLEL_BD));
-        let results = Arc::new(Mutex::new(HashMap::new()));
-        let handles: Vec<_> = workspaces
+    let beads_results: HashMap<PathBuf, Option<(u64, u64)>> = parallel_map(
+        workspaces
             .iter()
             .filter(|ws| ws.join(".beads").exists())
-            .map(|ws| {
-                let ws = ws.clone();
-                let results = Arc::clone(&results);
-                let semaphore: Arc<Semaphore> = Arc::clone(&semaphore);
-                std::thread::spawn(move || {
-                    // Acquire a permit before running bd — this caps concurrency.
-                    let _permit = semaphore.acquire();
-                    let counts = fetch_beads_counts(&ws);
-                    results.lock().unwrap().insert(ws, counts);
-                })
-            })
-            .collect();
-        for handle in handles {
-            let _ = handle.join();
-        }
-        Arc::try_unwrap(results).unwrap().into_inner().unwrap()
+            .cloned()
+            .collect(),
+        fetch_beads_counts,
+    );
+
+    // Detail columns (and anything that sorts or filters on them) need a
+    // freshness scan and a doctor run per workspace; run those in parallel too.
+    let need_detail = detail || json || stale_only || !matches!(sort, LsSort::Name | LsSort::Phase);
+    let mut workspace_details: HashMap<PathBuf, WorkspaceDetail> = if need_detail {
+        parallel_map(workspaces.clone(), |ws| Some(workspace_detail(ws)))
+            .into_iter()
+            .filter_map(|(ws, d)| d.map(|d| (ws, d)))
+            .collect()
+    } else {
+        HashMap::new()
     };
 
     for workspace in &workspaces {
@@ -103,27 +150,56 @@ pub fn run(root: Option<PathBuf>, depth: Option<usize>, timeout_secs: u64) -> Re
             .collect();
         project_names.sort();
 
+        let ws_detail = workspace_details.remove(workspace);
         for name in project_names {
             let state_path = proj_dir.join(&name).join(STATE_FILE);
             let phase = match ProjectState::load(&state_path) {
                 Ok(state) => state.current.to_string(),
                 Err(_) => "unknown".to_string(),
             };
+            let detail = ws_detail.as_ref().map(|ws_detail| Detail {
+                last_handoff: last_handoff_date(workspace, &name),
+                pipeline: ws_detail.pipeline.clone(),
+                stale: ws_detail.stale.get(&name).copied().unwrap_or(0),
+                health: ws_detail.health.clone(),
+                debt: reflect_debt(workspace, &name),
+            });
             rows.push(Row {
                 name,
                 workspace: workspace.clone(),
                 phase,
                 counts,
+                detail,
             });
         }
     }
 
-    // Sort rows by project name
+    // Filter
+    if !phases.is_empty() {
+        rows.retain(|row| phases.iter().any(|p| p.eq_ignore_ascii_case(&row.phase)));
+    }
+    if stale_only {
+        rows.retain(|row| row.detail.as_ref().is_some_and(|d| d.stale > 0));
+    }
+
+    // Sort rows by project name, then by the requested column (stable).
     rows.sort_by(|a, b| a.name.cmp(&b.name));
+    sort_rows(&mut rows, sort);
+
+    if json {
+        return print_envelope_ok(LsPayload {
+            root: root.display().to_string(),
+            projects: rows.into_iter().map(ls_project).collect(),
+        });
+    }
 
     // Empty case
     if rows.is_empty() {
-        println!("No wai workspaces found under {}", root.display());
+        if phases.is_empty() && !stale_only {
+            println!("No wai workspaces found under {}", root.display());
+        } else {
+            println!("No projects match the filter under {}", root.display());
+        }
         return Ok(());
     }
 
@@ -165,20 +241,203 @@ pub fn run(root: Option<PathBuf>, depth: Option<usize>, timeout_secs: u64) -> Re
         let name_col = format!("{:<width$}", display_names[i], width = max_name);
         let phase_col = format_phase_col(&row.phase, max_phase);
 
+        let mut line = format!("{}  {}", name_col, phase_col);
         if any_beads {
             let counts_str = match row.counts {
                 Some((open, ready)) => format!("{} open, {} ready", open, ready),
                 None => String::new(),
             };
-            println!("{}  {}  {}", name_col, phase_col, counts_str);
-        } else {
-            println!("{}  {}", name_col, phase_col);
+            line = format!("{}  {}", line, counts_str);
         }
+        if detail && let Some(d) = &row.detail {
+            line = format!("{}  {}", line, format_detail(d));
+        }
+        println!("{}", line.trim_end());
     }
 
     Ok(())
 }
 
+/// Run `f` for every workspace on at most [`MAX_PARALLEL_BD`] threads at a time.
+fn parallel_map<T, F>(workspaces: Vec<PathBuf>, f: F) -> HashMap<PathBuf, Option<T>>
+where
+    T: Send + 'static,
+    F: Fn(&Path) -> Option<T> + Send + Sync + Copy + 'static,
+{
+    let semaphore = Arc::new(Semaphore::new(MAX_PARALLEL_BD));
+    let results = Arc::new(Mutex::new(HashMap::new()));
+    let handles: Vec<_> = workspaces
+        .into_iter()
+        .map(|ws| {
+            let results = Arc::clone(&results);
+            let semaphore: Arc<Semaphore> = Arc::clone(&semaphore);
+            std::thread::spawn(move || {
+                // Acquire a permit before doing the work — this caps concurrency.
+                let _permit = semaphore.acquire();
+                let value = f(&ws);
+                results.lock().unwrap().insert(ws, value);
+            })
+        })
+        .collect();
+    for handle in handles {
+        let _ = handle.join();
+    }
+    Arc::try_unwrap(results)
+        .ok()
+        .expect("all workers joined")
+        .into_inner()
+        .unwrap()
+}
+
+fn workspace_detail(workspace: &Path) -> WorkspaceDetail {
+    let marker = format!("{}/", projects_dir(workspace).display());
+    let mut stale: HashMap<String, usize> = HashMap::new();
+    for entry in scan_freshness(workspace).stale {
+        let project = entry
+            .artifact
+            .strip_prefix(&marker)
+            .and_then(|rest| rest.split('/').next());
+        if let Some(project) = project {
+            *stale.entry(project.to_string()).or_insert(0) += 1;
+        }
+    }
+    let report = health_summary(workspace);
+    WorkspaceDetail {
+        pipeline: active_pipeline(workspace),
+        stale,
+        health: LsHealth {
+            warn: report.summary.warn,
+            fail: report.summary.fail,
+        },
+    }
+}
+
+/// The workspace's active pipeline run, from its `.last-run` pointer.
+///
+/// `WAI_PIPELINE_RUN` is deliberately ignored: it names a run in the current
+/// workspace, not in the ones being listed.
+fn active_pipeline(workspace: &Path) -> Option<LsPipeline> {
+    let run_id = std::fs::read_to_string(last_run_path(workspace)).ok()?;
+    let run_id = run_id.trim();
+    let run_path = wai_dir(workspace)
+        .join("pipeline-runs")
+        .join(format!("{}.yml", run_id));
+    let run: PipelineRun = serde_yml::from_str(&std::fs::read_to_string(run_path).ok()?).ok()?;
+    let def_path = pipelines_dir(workspace).join(format!("{}.toml", run.pipeline));
+    let definition = load_pipeline_toml(&def_path).ok()?;
+    let step = definition.steps.get(run.current_step)?;
+    Some(LsPipeline {
+        name: run.pipeline.clone(),
+        run_id: run.run_id.clone(),
+        step: run.current_step + 1,
+        total: definition.steps.len(),
+        step_id: step.id.clone(),
+    })
+}
+
+fn last_handoff_date(workspace: &Path, project: &str) -> Option<NaiveDate> {
+    let latest = find_latest_handoffs(workspace, project).ok()?;
+    let path = latest.first()?;
+    Some(read_handoff_summary(workspace, path).0)
+}
+
+/// Position of `phase` in the workflow; unknown phases sort last.
+fn phase_rank(phase: &str) -> usize {
+    const ORDER: [&str; 6] = [
+        "research",
+        "design",
+        "plan",
+        "implement",
+        "review",
+        "archive",
+    ];
+    ORDER
+        .iter()
+        .position(|p| *p == phase)
+        .unwrap_or(ORDER.len())
+}
+
+fn sort_rows(rows: &mut [Row], sort: LsSort) {
+    use std::cmp::Reverse;
+    match sort {
+        LsSort::Name => {}
+        LsSort::Phase => rows.sort_by_key(|r| phase_rank(&r.phase)),
+        LsSort::Stale => rows.sort_by_key(|r| Reverse(r.detail.as_ref().map_or(0, |d| d.stale))),
+        LsSort::Handoff => rows.sort_by_key(|r| r.detail.as_ref().and_then(|d| d.last_handoff)),
+        LsSort::Health => rows.sort_by_key(|r| {
+            Reverse(
+                r.detail
+                    .as_ref()
+                    .map_or((0, 0), |d| (d.health.fail, d.health.warn)),
+            )
+        }),
+        LsSort::Debt => rows.sort_by_key(|r| {
+            Reverse(
+                r.detail
+                    .as_ref()
+                    .map_or((false, 0), |d| (d.debt.is_due(), d.debt.handoffs)),
+            )
+        }),
+    }
+}
+
+fn format_detail(d: &Detail) -> String {
+    let handoff = d
+        .last_handoff
+        .map(|date| format!("handoff {}", date))
+        .unwrap_or_else(|| "no handoff".to_string());
+    let pipeline = d
+        .pipeline
+        .as_ref()
+        .map(|p| format!("{} {}/{}", p.name, p.step, p.total))
+        .unwrap_or_else(|| "no pipeline".to_string());
+    let stale = if d.stale > 0 {
+        format!("{} stale", d.stale).yellow().to_string()
+    } else {
+        "0 stale".dimmed().to_string()
+    };
+    let health = match (d.health.fail, d.health.warn) {
+        (0, 0) => "healthy".green().to_string(),
+        (0, warn) => format!("{} warn", warn).yellow().to_string(),
+        (fail, warn) => format!("{} fail, {} warn", fail, warn).red().to_string(),
+    };
+    let reflect = if d.debt.is_due() {
+        format!("reflect due ({})", d.debt.handoffs)
+            .yellow()
+            .to_string()
+    } else {
+        format!("{} since reflect", d.debt.handoffs)
+            .dimmed()
+            .to_string()
+    };
+    format!(
+        "{}  {}  {}  {}  {}",
+        handoff.dimmed(),
+        pipeline,
+        stale,
+        health,
+        reflect
+    )
+}
+
+fn ls_project(row: Row) -> LsProject {
+    let detail = row.detail;
+    LsProject {
+        name: row.name,
+        workspace: row.workspace.display().to_string(),
+        phase: row.phase,
+        beads: row.counts.map(|(open, ready)| BeadsSummary { open, ready }),
+        last_handoff: detail
+            .as_ref()
+            .and_then(|d| d.last_handoff)
+            .map(|date| date.format("%Y-%m-%d").to_string()),
+        pipeline: detail.as_ref().and_then(|d| d.pipeline.clone()),
+        stale_artifacts: detail.as_ref().map(|d| d.stale),
+        health: detail.as_ref().map(|d| d.health.clone()),
+        reflect_debt: detail.as_ref().map(|d| d.debt.entry()),
+    }
+}
+
 /// Walk the filesystem from `root` up to `max_depth` levels, returning paths of
 /// directories that contain `.wai/config.toml`. Hidden directories are skipped
 /// during traversal; `.wai/` itself is never recursed into.
@@ -35,6 +35,7 @@ mod show;
 mod status;
 mod sync;
 mod timeline;
+mod trace;
 mod way;
 mod why;
 
@@ -91,6 +92,13 @@ pub fn run(cli: Cli, guide: &Guide) -> Result<()> {
             crate::cli::ResourceCommands::Import(import_cmd) => resource::run_import(import_cmd),
             crate::cli::ResourceCommands::Install(args) => resource::run_install(args),
             crate::cli::ResourceCommands::Export(args) => resource::run_export(args),
+            crate::cli::ResourceCommands::Lint(args) => resource::run_lint(args),
+            crate::cli::ResourceCommands::Trust {
+                public_key,
+                label,
+                list,
+                revoke,
+            } => resource::run_trust(public_key, label, list, revoke),
         },
         Some(Commands::Feedback {
             kind,
@@ -117,14 +125,33 @@ pub fn run(cli: Cli, guide: &Guide) -> Result<()> {
         }),
         Some(Commands::Pipeline(cmd)) => pipeline::run(cmd),
         Some(Commands::Artifacts(cmd)) => artifacts::run(cmd),
-        Some(Commands::Close { project, remember }) => close::run(project, remember),
-        Some(Commands::Prime { project }) => prime::run(project),
+        Some(Commands::Trace(cmd)) => trace::run(cmd),
+        Some(Commands::Close {
+            project,
+            remember,
+            author,
+            session,
+            reflect,
+        }) => close::run(project, remember, author, session, reflect),
+        Some(Commands::Prime { project, budget }) => prime::run(project, budget),
         Some(Commands::Project(cmd)) => project::run(cmd),
         Some(Commands::Ls {
             root,
             depth,
             timeout,
-        }) => ls::run(root, depth, timeout),
+            detail,
+            sort,
+            phase,
+            stale,
+        }) => ls::run(ls::LsArgs {
+            root,
+            depth,
+            timeout_secs: timeout,
+            detail,
+            sort,
+            phases: phase,
+            stale_only: stale,
+        }),
         Some(Commands::Tutorial) => crate::tutorial::run(),
         Some(Commands::Why {
             query,
@@ -134,20 +161,24 @@ pub fn run(cli: Cli, guide: &Guide) -> Result<()> {
         Some(Commands::Reflect {
             project,
             conversation,
+            no_traces,
             output,
             dry_run,
             yes,
             inject_content,
             save_memories,
+            reject,
         }) => reflect::run(reflect::ReflectArgs {
             project,
             conversation,
+            no_traces,
             output,
             dry_run,
             yes,
             inject_content,
             verbose: cli.verbose.raw_count(),
             save_memories,
+            reject,
         }),
         Some(Commands::Completions { shell }) => {
             let mut cmd = crate::cli::Cli::command();
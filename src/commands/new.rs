@@ -39,7 +39,7 @@ pub fn run(cmd: NewCommands) -> Result<()> {
             let state = ProjectState::default();
             state.save(&proj_dir.join(STATE_FILE))?;
 
-            plugin::run_hooks(&project_root, "on_project_create");
+            plugin::run_project_hooks(&project_root, "on_project_create", Some(&name));
 
             log::success(format!("Created project '{}'", name)).into_diagnostic()?;
 
@@ -76,7 +76,7 @@ pub fn run(args: PhaseArgs) -> Result<()> {
             let new_phase = state.advance()?;
             state.save(&state_path)?;
 
-            plugin::run_hooks(&project_root, "on_phase_transition");
+            plugin::run_project_hooks(&project_root, "on_phase_transition", Some(project_name));
 
             log::success(format!(
                 "Project '{}' advanced to phase: {}",
@@ -96,7 +96,7 @@ pub fn run(args: PhaseArgs) -> Result<()> {
             let new_phase = state.go_back()?;
             state.save(&state_path)?;
 
-            plugin::run_hooks(&project_root, "on_phase_transition");
+            plugin::run_project_hooks(&project_root, "on_phase_transition", Some(project_name));
 
             log::success(format!(
                 "Project '{}' moved back to phase: {}",
@@ -128,7 +128,7 @@ pub fn run(args: PhaseArgs) -> Result<()> {
             state.transition_to(target)?;
             state.save(&state_path)?;
 
-            plugin::run_hooks(&project_root, "on_phase_transition");
+            plugin::run_project_hooks(&project_root, "on_phase_transition", Some(project_name));
 
             log::success(format!(
                 "Project '{}' set to phase: {}",
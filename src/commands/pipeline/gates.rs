@@ -1,9 +1,13 @@
 use miette::Result;
 use owo_colors::OwoColorize;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
+use std::time::{Duration, Instant};
 
-use super::{OracleGate, PipelineDefinition, PipelineRun, PipelineStep, StepGate};
+use crate::json::OracleFinding;
+
+use super::{OracleGate, PipelineDefinition, PipelineRun, PipelineStep, ProceduralGate, StepGate};
 
 // ─── Artifact scanning helpers ────────────────────────────────────────────────
 
@@ -247,9 +251,21 @@ pub(super) fn evaluate_gates(
     gate: &StepGate,
     step: &PipelineStep,
     run: &PipelineRun,
-    _definition: &PipelineDefinition,
+    definition: &PipelineDefinition,
     project_root: &Path,
 ) -> Result<Vec<String>> {
+    Ok(evaluate_gates_report(gate, step, run, definition, project_root)?.failures)
+}
+
+/// Like [`evaluate_gates`], but also returns the oracle findings gathered on
+/// the way (empty when an earlier tier already failed).
+pub(super) fn evaluate_gates_report(
+    gate: &StepGate,
+    step: &PipelineStep,
+    run: &PipelineRun,
+    _definition: &PipelineDefinition,
+    project_root: &Path,
+) -> Result<GateReport> {
     let mut failures = Vec::new();
 
     // Collect artifacts for this step
@@ -282,7 +298,10 @@ pub(super) fn evaluate_gates(
         }
     }
     if !failures.is_empty() {
-        return Ok(failures);
+        return Ok(GateReport {
+            failures,
+            findings: Vec::new(),
+        });
     }
 
     // Tier 2: Procedural
@@ -335,7 +354,10 @@ pub(super) fn evaluate_gates(
         }
     }
     if !failures.is_empty() {
-        return Ok(failures);
+        return Ok(GateReport {
+            failures,
+            findings: Vec::new(),
+        });
     }
 
     // Tier 3: Coverage
@@ -349,16 +371,21 @@ pub(super) fn evaluate_gates(
         ));
     }
     if !failures.is_empty() {
-        return Ok(failures);
+        return Ok(GateReport {
+            failures,
+            findings: Vec::new(),
+        });
     }
 
     // Tier 4: Oracles
-    for oracle in &gate.oracles {
-        let oracle_failures = run_oracle(oracle, &step_artifacts, project_root)?;
-        failures.extend(oracle_failures);
-    }
-    if !failures.is_empty() {
-        return Ok(failures);
+    let oracles = run_oracles(
+        &gate.oracles,
+        &step_artifacts,
+        gate.procedural.as_ref(),
+        project_root,
+    )?;
+    if !oracles.failures.is_empty() {
+        return Ok(oracles);
     }
 
     // Tier 5: Approval
@@ -391,68 +418,192 @@ pub(super) fn evaluate_gates(
         }
     }
 
-    Ok(failures)
+    Ok(GateReport {
+        failures,
+        findings: oracles.findings,
+    })
 }
 
 // ─── Oracle helpers ───────────────────────────────────────────────────────────
 
-/// Run an oracle gate check. Returns failure messages (empty = passed).
+/// Maximum number of oracle processes running at once.
+const MAX_PARALLEL_ORACLES: usize = 8;
+
+/// Result of a single oracle invocation.
+#[derive(Debug, Default)]
+pub(super) struct OracleOutput {
+    /// Why the oracle failed (non-zero exit or timeout); `None` when it passed.
+    pub(super) failure: Option<String>,
+    /// Structured findings parsed from the oracle's JSON stdout.
+    pub(super) findings: Vec<OracleFinding>,
+}
+
+/// Gate failure messages plus the oracle findings behind them.
+#[derive(Debug, Default)]
+pub(super) struct GateReport {
+    pub(super) failures: Vec<String>,
+    pub(super) findings: Vec<OracleFinding>,
+}
+
+/// One planned oracle process: which oracle, which artifact (if per-artifact).
+struct OracleJob<'a> {
+    oracle: &'a OracleGate,
+    command: String,
+    paths: Vec<String>,
+    artifact: Option<String>,
+}
+
+/// Run a single oracle gate check (no severity limits applied).
 pub(super) fn run_oracle(
     oracle: &OracleGate,
     artifacts: &[ArtifactInfo],
     project_root: &Path,
-) -> Result<Vec<String>> {
-    let mut failures = Vec::new();
-
-    // Resolve the oracle command
-    let command = if let Some(ref cmd) = oracle.command {
-        cmd.clone()
-    } else {
-        // Name-based resolution from .wai/resources/oracles/
-        resolve_oracle_command(&oracle.name, project_root)?
-    };
+) -> Result<GateReport> {
+    run_oracles(std::slice::from_ref(oracle), artifacts, None, project_root)
+}
 
-    let timeout_secs = oracle.timeout.unwrap_or(30);
-    let scope = oracle.scope.as_deref().unwrap_or("artifact");
+/// Run every oracle against the step's artifacts in parallel.
+///
+/// Oracles that exit non-zero or time out fail the gate. Findings they print
+/// as JSON are collected, and critical/high findings per artifact are held
+/// against the step's `max_critical`/`max_high` procedural limits.
+pub(super) fn run_oracles(
+    oracles: &[OracleGate],
+    artifacts: &[ArtifactInfo],
+    procedural: Option<&ProceduralGate>,
+    project_root: &Path,
+) -> Result<GateReport> {
+    let mut report = GateReport::default();
 
     // Filter to non-review artifacts for oracle checking
     let applicable: Vec<_> = artifacts
         .iter()
         .filter(|a| a.artifact_type != "review")
         .collect();
+    let projects = crate::config::projects_dir(project_root);
 
-    if scope == "all" {
-        // Single invocation with all artifact paths
-        if applicable.is_empty() {
-            return Ok(failures);
-        }
-        let projects = crate::config::projects_dir(project_root);
-        let paths: Vec<String> = applicable
-            .iter()
-            .filter_map(|a| find_artifact_path(&projects, &a.filename))
-            .collect();
-        if !paths.is_empty() {
-            let args: Vec<&str> = paths.iter().map(|s| s.as_str()).collect();
-            if let Some(err) = execute_oracle(&command, &args, timeout_secs)? {
-                failures.push(format!("Oracle '{}' failed: {}", oracle.name, err));
+    let mut jobs = Vec::new();
+    for oracle in oracles {
+        // Resolve the oracle command
+        let command = if let Some(ref cmd) = oracle.command {
+            cmd.clone()
+        } else {
+            // Name-based resolution from .wai/resources/oracles/
+            resolve_oracle_command(&oracle.name, project_root)?
+        };
+
+        if oracle.scope.as_deref() == Some("all") {
+            // Single invocation with all artifact paths
+            let paths: Vec<String> = applicable
+                .iter()
+                .filter_map(|a| find_artifact_path(&projects, &a.filename))
+                .collect();
+            if !paths.is_empty() {
+                jobs.push(OracleJob {
+                    oracle,
+                    command,
+                    paths,
+                    artifact: None,
+                });
+            }
+        } else {
+            // Per-artifact invocation
+            for artifact in &applicable {
+                if let Some(path) = find_artifact_path(&projects, &artifact.filename) {
+                    jobs.push(OracleJob {
+                        oracle,
+                        command: command.clone(),
+                        paths: vec![path],
+                        artifact: Some(artifact.filename.clone()),
+                    });
+                }
             }
         }
-    } else {
-        // Per-artifact invocation
-        let projects = crate::config::projects_dir(project_root);
-        for artifact in &applicable {
-            if let Some(path) = find_artifact_path(&projects, &artifact.filename)
-                && let Some(err) = execute_oracle(&command, &[&path], timeout_secs)?
-            {
-                failures.push(format!(
+    }
+
+    let mut outputs = Vec::with_capacity(jobs.len());
+    for batch in jobs.chunks(MAX_PARALLEL_ORACLES) {
+        let results = std::thread::scope(|scope| {
+            let handles: Vec<_> = batch
+                .iter()
+                .map(|job| {
+                    scope.spawn(move || {
+                        let args: Vec<&str> = job.paths.iter().map(|s| s.as_str()).collect();
+                        execute_oracle(&job.command, &args, job.oracle.timeout.unwrap_or(30))
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().expect("oracle thread panicked"))
+                .collect::<Vec<_>>()
+        });
+        outputs.extend(batch.iter().zip(results));
+    }
+
+    for (job, output) in outputs {
+        let output = output?;
+        if let Some(err) = output.failure {
+            report.failures.push(match &job.artifact {
+                Some(artifact) => format!(
                     "Oracle '{}' failed for '{}': {}",
-                    oracle.name, artifact.filename, err
-                ));
+                    job.oracle.name, artifact, err
+                ),
+                None => format!("Oracle '{}' failed: {}", job.oracle.name, err),
+            });
+        }
+        for mut finding in output.findings {
+            finding.oracle = job.oracle.name.clone();
+            if finding.artifact.is_none() {
+                finding.artifact = job.artifact.clone();
             }
+            report.findings.push(finding);
         }
     }
 
-    Ok(failures)
+    if let Some(pg) = procedural {
+        report
+            .failures
+            .extend(severity_failures(&report.findings, pg));
+    }
+
+    Ok(report)
+}
+
+/// Hold oracle findings against the procedural severity limits, per artifact.
+fn severity_failures(findings: &[OracleFinding], pg: &ProceduralGate) -> Vec<String> {
+    let mut counts: BTreeMap<&str, (u32, u32)> = BTreeMap::new();
+    for finding in findings {
+        let entry = counts
+            .entry(finding.artifact.as_deref().unwrap_or("all artifacts"))
+            .or_default();
+        match finding.severity.as_str() {
+            "critical" => entry.0 += 1,
+            "high" => entry.1 += 1,
+            _ => {}
+        }
+    }
+
+    let mut failures = Vec::new();
+    for (artifact, (critical, high)) in counts {
+        if let Some(max_crit) = pg.max_critical
+            && critical > max_crit
+        {
+            failures.push(format!(
+                "Oracles report {} critical findings for '{}' (max: {}).",
+                critical, artifact, max_crit
+            ));
+        }
+        if let Some(max_h) = pg.max_high
+            && high > max_h
+        {
+            failures.push(format!(
+                "Oracles report {} high findings for '{}' (max: {}).",
+                high, artifact, max_h
+            ));
+        }
+    }
+    failures
 }
 
 /// Resolve oracle name to an executable path in .wai/resources/oracles/.
@@ -470,47 +621,120 @@ pub(super) fn resolve_oracle_command(name: &str, project_root: &Path) -> Result<
     miette::bail!("Oracle '{}' not found in {}", name, oracles_dir.display())
 }
 
-/// Execute an oracle command with arguments. Returns None on success (exit 0),
-/// or Some(stderr) on failure.
+/// Execute an oracle command with arguments, killing it once `timeout_secs`
+/// elapse (`0` waits forever).
+///
+/// Exit 0 passes; anything else fails with stderr (or the exit code) as the
+/// reason. JSON findings on stdout are parsed either way.
 pub(super) fn execute_oracle(
     command: &str,
     args: &[&str],
     timeout_secs: u64,
-) -> Result<Option<String>> {
-    use std::process::Command;
+) -> Result<OracleOutput> {
+    use std::io::Read;
+    use std::process::{Command, Stdio};
 
     let mut cmd = Command::new(command);
     cmd.args(args);
-    cmd.stdout(std::process::Stdio::piped());
-    cmd.stderr(std::process::Stdio::piped());
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+    // Own process group, so a timeout also takes down anything the oracle spawned.
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
 
-    let child = cmd
+    let mut child = cmd
         .spawn()
         .map_err(|e| miette::miette!("Failed to execute oracle '{}': {}", command, e))?;
 
-    let output = if timeout_secs > 0 {
-        // Wait with timeout
-        let result = child.wait_with_output();
-        match result {
-            Ok(o) => o,
+    // Drain the pipes on their own threads so a chatty oracle cannot block on
+    // a full pipe while we wait for it.
+    let mut stdout_pipe = child.stdout.take().expect("stdout is piped");
+    let mut stderr_pipe = child.stderr.take().expect("stderr is piped");
+    let stdout_reader = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stdout_pipe.read_to_end(&mut buf);
+        buf
+    });
+    let stderr_reader = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stderr_pipe.read_to_end(&mut buf);
+        buf
+    });
+
+    let deadline = (timeout_secs > 0).then(|| Instant::now() + Duration::from_secs(timeout_secs));
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => {}
             Err(e) => return Err(miette::miette!("Oracle execution failed: {}", e)),
         }
-    } else {
-        child
-            .wait_with_output()
-            .map_err(|e| miette::miette!("Oracle execution failed: {}", e))?
+        if deadline.is_some_and(|d| Instant::now() >= d) {
+            kill_oracle(&mut child);
+            // The readers are left behind: a surviving grandchild may still
+            // hold the pipes open.
+            return Ok(OracleOutput {
+                failure: Some(format!("timed out after {}s", timeout_secs)),
+                findings: Vec::new(),
+            });
+        }
+        std::thread::sleep(Duration::from_millis(20));
     };
 
-    if output.status.success() {
-        Ok(None)
+    let stdout = stdout_reader.join().unwrap_or_default();
+    let stderr = stderr_reader.join().unwrap_or_default();
+    let findings = parse_findings(&String::from_utf8_lossy(&stdout));
+
+    let failure = if status.success() {
+        None
     } else {
-        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        Ok(Some(if stderr.is_empty() {
-            format!("exit code {}", output.status.code().unwrap_or(-1))
+        let stderr = String::from_utf8_lossy(&stderr).trim().to_string();
+        Some(if stderr.is_empty() {
+            format!("exit code {}", status.code().unwrap_or(-1))
         } else {
             stderr
-        }))
+        })
+    };
+    Ok(OracleOutput { failure, findings })
+}
+
+fn kill_oracle(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    {
+        let _ = std::process::Command::new("kill")
+            .args(["-KILL", &format!("-{}", child.id())])
+            .stderr(std::process::Stdio::null())
+            .status();
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Parse oracle stdout as findings: a JSON array, or an object with a
+/// `findings` array. Anything else is plain output and yields no findings.
+pub(super) fn parse_findings(stdout: &str) -> Vec<OracleFinding> {
+    #[derive(serde::Deserialize)]
+    struct Wrapped {
+        findings: Vec<OracleFinding>,
     }
+
+    let trimmed = stdout.trim();
+    let findings = if trimmed.starts_with('[') {
+        serde_json::from_str::<Vec<OracleFinding>>(trimmed).ok()
+    } else if trimmed.starts_with('{') {
+        serde_json::from_str::<Wrapped>(trimmed)
+            .ok()
+            .map(|w| w.findings)
+    } else {
+        None
+    };
+    findings
+        .unwrap_or_default()
+        .into_iter()
+        .map(|mut f| {
+            f.severity = f.severity.trim().to_ascii_lowercase();
+            f
+        })
+        .collect()
 }
 
 /// Find the absolute path of an artifact by filename, searching all project artifact directories.
@@ -353,8 +353,8 @@ mod tests {
     use super::*;
     use definition::{load_pipeline_toml, validate_pipeline};
     use gates::{
-        evaluate_gates, execute_oracle, find_step_artifact_paths, parse_frontmatter,
-        resolve_oracle_command,
+        evaluate_gates, execute_oracle, find_step_artifact_paths, parse_findings,
+        parse_frontmatter, resolve_oracle_command,
     };
     use queries::find_step_addenda;
     use setup::{builtin_template_names, get_builtin_template};
@@ -1325,7 +1325,7 @@ require_input_manifest = true
     fn execute_oracle_success() {
         let result = execute_oracle("true", &[], 30).unwrap();
         assert!(
-            result.is_none(),
+            result.failure.is_none(),
             "expected None (success), got: {:?}",
             result
         );
@@ -1334,7 +1334,31 @@ require_input_manifest = true
     #[test]
     fn execute_oracle_failure() {
         let result = execute_oracle("false", &[], 30).unwrap();
-        assert!(result.is_some(), "expected Some (failure)");
+        assert!(result.failure.is_some(), "expected Some (failure)");
+    }
+
+    #[test]
+    fn execute_oracle_kills_on_timeout() {
+        let started = std::time::Instant::now();
+        let result = execute_oracle("sleep", &["30"], 1).unwrap();
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+        assert_eq!(result.failure.as_deref(), Some("timed out after 1s"));
+    }
+
+    #[test]
+    fn parse_findings_accepts_array_object_and_plain_output() {
+        let findings = parse_findings(
+            r#"[{"severity": "High", "location": "Method", "message": "n too small"}]"#,
+        );
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, "high");
+        assert_eq!(findings[0].location.as_deref(), Some("Method"));
+
+        let findings = parse_findings(r#"{"findings": [{"message": "note"}]}"#);
+        assert_eq!(findings[0].severity, "info");
+
+        assert!(parse_findings("all good\n").is_empty());
+        assert!(parse_findings("{not json").is_empty());
     }
 
     // ── built-in templates ────────────────────────────────────────────────
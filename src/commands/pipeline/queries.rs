@@ -5,15 +5,16 @@ use std::path::Path;
 
 use crate::config::pipelines_dir;
 use crate::context::current_context;
-use crate::json::PipelineCurrentPayload;
+use crate::json::{OracleFinding, PipelineCheckPayload, PipelineCurrentPayload};
 use crate::output::print_envelope_ok;
 
 use super::definition::{list_pipeline_names, load_pipeline_toml, validate_pipeline};
 use super::gates::{
-    evaluate_gates, find_step_artifacts, format_gate_summary, parse_frontmatter, print_gate_status,
+    GateReport, evaluate_gates_report, find_step_artifacts, format_gate_summary, parse_frontmatter,
+    print_gate_status,
 };
 use super::orchestration::{pipeline_current_status, resolve_active_run_id};
-use super::{PipelineDefinition, PipelineRun, ValidationLevel};
+use super::{PipelineDefinition, PipelineRun, PipelineStep, ValidationLevel};
 
 use crate::commands::require_project;
 
@@ -396,51 +397,112 @@ pub(super) fn cmd_check(oracle_name: Option<&str>) -> Result<()> {
             );
         };
 
-        let failures = super::gates::run_oracle(oracle, &step_artifacts, &project_root)?;
+        let report = super::gates::run_oracle(oracle, &step_artifacts, &project_root)?;
+        if current_context().json {
+            return print_check_payload(&run, step, report);
+        }
         println!();
-        if failures.is_empty() {
+        if report.failures.is_empty() {
             println!("  {} Oracle '{}': PASS", "✓".green(), oracle_filter);
         } else {
-            for f in &failures {
+            for f in &report.failures {
                 println!("  {} {}", "✗".red(), f);
             }
         }
+        print_findings(&report.findings);
         println!();
         return Ok(());
     }
 
     // Full gate check
     let Some(ref gate) = step.gate else {
+        if current_context().json {
+            return print_check_payload(&run, step, GateReport::default());
+        }
         println!();
         println!("  No gates configured for step '{}'. Result: PASS", step.id);
         println!();
         return Ok(());
     };
 
-    let failures = evaluate_gates(gate, step, &run, &definition, &project_root)?;
+    let report = evaluate_gates_report(gate, step, &run, &definition, &project_root)?;
+    if current_context().json {
+        return print_check_payload(&run, step, report);
+    }
 
     println!();
     println!("  {} Gate check for step '{}':", "◆".cyan(), step.id);
     println!();
 
-    if failures.is_empty() {
+    if report.failures.is_empty() {
         println!("  {} Result: PASS", "✓".green());
     } else {
-        for f in &failures {
+        for f in &report.failures {
             println!("  {} {}", "✗".red(), f);
         }
         println!();
         println!(
             "  {} Result: BLOCKED — resolve {} failure(s)",
             "✗".red(),
-            failures.len()
+            report.failures.len()
         );
     }
+    print_findings(&report.findings);
     println!();
 
     Ok(())
 }
 
+fn print_check_payload(run: &PipelineRun, step: &PipelineStep, report: GateReport) -> Result<()> {
+    print_envelope_ok(PipelineCheckPayload {
+        run_id: run.run_id.clone(),
+        step: step.id.clone(),
+        passed: report.failures.is_empty(),
+        failures: report.failures,
+        findings: report.findings,
+    })
+}
+
+/// List oracle findings, most severe first.
+fn print_findings(findings: &[OracleFinding]) {
+    if findings.is_empty() {
+        return;
+    }
+    let rank = |severity: &str| {
+        ["critical", "high", "medium", "low"]
+            .iter()
+            .position(|s| *s == severity)
+            .unwrap_or(4)
+    };
+    let mut sorted: Vec<_> = findings.iter().collect();
+    sorted.sort_by_key(|f| rank(&f.severity));
+
+    println!();
+    println!("  {} Oracle findings ({}):", "◆".cyan(), findings.len());
+    for f in sorted {
+        let severity = format!("[{}]", f.severity);
+        let severity = match f.severity.as_str() {
+            "critical" | "high" => severity.red().to_string(),
+            "medium" => severity.yellow().to_string(),
+            _ => severity.dimmed().to_string(),
+        };
+        let mut location = f.artifact.clone().unwrap_or_default();
+        if let Some(line) = f.line {
+            location = format!("{}:{}", location, line);
+        }
+        if let Some(ref loc) = f.location {
+            location = format!("{} ({})", location, loc);
+        }
+        println!(
+            "    {} {} — {} {}",
+            severity,
+            location.trim(),
+            f.message,
+            format!("[{}]", f.oracle).dimmed()
+        );
+    }
+}
+
 // ─── validate ─────────────────────────────────────────────────────────────────
 
 pub(super) fn cmd_validate(name: Option<&str>) -> Result<()> {
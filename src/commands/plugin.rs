@@ -27,8 +27,9 @@ pub fn run(cmd: PluginCommands) -> Result<()> {
                             "not found".to_string()
                         },
                         detected: p.detected,
+                        reason: p.reason,
                         detector: p.def.detector.as_ref().map(|detector| PluginDetector {
-                            detector_type: detector.detector_type.clone(),
+                            detector_type: detector_kind(detector).to_string(),
                             path: detector.path.clone(),
                         }),
                         commands: p
@@ -42,6 +43,7 @@ pub fn run(cmd: PluginCommands) -> Result<()> {
                             })
                             .collect(),
                         hooks: p.def.hooks.keys().cloned().collect(),
+                        checks: p.def.checks.iter().map(|c| c.name.clone()).collect(),
                     })
                     .collect::<Vec<_>>();
                 return print_envelope_list(plugins);
@@ -59,10 +61,9 @@ pub fn run(cmd: PluginCommands) -> Result<()> {
                     "not found".dimmed().to_string()
                 };
 
-                let source = if p.def.detector.is_some() {
-                    "built-in"
-                } else {
-                    "custom"
+                let source = match p.source {
+                    plugin::PluginSource::BuiltIn => "built-in",
+                    plugin::PluginSource::Custom => "custom",
                 };
 
                 println!(
@@ -73,6 +74,12 @@ pub fn run(cmd: PluginCommands) -> Result<()> {
                     status,
                     source.dimmed()
                 );
+                println!(
+                    "      {} {}: {}",
+                    "↳".dimmed(),
+                    if p.detected { "active" } else { "inactive" },
+                    p.reason.dimmed()
+                );
 
                 // Show commands if any
                 if !p.def.commands.is_empty() {
@@ -104,6 +111,17 @@ pub fn run(cmd: PluginCommands) -> Result<()> {
                             .dimmed()
                     );
                 }
+
+                // Show checks if any
+                if !p.def.checks.is_empty() {
+                    let check_names: Vec<&str> =
+                        p.def.checks.iter().map(|c| c.name.as_str()).collect();
+                    println!(
+                        "      {} checks: {}",
+                        "↳".dimmed(),
+                        check_names.join(", ").dimmed()
+                    );
+                }
             }
 
             println!();
@@ -249,9 +267,9 @@ pub fn run(cmd: PluginCommands) -> Result<()> {
 
             let mut store = TrustStore::load();
             let hook_names: Vec<String> = target.def.hooks.keys().cloned().collect();
-            if hook_names.is_empty() {
+            if hook_names.is_empty() && target.def.checks.is_empty() {
                 return Err(crate::error::WaiError::PluginTrustError {
-                    message: format!("plugin '{name}' defines no hooks"),
+                    message: format!("plugin '{name}' defines no hooks or checks"),
                 }
                 .into());
             }
@@ -275,6 +293,28 @@ pub fn run(cmd: PluginCommands) -> Result<()> {
                     approved.push((hook_name.clone(), digest));
                 }
             }
+            // Checks are approved like hooks, under a `check:<name>` entry.
+            for check in &target.def.checks {
+                let trust_name = plugin::check_trust_name(check);
+                if let Some(h) = hook.as_ref()
+                    && *h != trust_name
+                {
+                    continue;
+                }
+                let digest = plugin::compute_check_digest(&target.def, check);
+                let command = match check.fix {
+                    Some(ref fix) => format!("{} (fix: {})", check.command, fix),
+                    None => check.command.clone(),
+                };
+                store.approve(TrustEntry {
+                    plugin_name: name.clone(),
+                    hook_name: trust_name.clone(),
+                    digest: digest.clone(),
+                    command,
+                    approved_at: chrono::Utc::now().to_rfc3339(),
+                });
+                approved.push((trust_name, digest));
+            }
             store
                 .save()
                 .map_err(|e| crate::error::WaiError::PluginTrustError { message: e })?;
@@ -301,3 +341,14 @@ pub fn run(cmd: PluginCommands) -> Result<()> {
         }
     }
 }
+
+/// Label for a detector in JSON output: its type, or the combinator it uses.
+fn detector_kind(detector: &plugin::DetectorDef) -> &str {
+    if !detector.all.is_empty() {
+        "all"
+    } else if !detector.any.is_empty() {
+        "any"
+    } else {
+        &detector.detector_type
+    }
+}
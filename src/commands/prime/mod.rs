@@ -1,12 +1,15 @@
+mod pack;
+
 use chrono::{Local, NaiveDate};
 use miette::{IntoDiagnostic, Result};
 use owo_colors::OwoColorize;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime};
 
 use crate::config::{HANDOFFS_DIR, PLANS_DIR, STATE_FILE, projects_dir};
 use crate::context::current_context;
-use crate::json::{BeadsSummary, OpenspecEntry, PrimePayload};
+use crate::json::{BeadsSummary, OpenspecEntry, PrimeHandoff, PrimePayload, Suggestion};
 use crate::openspec;
 use crate::output::print_envelope;
 use crate::plugin;
@@ -15,14 +18,18 @@ use crate::state::ProjectState;
 use crate::workspace::detect_installed_pipelines;
 
 use super::doctor::health_summary;
+use super::handoff::SessionIdentity;
+use super::handoff::session::{PENDING_RESUME_FILE, read_pending_resume, retain_pending_resume};
+use super::handoff::template::{section_range, sections_for_handoff};
 use super::pipeline::pipeline_current_status;
+use super::reflect::reflect_debt;
 
 use super::{beads_counts, beads_summary, list_projects, require_project, resolve_project};
 
 /// Maximum age of a `.pending-resume` file before it is considered stale.
 const RESUME_WINDOW: Duration = Duration::from_secs(12 * 60 * 60);
 
-pub fn run(project: Option<String>) -> Result<()> {
+pub fn run(project: Option<String>, budget: Option<usize>) -> Result<()> {
     let project_root = require_project()?;
     let json_mode = current_context().json;
 
@@ -35,10 +42,15 @@ pub fn run(project: Option<String>) -> Result<()> {
                 phase: None,
                 resume: false,
                 handoff_summary: None,
+                handoffs: Vec::new(),
                 next_steps: Vec::new(),
                 plans: Vec::new(),
                 beads: None,
                 openspec: Vec::new(),
+                plugin_status: Vec::new(),
+                memories: Vec::new(),
+                reflect_debt: None,
+                suggestions: Vec::new(),
             };
             return print_envelope(genesis::envelope::EnvelopeKind::Ok, payload, vec![], vec![]);
         }
@@ -62,15 +74,19 @@ pub fn run(project: Option<String>) -> Result<()> {
         Err(_) => "unknown".to_string(),
     };
 
+    if let Some(budget) = budget {
+        return pack::run(&project_root, &project_name, &phase, budget);
+    }
+
     // Resume detection: check for .pending-resume signal from wai close.
     // The signal is valid if the .pending-resume file was written within the
     // last 12 hours.  A stale file (older than 12 hours) is deleted with a
-    // diagnostic note so the user knows it was found but skipped.
-    let pending_resume_path = proj_dir.join(".pending-resume");
-    let resume_info = check_pending_resume(&proj_dir, &pending_resume_path);
+    // diagnostic note so the user knows it was found but skipped. Each session
+    // that closed today has its own entry; all of them are resumed.
+    let resume_info = check_pending_resume(&project_root, &proj_dir);
 
     // Plugin summaries (beads, openspec) — gathered for both JSON and terminal paths.
-    let hook_outputs = plugin::run_hooks(&project_root, "on_status");
+    let hook_outputs = plugin::run_project_hooks(&project_root, "on_status", Some(&project_name));
     let spec_status = openspec::read_status(&project_root);
 
     if json_mode {
@@ -91,21 +107,39 @@ pub fn run(project: Option<String>) -> Result<()> {
     // Project + phase
     println!("{} Project: {} [{}]", "•".dimmed(), project_name, phase);
 
-    if let Some((handoff_path, date, snippet)) = resume_info {
-        println!("⚡ RESUMING: {} — '{}'", date.format("%Y-%m-%d"), snippet);
-        let steps = extract_next_steps(&handoff_path);
-        if !steps.is_empty() {
-            println!("  Next Steps:");
-            for step in &steps {
-                println!("    {}", step);
+    // Concurrent sessions are labelled with their author/session so their
+    // handoffs can be told apart.
+    if !resume_info.is_empty() {
+        let labelled = resume_info.len() > 1;
+        for (handoff_path, date, snippet) in &resume_info {
+            println!(
+                "⚡ RESUMING: {} — '{}'{}",
+                date.format("%Y-%m-%d"),
+                snippet,
+                session_suffix(handoff_path, labelled)
+            );
+            let steps = extract_next_steps(&project_root, handoff_path);
+            if !steps.is_empty() {
+                println!("  Next Steps:");
+                for step in &steps {
+                    println!("    {}", step);
+                }
             }
         }
     } else {
         // Handoff (normal path)
-        if let Some(handoff_path) = find_latest_handoff(&project_root, &project_name)? {
-            let (date, snippet) = read_handoff_summary(&handoff_path);
+        let latest = find_latest_handoffs(&project_root, &project_name)?;
+        let labelled = latest.len() > 1;
+        for handoff_path in &latest {
+            let (date, snippet) = read_handoff_summary(&project_root, handoff_path);
             if !snippet.is_empty() {
-                println!("{} Handoff: {} — '{}'", "•".dimmed(), date, snippet);
+                println!(
+                    "{} Handoff: {} — '{}'{}",
+                    "•".dimmed(),
+                    date,
+                    snippet,
+                    session_suffix(handoff_path, labelled)
+                );
             }
             // If snippet is empty, it means missing/invalid frontmatter → skip the line
         }
@@ -128,6 +162,10 @@ pub fn run(project: Option<String>) -> Result<()> {
         }
     }
 
+    for entry in plugin::collect_plugin_status(&hook_outputs) {
+        println!("{} {}: {}", "•".dimmed(), entry.label, entry.value);
+    }
+
     if let Some(ref spec) = spec_status {
         for change in &spec.changes {
             let pct = (change.done * 100).checked_div(change.total).unwrap_or(0);
@@ -142,30 +180,33 @@ pub fn run(project: Option<String>) -> Result<()> {
         }
     }
 
-    // bd memories — show up to 5, omit section if unavailable
-    if let Some(memories_raw) = fetch_memories(&project_root) {
-        let lines: Vec<&str> = memories_raw
-            .lines()
-            .filter(|l| !l.trim().is_empty())
-            .collect();
-        if !lines.is_empty() {
-            println!("{} Memories:", "◆".cyan());
-            let shown = lines.iter().take(5);
-            for line in shown {
-                let truncated = if line.chars().count() > 80 {
-                    format!("{}…", line.chars().take(80).collect::<String>())
-                } else {
-                    line.to_string()
-                };
-                println!("  {} {}", "•".dimmed(), truncated);
-            }
-            if lines.len() > 5 {
-                println!(
-                    "  {} … and {} more, run `bd memories` to see all",
-                    "•".dimmed(),
-                    lines.len() - 5
-                );
-            }
+    // Memories from JSON-protocol hooks, then bd memories — show up to 5,
+    // omit section if none are available
+    let plugin_memories = plugin::collect_plugin_memories(&hook_outputs);
+    let memories_raw = fetch_memories(&project_root).unwrap_or_default();
+    let lines: Vec<&str> = plugin_memories
+        .iter()
+        .map(String::as_str)
+        .chain(memories_raw.lines())
+        .filter(|l| !l.trim().is_empty())
+        .collect();
+    if !lines.is_empty() {
+        println!("{} Memories:", "◆".cyan());
+        let shown = lines.iter().take(5);
+        for line in shown {
+            let truncated = if line.chars().count() > 80 {
+                format!("{}…", line.chars().take(80).collect::<String>())
+            } else {
+                line.to_string()
+            };
+            println!("  {} {}", "•".dimmed(), truncated);
+        }
+        if lines.len() > 5 {
+            println!(
+                "  {} … and {} more, run `bd memories` to see all",
+                "•".dimmed(),
+                lines.len() - 5
+            );
         }
     }
 
@@ -180,6 +221,16 @@ pub fn run(project: Option<String>) -> Result<()> {
     // Pipelines — surface available/active pipelines for the current project
     render_pipelines(&project_root, &phase);
 
+    // Reflection debt — one line once the project's policy says it is due.
+    let debt = reflect_debt(&project_root, &project_name);
+    if debt.is_due() {
+        println!(
+            "{} Reflect: {} — run `wai reflect`",
+            "⚠".yellow(),
+            debt.reason()
+        );
+    }
+
     // Doctor health summary — one line when not clean, silent when green.
     render_health_summary(&project_root);
 
@@ -187,6 +238,9 @@ pub fn run(project: Option<String>) -> Result<()> {
     if let Some(next_id) = suggested_next(&project_root) {
         println!("{} Suggested next: bd show {}", "→".cyan(), next_id);
     }
+    for s in plugin::collect_plugin_suggestions(&hook_outputs) {
+        println!("{} {}: {}", "→".cyan(), s.label, s.command);
+    }
 
     Ok(())
 }
@@ -196,26 +250,33 @@ fn render_json(
     project_root: &Path,
     project_name: &str,
     phase: &str,
-    resume_info: Option<(PathBuf, NaiveDate, String)>,
+    resume_info: Vec<(PathBuf, NaiveDate, String)>,
     hook_outputs: &[crate::plugin::HookOutput],
     spec_status: Option<crate::openspec::OpenSpecStatus>,
 ) -> Result<()> {
-    let (resume, handoff_summary, next_steps) =
-        if let Some((handoff_path, _, snippet)) = resume_info {
-            let steps = extract_next_steps(&handoff_path);
-            (true, Some(snippet), steps)
-        } else {
-            // Normal path: read latest handoff for summary only (no next steps shown).
-            let summary = find_latest_handoff(project_root, project_name)?.and_then(|hp| {
-                let (_, snippet) = read_handoff_summary(&hp);
-                if snippet.is_empty() {
-                    None
-                } else {
-                    Some(snippet)
-                }
-            });
-            (false, summary, Vec::new())
-        };
+    let resume = !resume_info.is_empty();
+    let (handoffs, next_steps): (Vec<PrimeHandoff>, Vec<String>) = if resume {
+        let steps = resume_info
+            .iter()
+            .flat_map(|(hp, _, _)| extract_next_steps(project_root, hp))
+            .collect();
+        let handoffs = resume_info
+            .into_iter()
+            .map(|(hp, date, snippet)| prime_handoff(&hp, date, snippet))
+            .collect();
+        (handoffs, steps)
+    } else {
+        // Normal path: read latest handoffs for summary only (no next steps shown).
+        let handoffs = find_latest_handoffs(project_root, project_name)?
+            .into_iter()
+            .filter_map(|hp| {
+                let (date, snippet) = read_handoff_summary(project_root, &hp);
+                (!snippet.is_empty()).then(|| prime_handoff(&hp, date, snippet))
+            })
+            .collect();
+        (handoffs, Vec::new())
+    };
+    let handoff_summary = handoffs.first().map(|h| h.summary.clone());
 
     let beads = hook_outputs
         .iter()
@@ -248,15 +309,29 @@ fn render_json(
 
     let plans = read_recent_plans(project_root, project_name, 3);
 
+    let debt = reflect_debt(project_root, project_name);
+    let mut suggestions = plugin::collect_plugin_suggestions(hook_outputs);
+    if debt.is_due() {
+        suggestions.push(Suggestion {
+            label: "Reflect on recent sessions".to_string(),
+            command: "wai reflect".to_string(),
+        });
+    }
+
     let payload = PrimePayload {
         project: Some(project_name.to_string()),
         phase: Some(phase.to_string()),
         resume,
         handoff_summary,
+        handoffs,
         next_steps,
         plans,
         beads,
         openspec,
+        plugin_status: plugin::collect_plugin_status(hook_outputs),
+        memories: plugin::collect_plugin_memories(hook_outputs),
+        reflect_debt: Some(debt.entry()),
+        suggestions,
     };
     print_envelope(genesis::envelope::EnvelopeKind::Ok, payload, vec![], vec![])
 }
@@ -278,39 +353,26 @@ fn parse_handoff_date_strict(path: &Path) -> Option<NaiveDate> {
     })
 }
 
-/// Read `.pending-resume` and resolve the handoff path it points to.
-///
-/// Returns `None` if the file is absent, the path is invalid, or the target
-/// handoff file does not exist on disk.
-pub fn read_pending_resume(project_dir: &Path) -> Option<PathBuf> {
-    let content = std::fs::read_to_string(project_dir.join(".pending-resume")).ok()?;
-    let relative = content.trim();
-    if relative.is_empty() {
-        return None;
-    }
-    let resolved = project_dir.join(relative);
-    if resolved.exists() {
-        Some(resolved)
-    } else {
-        None
-    }
-}
-
 /// Check the `.pending-resume` signal using a 12-hour freshness window.
 ///
-/// Returns `Some((handoff_path, date, snippet))` when the signal is fresh and
-/// the handoff has a non-empty snippet.  When the `.pending-resume` file exists
-/// but is older than 12 hours, prints a diagnostic note, deletes the stale
-/// file, and returns `None`.
+/// Returns `(handoff_path, date, snippet)` for every session entry that is
+/// fresh and whose handoff has a non-empty snippet. When the `.pending-resume`
+/// file exists but is older than 12 hours, prints a diagnostic note, deletes
+/// the stale file, and returns nothing. Entries dated before today are pruned.
 fn check_pending_resume(
+    project_root: &Path,
     project_dir: &Path,
-    pending_path: &Path,
-) -> Option<(PathBuf, NaiveDate, String)> {
+) -> Vec<(PathBuf, NaiveDate, String)> {
+    let pending_path = project_dir.join(PENDING_RESUME_FILE);
     // If the signal file doesn't exist there's nothing to do.
-    let meta = std::fs::metadata(pending_path).ok()?;
+    let Some(mtime) = std::fs::metadata(&pending_path)
+        .ok()
+        .and_then(|m| m.modified().ok())
+    else {
+        return Vec::new();
+    };
 
     // Determine the age of the .pending-resume file via its mtime.
-    let mtime = meta.modified().ok()?;
     let age = SystemTime::now()
         .duration_since(mtime)
         .unwrap_or(RESUME_WINDOW);
@@ -322,83 +384,143 @@ fn check_pending_resume(
             "note: stale resume signal found (created {}), skipping.",
             created_local.format("%Y-%m-%d %H:%M")
         );
-        let _ = std::fs::remove_file(pending_path);
-        return None;
+        let _ = std::fs::remove_file(&pending_path);
+        return Vec::new();
     }
 
-    // Fresh: resolve the handoff path from the file contents.
-    let hp = read_pending_resume(project_dir)?;
-    let date = parse_handoff_date_strict(&hp)?;
-
-    // A pending-resume from a previous day is stale even if the file is
-    // recently written (e.g. during tests or clock skew).
+    // Fresh: resolve the handoff paths from the file contents.
+    let listed = read_pending_resume(project_dir);
     let today = Local::now().date_naive();
-    if date < today {
-        let _ = std::fs::remove_file(pending_path);
-        return None;
+    let mut keep = Vec::new();
+    let mut fresh = Vec::new();
+    for hp in &listed {
+        let Some(date) = parse_handoff_date_strict(hp) else {
+            continue;
+        };
+        // An entry from a previous day is stale even if the file is recently
+        // written (e.g. another session closed today, or clock skew).
+        if date < today {
+            continue;
+        }
+        keep.push(hp.clone());
+        let (_, snippet) = read_handoff_summary(project_root, hp);
+        if !snippet.is_empty() {
+            fresh.push((hp.clone(), date, snippet));
+        }
     }
+    if keep.len() != listed.len() {
+        retain_pending_resume(project_dir, &keep);
+    }
+    fresh
+}
 
-    let (_, snippet) = read_handoff_summary(&hp);
-    if snippet.is_empty() {
-        return None;
+/// ` (<author/session>)` when several handoffs are shown together.
+fn session_suffix(handoff_path: &Path, labelled: bool) -> String {
+    if !labelled {
+        return String::new();
     }
-    Some((hp, date, snippet))
+    let content = std::fs::read_to_string(handoff_path).unwrap_or_default();
+    format!(" ({})", SessionIdentity::from_frontmatter(&content).label())
 }
 
-/// Extract lines from the `## Next Steps` section of a handoff file.
+fn prime_handoff(handoff_path: &Path, date: NaiveDate, summary: String) -> PrimeHandoff {
+    let content = std::fs::read_to_string(handoff_path).unwrap_or_default();
+    let identity = SessionIdentity::from_frontmatter(&content);
+    PrimeHandoff {
+        date: date.format("%Y-%m-%d").to_string(),
+        author: identity.author,
+        session: identity.session,
+        summary,
+    }
+}
+
+/// Extract lines from the next-steps section of a handoff file.
 ///
-/// Collects lines from after the heading until the next `##` heading or EOF.
-/// Skips blank lines and lines starting with `<!--`.
-pub fn extract_next_steps(handoff_path: &Path) -> Vec<String> {
+/// The section is `## Next Steps` unless the handoff was rendered from a
+/// template that names a different one. Collects lines from after the heading
+/// until the next `##` heading or EOF.
+pub fn extract_next_steps(project_root: &Path, handoff_path: &Path) -> Vec<String> {
     let Ok(content) = std::fs::read_to_string(handoff_path) else {
         return Vec::new();
     };
-    let mut in_section = false;
-    let mut items = Vec::new();
-    for line in content.lines() {
-        if line.starts_with("## Next Steps") {
-            in_section = true;
-            continue;
-        }
-        if in_section {
-            if line.starts_with("## ") {
-                break;
-            }
+    let sections = sections_for_handoff(project_root, &content);
+    let Some(range) = section_range(&content, &sections.next_steps) else {
+        return Vec::new();
+    };
+    content[range]
+        .lines()
+        .filter(|line| {
             let trimmed = line.trim();
-            if trimmed.is_empty() || trimmed.starts_with("<!--") {
-                continue;
-            }
-            items.push(line.to_string());
-        }
-    }
-    items
+            !trimmed.is_empty() && !trimmed.starts_with("<!--")
+        })
+        .map(str::to_string)
+        .collect()
 }
 
-/// Find the most recent handoff file for a project (sorted descending by filename).
-pub fn find_latest_handoff(project_root: &Path, project: &str) -> Result<Option<PathBuf>> {
+/// Find the latest handoff of each author/session on the most recent day
+/// any handoff was written, newest first.
+///
+/// The day comes from the `YYYY-MM-DD` file name prefix (falling back to the
+/// `date:` front matter field); within a day, modification time decides.
+pub fn find_latest_handoffs(project_root: &Path, project: &str) -> Result<Vec<PathBuf>> {
     let handoffs_dir = projects_dir(project_root).join(project).join(HANDOFFS_DIR);
 
     if !handoffs_dir.exists() {
-        return Ok(None);
+        return Ok(Vec::new());
     }
 
     let entries = std::fs::read_dir(&handoffs_dir).into_diagnostic()?;
-    let mut files: Vec<PathBuf> = entries
+    let files: Vec<(NaiveDate, SystemTime, PathBuf)> = entries
         .filter_map(|e| e.ok())
         .filter(|e| e.path().extension().and_then(|x| x.to_str()) == Some("md"))
-        .map(|e| e.path())
+        .map(|e| {
+            let path = e.path();
+            let day = handoff_day(&path);
+            let mtime = e
+                .metadata()
+                .and_then(|m| m.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            (day, mtime, path)
+        })
         .collect();
 
-    files.sort_by(|a, b| b.cmp(a)); // descending: newest filename first
-    Ok(files.into_iter().next())
+    let Some(latest_day) = files.iter().map(|(day, _, _)| *day).max() else {
+        return Ok(Vec::new());
+    };
+    let mut newest: HashMap<Option<String>, (SystemTime, PathBuf)> = HashMap::new();
+    for (_, mtime, path) in files.into_iter().filter(|(day, _, _)| *day == latest_day) {
+        let content = std::fs::read_to_string(&path).unwrap_or_default();
+        let key = SessionIdentity::from_frontmatter(&content).key();
+        match newest.get(&key) {
+            Some((best, best_path)) if (*best, best_path) >= (mtime, &path) => {}
+            _ => {
+                newest.insert(key, (mtime, path));
+            }
+        }
+    }
+
+    let mut latest: Vec<(SystemTime, PathBuf)> = newest.into_values().collect();
+    latest.sort_by(|a, b| b.cmp(a)); // descending: newest first
+    Ok(latest.into_iter().map(|(_, path)| path).collect())
+}
+
+fn handoff_day(path: &Path) -> NaiveDate {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    name.get(..10)
+        .and_then(|prefix| NaiveDate::parse_from_str(prefix, "%Y-%m-%d").ok())
+        .or_else(|| parse_handoff_date_strict(path))
+        .unwrap_or(NaiveDate::MIN)
 }
 
 /// Parse frontmatter date + first paragraph snippet (up to 80 chars) from a handoff file.
 ///
+/// The snippet comes from the summary section declared by the handoff's
+/// template, if any.
+///
 /// Returns `(date, snippet)`. If frontmatter is missing or invalid, returns an empty
 /// snippet (caller omits the handoff line). If frontmatter parses but no paragraph is
 /// found, snippet is `"no summary yet"`.
-pub fn read_handoff_summary(path: &Path) -> (NaiveDate, String) {
+pub fn read_handoff_summary(project_root: &Path, path: &Path) -> (NaiveDate, String) {
     let fallback_date = Local::now().date_naive();
 
     let Ok(content) = std::fs::read_to_string(path) else {
@@ -429,8 +551,15 @@ pub fn read_handoff_summary(path: &Path) -> (NaiveDate, String) {
         .and_then(|date_str| NaiveDate::parse_from_str(&date_str, "%Y-%m-%d").ok())
         .unwrap_or(fallback_date);
 
-    // Find first paragraph in the body (non-empty, non-heading, non-code-fence line)
-    let snippet = find_first_paragraph(rest);
+    // Find first paragraph (non-empty, non-heading, non-code-fence line) in the
+    // template's summary section, or in the whole body when none is declared.
+    let summary_section = sections_for_handoff(project_root, &content)
+        .summary
+        .and_then(|heading| section_range(rest, &heading));
+    let snippet = match summary_section {
+        Some(range) => find_first_paragraph(&rest[range]),
+        None => find_first_paragraph(rest),
+    };
 
     (date, snippet)
 }
@@ -0,0 +1,462 @@
+use std::path::Path;
+use std::time::SystemTime;
+
+use miette::Result;
+use owo_colors::OwoColorize;
+
+use crate::config::{RESEARCH_DIR, projects_dir};
+use crate::context::current_context;
+use crate::freshness::scan_freshness;
+use crate::json::{PrimePackItem, PrimePackPayload};
+use crate::managed_block::CHARS_PER_TOKEN;
+use crate::output::print_envelope_ok;
+
+use super::super::handoff::template::{section_range, sections_for_handoff, split_frontmatter};
+use super::super::pipeline::pipeline_current_status;
+use super::super::why::context::{Artifact, ArtifactKind, truncate_context};
+use super::{extract_next_steps, find_latest_handoffs, read_handoff_summary};
+
+/// Most recent research artifacts considered for the pack.
+const MAX_RESEARCH: usize = 10;
+
+/// Words shorter than this are left out of the research ranking query.
+const MIN_QUERY_TERM: usize = 4;
+
+/// What a pack item carries, in rank order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PackKind {
+    Project,
+    Handoff,
+    OpenDecisions,
+    StaleArtifacts,
+    PipelineStep,
+    Research,
+}
+
+impl PackKind {
+    fn name(self) -> &'static str {
+        match self {
+            PackKind::Project => "project",
+            PackKind::Handoff => "handoff",
+            PackKind::OpenDecisions => "open-decisions",
+            PackKind::StaleArtifacts => "stale-artifacts",
+            PackKind::PipelineStep => "pipeline-step",
+            PackKind::Research => "research",
+        }
+    }
+}
+
+/// One candidate piece of context.
+#[derive(Debug, Clone)]
+struct PackItem {
+    kind: PackKind,
+    /// Where the content came from (a path, run ID, or project name).
+    source: String,
+    content: String,
+    /// Shorter rendering used when `content` does not fit.
+    compact: Option<String>,
+}
+
+/// The assembled pack: items that fit the budget, in rank order, and the
+/// ones that were left out.
+#[derive(Debug, Default)]
+struct Pack {
+    included: Vec<(PackItem, bool)>,
+    dropped: Vec<PackItem>,
+    used_chars: usize,
+}
+
+fn tokens(chars: usize) -> usize {
+    chars.div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Print a context pack for `project` that fits in `budget` tokens.
+pub fn run(project_root: &Path, project: &str, phase: &str, budget: usize) -> Result<()> {
+    let ranked = ranked_items(project_root, project, phase);
+    let research = research_candidates(project_root, project);
+    let query = research_query(&ranked, project, phase);
+    let pack = assemble(
+        ranked,
+        research,
+        &query,
+        budget.saturating_mul(CHARS_PER_TOKEN),
+    );
+
+    if current_context().json {
+        return print_envelope_ok(PrimePackPayload {
+            project: project.to_string(),
+            phase: phase.to_string(),
+            budget_tokens: budget,
+            used_tokens: tokens(pack.used_chars),
+            included: pack
+                .included
+                .iter()
+                .map(|(item, condensed)| {
+                    let content = if *condensed {
+                        item.compact.clone().unwrap_or_default()
+                    } else {
+                        item.content.clone()
+                    };
+                    PrimePackItem {
+                        kind: item.kind.name().to_string(),
+                        source: item.source.clone(),
+                        tokens: tokens(content.len()),
+                        condensed: *condensed,
+                        content: Some(content),
+                    }
+                })
+                .collect(),
+            dropped: pack
+                .dropped
+                .iter()
+                .map(|item| PrimePackItem {
+                    kind: item.kind.name().to_string(),
+                    source: item.source.clone(),
+                    tokens: tokens(item.content.len()),
+                    condensed: false,
+                    content: None,
+                })
+                .collect(),
+        });
+    }
+
+    println!(
+        "{} wai prime — context pack (~{}/{} tokens)",
+        "◆".cyan(),
+        tokens(pack.used_chars),
+        budget
+    );
+    for (item, condensed) in &pack.included {
+        let content = if *condensed {
+            item.compact.as_deref().unwrap_or_default()
+        } else {
+            &item.content
+        };
+        println!();
+        println!("{}", content.trim_end());
+    }
+    if !pack.dropped.is_empty() {
+        println!();
+        let names: Vec<String> = pack
+            .dropped
+            .iter()
+            .map(|item| format!("{} {}", item.kind.name(), item.source))
+            .collect();
+        println!(
+            "{} Dropped to fit the budget: {}",
+            "→".cyan(),
+            names.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Fill the budget in rank order. Fixed items that do not fit are replaced by
+/// their compact form when that fits, otherwise dropped; research candidates
+/// share whatever is left through [`truncate_context`].
+fn assemble(ranked: Vec<PackItem>, research: Vec<Artifact>, query: &str, max_chars: usize) -> Pack {
+    let mut pack = Pack::default();
+    for item in ranked {
+        let remaining = max_chars.saturating_sub(pack.used_chars);
+        if item.content.len() <= remaining {
+            pack.used_chars += item.content.len();
+            pack.included.push((item, false));
+        } else if let Some(compact) = item.compact.as_ref().filter(|c| c.len() <= remaining) {
+            pack.used_chars += compact.len();
+            pack.included.push((item, true));
+        } else {
+            pack.dropped.push(item);
+        }
+    }
+
+    // Research shares what is left, ranked against the pack by truncate_context.
+    let remaining = max_chars.saturating_sub(pack.used_chars);
+    let (selected, _) = truncate_context(research.clone(), query, remaining);
+    for artifact in &selected {
+        pack.used_chars += artifact.content.len();
+        pack.included.push((research_item(artifact), false));
+    }
+    for artifact in &research {
+        if !selected.iter().any(|a| a.rel_path == artifact.rel_path) {
+            pack.dropped.push(research_item(artifact));
+        }
+    }
+    pack
+}
+
+fn research_item(artifact: &Artifact) -> PackItem {
+    PackItem {
+        kind: PackKind::Research,
+        source: artifact.rel_path.clone(),
+        content: artifact.content.clone(),
+        compact: None,
+    }
+}
+
+/// Fixed items in rank order: project, handoffs, open decisions, stale
+/// artifact warnings, and the active pipeline step.
+fn ranked_items(project_root: &Path, project: &str, phase: &str) -> Vec<PackItem> {
+    let mut items = vec![PackItem {
+        kind: PackKind::Project,
+        source: project.to_string(),
+        content: format!("# Project: {} [{}]\n", project, phase),
+        compact: None,
+    }];
+
+    let handoffs = find_latest_handoffs(project_root, project).unwrap_or_default();
+    let mut open = Vec::new();
+    for path in &handoffs {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        let source = relative(project_root, path);
+        let (date, summary) = read_handoff_summary(project_root, path);
+        let mut compact = format!("## Handoff {} ({})\n\n{}\n", date, source, summary);
+        let steps = extract_next_steps(project_root, path);
+        if !steps.is_empty() {
+            compact.push_str("\nNext Steps:\n");
+            for step in &steps {
+                compact.push_str(step);
+                compact.push('\n');
+            }
+        }
+        items.push(PackItem {
+            kind: PackKind::Handoff,
+            source,
+            content: format!(
+                "## Handoff {} ({})\n\n{}\n",
+                date,
+                relative(project_root, path),
+                strip_comments(strip_frontmatter(&content)).trim()
+            ),
+            compact: Some(compact),
+        });
+
+        let sections = sections_for_handoff(project_root, &content);
+        if let Some(range) = section_range(&content, &sections.open_questions) {
+            open.extend(
+                content[range]
+                    .lines()
+                    .map(str::trim)
+                    .filter(|l| !l.is_empty() && !l.starts_with("<!--"))
+                    .map(str::to_string),
+            );
+        }
+    }
+
+    if !open.is_empty() {
+        items.push(PackItem {
+            kind: PackKind::OpenDecisions,
+            source: project.to_string(),
+            content: format!("## Open Decisions\n\n{}\n", open.join("\n")),
+            compact: None,
+        });
+    }
+
+    let marker = format!(".wai/projects/{}/", project);
+    let stale: Vec<String> = scan_freshness(project_root)
+        .stale
+        .into_iter()
+        .filter(|s| s.artifact.replace('\\', "/").contains(&marker))
+        .map(|s| {
+            let artifact = relative(project_root, Path::new(&s.artifact));
+            let decision = s
+                .decision_point
+                .map(|d| format!(" — {}", d))
+                .unwrap_or_default();
+            format!(
+                "- {}{} (changed: {})",
+                artifact,
+                decision,
+                s.changed_paths.join(", ")
+            )
+        })
+        .collect();
+    if !stale.is_empty() {
+        items.push(PackItem {
+            kind: PackKind::StaleArtifacts,
+            source: project.to_string(),
+            content: format!(
+                "## Stale Artifacts\n\nThese may no longer match the code they describe:\n{}\n",
+                stale.join("\n")
+            ),
+            compact: None,
+        });
+    }
+
+    if let Ok(Some(current)) = pipeline_current_status(project_root)
+        && let Some(step) = current.step
+    {
+        let pipeline = current.pipeline.unwrap_or_default();
+        let mut content = format!(
+            "## Pipeline Step: {} {}/{} ({})\n\n{}\n",
+            pipeline, step.index, step.total, step.id, step.prompt
+        );
+        if let Some(gate) = &current.gate_summary {
+            content.push_str(&format!("\nGate: {}\n", gate));
+        }
+        items.push(PackItem {
+            kind: PackKind::PipelineStep,
+            source: current.run_id.unwrap_or(pipeline),
+            content,
+            compact: None,
+        });
+    }
+
+    items
+}
+
+/// Research artifacts of `project`, most recent first, rendered as pack
+/// sections.
+fn research_candidates(project_root: &Path, project: &str) -> Vec<Artifact> {
+    let dir = projects_dir(project_root).join(project).join(RESEARCH_DIR);
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+    let mut artifacts: Vec<Artifact> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().and_then(|x| x.to_str()) == Some("md"))
+        .filter_map(|e| {
+            let path = e.path();
+            let rel_path = relative(project_root, &path);
+            let raw = std::fs::read_to_string(&path).ok()?;
+            Some(Artifact {
+                content: format!(
+                    "## Research: {}\n\n{}\n",
+                    rel_path,
+                    strip_frontmatter(&raw).trim()
+                ),
+                rel_path,
+                kind: ArtifactKind::Research,
+                modified: e.metadata().ok().and_then(|m| m.modified().ok()),
+            })
+        })
+        .collect();
+    artifacts.sort_by(|a, b| {
+        b.modified
+            .unwrap_or(SystemTime::UNIX_EPOCH)
+            .cmp(&a.modified.unwrap_or(SystemTime::UNIX_EPOCH))
+    });
+    artifacts.truncate(MAX_RESEARCH);
+    artifacts
+}
+
+/// Terms the research is ranked against: the project, its phase, and what
+/// the handoffs, open decisions and pipeline step talk about.
+fn research_query(items: &[PackItem], project: &str, phase: &str) -> String {
+    let mut terms = vec![project.to_string(), phase.to_string()];
+    for item in items.iter().filter(|i| i.kind != PackKind::Project) {
+        terms.extend(
+            item.content
+                .split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_')
+                .filter(|w| w.chars().count() >= MIN_QUERY_TERM)
+                .map(str::to_lowercase),
+        );
+    }
+    terms.sort();
+    terms.dedup();
+    terms.join(" ")
+}
+
+fn strip_frontmatter(content: &str) -> &str {
+    split_frontmatter(content)
+        .map(|(_, body)| body)
+        .unwrap_or(content)
+}
+
+fn strip_comments(content: &str) -> String {
+    content
+        .lines()
+        .filter(|l| !l.trim_start().starts_with("<!--"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn relative(project_root: &Path, path: &Path) -> String {
+    path.strip_prefix(project_root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(kind: PackKind, source: &str, content: &str, compact: Option<&str>) -> PackItem {
+        PackItem {
+            kind,
+            source: source.to_string(),
+            content: content.to_string(),
+            compact: compact.map(str::to_string),
+        }
+    }
+
+    fn research(path: &str, content: &str) -> Artifact {
+        Artifact {
+            rel_path: path.to_string(),
+            kind: ArtifactKind::Research,
+            content: content.to_string(),
+            modified: None,
+        }
+    }
+
+    fn sources(items: &[(PackItem, bool)]) -> Vec<&str> {
+        items.iter().map(|(i, _)| i.source.as_str()).collect()
+    }
+
+    #[test]
+    fn assemble_keeps_rank_order_and_skips_what_does_not_fit() {
+        let ranked = vec![
+            item(PackKind::Project, "p", "0123456789", None),
+            item(PackKind::Handoff, "h", &"x".repeat(100), None),
+            item(PackKind::OpenDecisions, "o", "0123456789", None),
+        ];
+        let pack = assemble(ranked, Vec::new(), "", 40);
+        assert_eq!(sources(&pack.included), vec!["p", "o"]);
+        assert_eq!(pack.dropped.len(), 1);
+        assert_eq!(pack.dropped[0].source, "h");
+        assert_eq!(pack.used_chars, 20);
+    }
+
+    #[test]
+    fn assemble_falls_back_to_compact_form() {
+        let ranked = vec![item(
+            PackKind::Handoff,
+            "h",
+            &"x".repeat(100),
+            Some("short summary"),
+        )];
+        let pack = assemble(ranked, Vec::new(), "", 40);
+        assert_eq!(pack.included.len(), 1);
+        assert!(pack.included[0].1, "compact form should be used");
+        assert_eq!(pack.used_chars, "short summary".len());
+    }
+
+    #[test]
+    fn assemble_ranks_research_by_query_within_remaining_budget() {
+        let ranked = vec![item(PackKind::Project, "p", "0123456789", None)];
+        let research = vec![
+            research("r/newest.md", &format!("unrelated {}", "y".repeat(40))),
+            research("r/older.md", &format!("caching layer {}", "z".repeat(40))),
+        ];
+        let pack = assemble(ranked, research, "caching", 70);
+        assert_eq!(sources(&pack.included), vec!["p", "r/older.md"]);
+        assert_eq!(pack.dropped.len(), 1);
+        assert_eq!(pack.dropped[0].source, "r/newest.md");
+    }
+
+    #[test]
+    fn research_query_skips_short_words() {
+        let items = vec![item(
+            PackKind::Handoff,
+            "h",
+            "Fix the cache invalidation bug",
+            None,
+        )];
+        let query = research_query(&items, "api", "implement");
+        assert!(query.contains("cache"));
+        assert!(query.contains("invalidation"));
+        assert!(!query.split(' ').any(|t| t == "the" || t == "fix"));
+    }
+}
@@ -8,6 +8,7 @@ use walkdir::WalkDir;
 use crate::config::wai_dir;
 use crate::managed_block::read_reflect_block;
 use crate::plugin::fetch_memories;
+use crate::trace::{Fidelity, discover_traces, render_trace, select_for_reflect};
 
 /// Budget allocations for context tiers (conversation, handoffs, secondary, previous reflections).
 const CONVERSATION_BUDGET: usize = 30_000;
@@ -15,11 +16,29 @@ const HANDOFF_BUDGET: usize = 40_000;
 const SECONDARY_BUDGET: usize = 30_000;
 const PREVIOUS_REFLECTIONS_BUDGET: usize = 20_000;
 
+/// At most this many local traces share the conversation budget.
+const MAX_REFLECT_TRACES: usize = 3;
+/// Stop adding traces once less than this much conversation budget remains.
+const MIN_TRACE_BUDGET: usize = 2_000;
+
+/// Where the highest-priority (conversation) context tier comes from.
+#[derive(Debug, Clone, Copy)]
+pub enum ConversationSource<'a> {
+    /// An explicit transcript file passed with `--conversation`.
+    File(&'a Path),
+    /// Local agent traces for this repository that ended on or after `since`.
+    Traces { since: Option<chrono::NaiveDate> },
+    /// No conversation tier.
+    None,
+}
+
 /// All context gathered before calling the LLM.
 #[derive(Debug)]
 pub struct ReflectContext {
     /// Conversation transcript content (truncated to budget from the top).
     pub conversation: Option<String>,
+    /// Auto-selected local session traces, used when no transcript is given.
+    pub traces: Vec<TraceEntry>,
     /// Handoff artifacts, newest-first, concatenated up to budget.
     pub handoffs: Vec<HandoffEntry>,
     /// Number of handoff files actually loaded (for YAML front-matter).
@@ -47,6 +66,14 @@ pub struct SecondaryEntry {
     pub content: String,
 }
 
+/// A local agent session trace rendered for the prompt.
+#[derive(Debug)]
+pub struct TraceEntry {
+    pub id: String,
+    pub fidelity: Fidelity,
+    pub content: String,
+}
+
 /// A previous reflection file loaded from `.wai/resources/reflections/`.
 #[derive(Debug)]
 pub struct ReflectionEntry {
@@ -292,15 +319,44 @@ pub fn read_previous_reflections(project_root: &Path, budget: usize) -> Vec<Refl
     result
 }
 
+/// Select recent local traces for `project_root` and render them newest-first
+/// until `budget` chars are used.
+pub fn read_local_traces(
+    project_root: &Path,
+    since: Option<chrono::NaiveDate>,
+    budget: usize,
+) -> Vec<TraceEntry> {
+    let traces = discover_traces(project_root);
+    let mut remaining = budget;
+    let mut result = Vec::new();
+    for trace in select_for_reflect(&traces, since, MAX_REFLECT_TRACES) {
+        if remaining < MIN_TRACE_BUDGET {
+            break;
+        }
+        let content = truncate_from_top(&render_trace(trace), remaining);
+        remaining = remaining.saturating_sub(content.len());
+        result.push(TraceEntry {
+            id: trace.id(),
+            fidelity: trace.fidelity,
+            content,
+        });
+    }
+    result
+}
+
 /// Gather all context for a reflect run.
 pub fn gather_reflect_context(
     project_root: &Path,
-    conversation_path: Option<&Path>,
+    conversation_source: ConversationSource,
     output_targets: &[PathBuf],
 ) -> Result<ReflectContext> {
-    let conversation = match conversation_path {
-        Some(p) => Some(read_conversation(p, CONVERSATION_BUDGET)?),
-        None => None,
+    let (conversation, traces) = match conversation_source {
+        ConversationSource::File(p) => (Some(read_conversation(p, CONVERSATION_BUDGET)?), vec![]),
+        ConversationSource::Traces { since } => (
+            None,
+            read_local_traces(project_root, since, CONVERSATION_BUDGET),
+        ),
+        ConversationSource::None => (None, vec![]),
     };
 
     let handoffs = read_handoffs(project_root, HANDOFF_BUDGET);
@@ -317,6 +373,7 @@ pub fn gather_reflect_context(
 
     Ok(ReflectContext {
         conversation,
+        traces,
         handoffs,
         handoff_count,
         secondary,
@@ -338,7 +395,12 @@ pub fn count_handoffs_since(project_handoffs_dir: &Path, since_date: &str) -> u3
     } else {
         parse_date_to_system_time(since_date)
     };
+    count_handoffs_after(project_handoffs_dir, cutoff)
+}
 
+/// Count handoff `.md` files in `project_handoffs_dir` modified after
+/// `cutoff`, or all of them when `cutoff` is `None`.
+pub fn count_handoffs_after(project_handoffs_dir: &Path, cutoff: Option<SystemTime>) -> u32 {
     if !project_handoffs_dir.exists() {
         return 0;
     }
@@ -594,7 +656,7 @@ mod tests {
         let dir = tmp();
         fs::write(dir.path().join("CLAUDE.md"), "# Claude\n").unwrap();
         let targets = vec![dir.path().join("CLAUDE.md")];
-        let ctx = gather_reflect_context(dir.path(), None, &targets).unwrap();
+        let ctx = gather_reflect_context(dir.path(), ConversationSource::None, &targets).unwrap();
         assert_eq!(ctx.handoff_count, 0);
     }
 
@@ -605,8 +667,55 @@ mod tests {
         make_wai_handoff(dir.path(), "proj", "h1.md", "handoff 1");
         make_wai_handoff(dir.path(), "proj", "h2.md", "handoff 2");
         let targets = vec![dir.path().join("CLAUDE.md")];
-        let ctx = gather_reflect_context(dir.path(), None, &targets).unwrap();
+        let ctx = gather_reflect_context(dir.path(), ConversationSource::None, &targets).unwrap();
         assert_eq!(ctx.handoff_count, 2);
         assert_eq!(ctx.handoff_count, ctx.handoffs.len());
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn gather_reflect_context_uses_local_traces_without_transcript() {
+        let dir = tmp();
+        let home = tmp();
+        let cwd = dir.path().display().to_string();
+        let line = serde_json::json!({
+            "type": "user",
+            "cwd": cwd,
+            "sessionId": "sess-1",
+            "timestamp": "2026-10-01T10:00:00Z",
+            "message": {"role": "user", "content": "why does the build fail?"}
+        });
+        // Claude Code names the directory after the encoded working directory.
+        let encoded: String = cwd
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        let session_dir = home.path().join(".claude/projects").join(encoded);
+        fs::create_dir_all(&session_dir).unwrap();
+        fs::write(session_dir.join("sess-1.jsonl"), line.to_string()).unwrap();
+
+        let prev = std::env::var_os("WAI_TRACE_HOME");
+        unsafe { std::env::set_var("WAI_TRACE_HOME", home.path()) };
+        let ctx =
+            gather_reflect_context(dir.path(), ConversationSource::Traces { since: None }, &[])
+                .unwrap();
+        let later = gather_reflect_context(
+            dir.path(),
+            ConversationSource::Traces {
+                since: chrono::NaiveDate::from_ymd_opt(2026, 10, 2),
+            },
+            &[],
+        )
+        .unwrap();
+        match prev {
+            Some(v) => unsafe { std::env::set_var("WAI_TRACE_HOME", v) },
+            None => unsafe { std::env::remove_var("WAI_TRACE_HOME") },
+        }
+
+        assert!(ctx.conversation.is_none());
+        assert_eq!(ctx.traces.len(), 1);
+        assert_eq!(ctx.traces[0].id, "claude-code:sess-1");
+        assert!(ctx.traces[0].content.contains("why does the build fail?"));
+        assert!(later.traces.is_empty());
+    }
 }
@@ -0,0 +1,277 @@
+use std::path::Path;
+use std::time::SystemTime;
+
+use chrono::{DateTime, Local, NaiveDate};
+
+use crate::config::{HANDOFFS_DIR, ProjectConfig, ReflectPolicy, projects_dir};
+use crate::json::ReflectDebtEntry;
+
+use super::context::{count_handoffs_after, count_handoffs_since};
+use super::meta::read_reflect_meta;
+
+/// Handoffs that may pile up before a reflection is due when `[reflect]`
+/// does not say otherwise.
+pub const DEFAULT_EVERY_HANDOFFS: u32 = 5;
+
+/// Unreflected work for one project, measured against its reflection policy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReflectDebt {
+    pub project: String,
+    /// Date of the last reflection (`YYYY-MM-DD`), if the project has one.
+    pub last_reflected: Option<String>,
+    /// Handoffs written since the last reflection.
+    pub handoffs: u32,
+    /// Days since the last reflection, or since the oldest handoff when the
+    /// project was never reflected on.
+    pub days: Option<i64>,
+    /// Effective handoff limit; `None` when disabled.
+    pub every_handoffs: Option<u32>,
+    /// Effective day limit; `None` when disabled.
+    pub every_days: Option<u32>,
+}
+
+impl ReflectDebt {
+    /// Evaluate `policy` against the given counters. Unset limits fall back to
+    /// the defaults and `0` disables a limit.
+    pub fn new(
+        project: &str,
+        last_reflected: Option<String>,
+        handoffs: u32,
+        days: Option<i64>,
+        policy: &ReflectPolicy,
+    ) -> Self {
+        Self {
+            project: project.to_string(),
+            last_reflected,
+            handoffs,
+            days,
+            every_handoffs: Some(policy.every_handoffs.unwrap_or(DEFAULT_EVERY_HANDOFFS))
+                .filter(|n| *n > 0),
+            every_days: policy.every_days.filter(|n| *n > 0),
+        }
+    }
+
+    fn handoffs_due(&self) -> bool {
+        self.every_handoffs.is_some_and(|n| self.handoffs >= n)
+    }
+
+    fn days_due(&self) -> bool {
+        // Time alone is not debt: there must be new sessions to reflect on.
+        self.handoffs > 0
+            && matches!((self.every_days, self.days), (Some(n), Some(d)) if d >= i64::from(n))
+    }
+
+    /// True once either limit of the policy has been reached.
+    pub fn is_due(&self) -> bool {
+        self.handoffs_due() || self.days_due()
+    }
+
+    /// One-line explanation, e.g. `6 handoffs since last reflection (policy: every 5 handoffs)`.
+    pub fn reason(&self) -> String {
+        let since = if self.last_reflected.is_some() {
+            "since last reflection"
+        } else {
+            "and no reflection yet"
+        };
+        let sessions = format!(
+            "{} handoff{}",
+            self.handoffs,
+            if self.handoffs == 1 { "" } else { "s" }
+        );
+        let mut limits = Vec::new();
+        if let Some(n) = self.every_handoffs {
+            limits.push(format!("every {} handoffs", n));
+        }
+        if let Some(n) = self.every_days {
+            limits.push(format!("every {} days", n));
+        }
+        let policy = if limits.is_empty() {
+            "none".to_string()
+        } else {
+            limits.join(" or ")
+        };
+        match self.days {
+            Some(days) if self.days_due() && !self.handoffs_due() => format!(
+                "{} over {} days {} (policy: {})",
+                sessions, days, since, policy
+            ),
+            _ => format!("{} {} (policy: {})", sessions, since, policy),
+        }
+    }
+
+    /// JSON form used by `wai status` and `wai prime`.
+    pub fn entry(&self) -> ReflectDebtEntry {
+        ReflectDebtEntry {
+            project: self.project.clone(),
+            handoffs: self.handoffs,
+            days: self.days,
+            last_reflected: self.last_reflected.clone(),
+            every_handoffs: self.every_handoffs,
+            every_days: self.every_days,
+            due: self.is_due(),
+            reason: self.reason(),
+        }
+    }
+}
+
+/// Effective reflection policy for `project` from `.wai/config.toml`.
+pub fn reflect_policy(project_root: &Path, project: &str) -> ReflectPolicy {
+    ProjectConfig::load(project_root)
+        .ok()
+        .and_then(|config| config.reflect)
+        .map(|reflect| reflect.for_project(project))
+        .unwrap_or_default()
+}
+
+/// Measure the reflection debt of `project`.
+pub fn reflect_debt(project_root: &Path, project: &str) -> ReflectDebt {
+    let proj_dir = projects_dir(project_root).join(project);
+    let handoffs_dir = proj_dir.join(HANDOFFS_DIR);
+    let meta = read_reflect_meta(&proj_dir).ok().flatten();
+    // Prefer the exact reflection time so handoffs written earlier the same
+    // day are not counted again; older meta files only carry the date.
+    let reflected_at = meta
+        .as_ref()
+        .and_then(|m| m.reflected_at.as_deref())
+        .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+        .map(SystemTime::from);
+    let last_reflected = meta.map(|m| m.last_reflected).filter(|d| !d.is_empty());
+    let handoffs = match reflected_at {
+        Some(at) => count_handoffs_after(&handoffs_dir, Some(at)),
+        None => count_handoffs_since(&handoffs_dir, last_reflected.as_deref().unwrap_or("")),
+    };
+
+    let today = Local::now().date_naive();
+    let since = match &last_reflected {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d").ok(),
+        None => oldest_handoff_date(&handoffs_dir),
+    };
+    let days = since.map(|d| (today - d).num_days());
+
+    ReflectDebt::new(
+        project,
+        last_reflected,
+        handoffs,
+        days,
+        &reflect_policy(project_root, project),
+    )
+}
+
+/// Debt of every project in the workspace, sorted by project name.
+pub fn workspace_debts(project_root: &Path) -> Vec<ReflectDebt> {
+    let mut projects = crate::commands::list_projects(project_root);
+    projects.sort();
+    projects
+        .iter()
+        .map(|name| reflect_debt(project_root, name))
+        .collect()
+}
+
+fn oldest_handoff_date(handoffs_dir: &Path) -> Option<NaiveDate> {
+    std::fs::read_dir(handoffs_dir)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().and_then(|x| x.to_str()) == Some("md"))
+        .filter_map(|e| e.metadata().ok()?.modified().ok())
+        .min()
+        .map(|t: SystemTime| DateTime::<Local>::from(t).date_naive())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(every_handoffs: Option<u32>, every_days: Option<u32>) -> ReflectPolicy {
+        ReflectPolicy {
+            every_handoffs,
+            every_days,
+        }
+    }
+
+    #[test]
+    fn default_policy_is_due_at_five_handoffs() {
+        let debt = ReflectDebt::new("p", None, 4, Some(30), &ReflectPolicy::default());
+        assert!(!debt.is_due());
+        let debt = ReflectDebt::new("p", None, 5, Some(30), &ReflectPolicy::default());
+        assert!(debt.is_due());
+        assert_eq!(
+            debt.reason(),
+            "5 handoffs and no reflection yet (policy: every 5 handoffs)"
+        );
+    }
+
+    #[test]
+    fn day_limit_needs_new_handoffs() {
+        let p = policy(Some(10), Some(14));
+        let last = Some("2026-01-01".to_string());
+        assert!(!ReflectDebt::new("p", last.clone(), 0, Some(40), &p).is_due());
+        let debt = ReflectDebt::new("p", last.clone(), 2, Some(40), &p);
+        assert!(debt.is_due());
+        assert_eq!(
+            debt.reason(),
+            "2 handoffs over 40 days since last reflection (policy: every 10 handoffs or every 14 days)"
+        );
+        assert!(!ReflectDebt::new("p", last, 2, Some(13), &p).is_due());
+    }
+
+    #[test]
+    fn zero_disables_a_limit() {
+        let p = policy(Some(0), None);
+        let debt = ReflectDebt::new("p", None, 50, Some(400), &p);
+        assert!(!debt.is_due());
+        assert_eq!(debt.every_handoffs, None);
+    }
+
+    #[test]
+    fn project_override_replaces_only_what_it_sets() {
+        let config: crate::config::ReflectConfig = toml::from_str(
+            "every_handoffs = 8\nevery_days = 30\n\n[projects.api]\nevery_days = 7\n",
+        )
+        .unwrap();
+        assert_eq!(config.for_project("api"), policy(Some(8), Some(7)));
+        assert_eq!(config.for_project("web"), policy(Some(8), Some(30)));
+    }
+
+    #[test]
+    fn reflect_debt_reads_meta_and_handoffs() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = tmp.path();
+        let handoffs = projects_dir(root).join("demo").join(HANDOFFS_DIR);
+        std::fs::create_dir_all(&handoffs).unwrap();
+        for i in 0..3 {
+            std::fs::write(handoffs.join(format!("h{}.md", i)), "x").unwrap();
+        }
+
+        let debt = reflect_debt(root, "demo");
+        assert_eq!(debt.handoffs, 3);
+        assert_eq!(debt.last_reflected, None);
+        assert_eq!(debt.days, Some(0));
+        assert!(!debt.is_due());
+
+        super::super::meta::write_reflect_meta(
+            &projects_dir(root).join("demo"),
+            &super::super::meta::ReflectMeta {
+                last_reflected: "9999-12-31".to_string(),
+                session_count: 3,
+                reflected_at: None,
+            },
+        )
+        .unwrap();
+        let debt = reflect_debt(root, "demo");
+        assert_eq!(debt.handoffs, 0);
+        assert_eq!(debt.last_reflected.as_deref(), Some("9999-12-31"));
+
+        // A same-day reflection clears the handoffs written before it.
+        let now = Local::now();
+        super::super::meta::write_reflect_meta(
+            &projects_dir(root).join("demo"),
+            &super::super::meta::ReflectMeta {
+                last_reflected: now.format("%Y-%m-%d").to_string(),
+                session_count: 4,
+                reflected_at: Some((now + chrono::Duration::seconds(1)).to_rfc3339()),
+            },
+        )
+        .unwrap();
+        assert_eq!(reflect_debt(root, "demo").handoffs, 0);
+    }
+}
@@ -7,6 +7,9 @@ use miette::{IntoDiagnostic, Result};
 pub struct ReflectMeta {
     pub last_reflected: String,
     pub session_count: u32,
+    /// RFC 3339 timestamp of the last reflection. Absent in files written by
+    /// older versions, which only recorded the date.
+    pub reflected_at: Option<String>,
 }
 
 /// Read `.reflect-meta` TOML from `project_dir`. Returns `None` if the file
@@ -28,20 +31,28 @@ pub fn read_reflect_meta(project_dir: &Path) -> Result<Option<ReflectMeta>> {
         .get("session_count")
         .and_then(|v| v.as_integer())
         .unwrap_or(0) as u32;
+    let reflected_at = table
+        .get("reflected_at")
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
 
     Ok(Some(ReflectMeta {
         last_reflected,
         session_count,
+        reflected_at,
     }))
 }
 
 /// Write `.reflect-meta` TOML to `project_dir`, overwriting any existing file.
 pub fn write_reflect_meta(project_dir: &Path, meta: &ReflectMeta) -> Result<()> {
     let path = project_dir.join(".reflect-meta");
-    let content = format!(
+    let mut content = format!(
         "last_reflected = \"{}\"\nsession_count = {}\n",
         meta.last_reflected, meta.session_count
     );
+    if let Some(at) = &meta.reflected_at {
+        content.push_str(&format!("reflected_at = \"{}\"\n", at));
+    }
     std::fs::write(&path, content).into_diagnostic()?;
     Ok(())
 }
@@ -153,6 +164,7 @@ mod tests {
         let meta = ReflectMeta {
             last_reflected: "2026-02-24".to_string(),
             session_count: 7,
+            reflected_at: None,
         };
         write_reflect_meta(dir.path(), &meta).unwrap();
         let read_back = read_reflect_meta(dir.path())
@@ -168,6 +180,7 @@ mod tests {
         let meta = ReflectMeta {
             last_reflected: "2026-01-01".to_string(),
             session_count: 3,
+            reflected_at: None,
         };
         write_reflect_meta(dir.path(), &meta).unwrap();
         let raw = fs::read_to_string(dir.path().join(".reflect-meta")).unwrap();
@@ -181,11 +194,13 @@ mod tests {
         let first = ReflectMeta {
             last_reflected: "2026-01-01".to_string(),
             session_count: 1,
+            reflected_at: None,
         };
         write_reflect_meta(dir.path(), &first).unwrap();
         let second = ReflectMeta {
             last_reflected: "2026-02-24".to_string(),
             session_count: 12,
+            reflected_at: None,
         };
         write_reflect_meta(dir.path(), &second).unwrap();
         let read_back = read_reflect_meta(dir.path()).unwrap().unwrap();
@@ -1,28 +1,37 @@
 pub mod context;
+pub mod debt;
 pub mod meta;
+pub mod review;
 
 // Re-export commonly used items so callers (e.g. close.rs) can import from
 // `super::reflect::` without knowing the submodule layout.
-pub use context::{count_handoffs_since, gather_reflect_context};
+pub use context::{ConversationSource, gather_reflect_context};
+pub use debt::{ReflectDebt, reflect_debt, workspace_debts};
 pub use meta::{
     predict_reflect_resource_path, read_reflect_meta, write_reflect_meta, write_reflect_resource,
 };
 
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 
 use miette::{IntoDiagnostic, Result};
 use owo_colors::OwoColorize;
 
 use crate::config::ProjectConfig;
+use crate::context::current_context;
+use crate::json::{ReflectChange, ReflectReviewPayload};
 use crate::llm::{AGENT_SENTINEL, detect_backend};
 use crate::managed_block::{
     REFLECT_REF_END, REFLECT_REF_START, has_reflect_block, read_reflect_block,
     wai_reflect_ref_content,
 };
+use crate::output::print_envelope_ok;
 use crate::plugin::store_memory;
+use crate::trace::Fidelity;
 
 use context::ReflectContext;
 use meta::ReflectMeta;
+use review::{BulletChange, ChangeKind, ReflectionDiff, RejectedBullets};
 
 // ── Output target detection ──────────────────────────────────────────────────
 
@@ -96,8 +105,8 @@ pub fn build_reflect_prompt(ctx: &ReflectContext, today: &str) -> String {
     parts.push(
         "# Input Hierarchy\n\
          The context below comes from three tiers, ranked by richness:\n\
-         1. **Conversation transcript** — raw session detail; failed attempts, surprises, \
-            step-by-step struggles (most information-dense)\n\
+         1. **Conversation transcript or local session traces** — raw session detail; \
+            failed attempts, surprises, step-by-step struggles (most information-dense)\n\
          2. **Handoff artifacts** — session summaries; intent, next steps, and gotchas\n\
          3. **Research/design/plan artifacts** — explicit decisions and domain knowledge\n\
          When referencing patterns, note the artifact date. If an artifact is older than \
@@ -139,6 +148,28 @@ pub fn build_reflect_prompt(ctx: &ReflectContext, today: &str) -> String {
         ));
     }
 
+    if !ctx.traces.is_empty() {
+        let mut section = String::from(
+            "# Local Session Traces\n\
+             Auto-detected from local agent tool stores for this repository, newest first.\n",
+        );
+        for t in &ctx.traces {
+            if t.fidelity == Fidelity::DiffOnly {
+                section.push_str(&format!(
+                    "\n## {} ({}, reduced-fidelity)\n\
+                     Only file changes were recorded for this session. Do NOT infer the \
+                     user's intent or the conversation from these changes as fact.\n",
+                    t.id,
+                    t.fidelity.name()
+                ));
+            } else {
+                section.push_str(&format!("\n## {} ({})\n", t.id, t.fidelity.name()));
+            }
+            section.push_str(&format!("```\n{}\n```\n", escape_fences(&t.content)));
+        }
+        parts.push(section);
+    }
+
     if !ctx.handoffs.is_empty() {
         let mut section = String::from("# Handoff Artifacts\n");
         for h in &ctx.handoffs {
@@ -294,29 +325,148 @@ pub fn extract_top_level_bullets(content: &str) -> Vec<String> {
         .collect()
 }
 
+/// Date of the project's last reflection, used to pick traces since then.
+fn last_reflected(project_root: &Path, project_name: Option<&str>) -> Option<chrono::NaiveDate> {
+    let project_dir = crate::config::projects_dir(project_root).join(project_name?);
+    let meta = read_reflect_meta(&project_dir).ok()??;
+    chrono::NaiveDate::parse_from_str(&meta.last_reflected, "%Y-%m-%d").ok()
+}
+
+/// Body of the project's current reflection resource, without front matter.
+fn previous_reflection(project_root: &Path, project: &str) -> Option<String> {
+    let refl_dir = crate::config::reflections_dir(project_root);
+    let path = meta::find_existing_reflection(&refl_dir, &slug::slugify(project))?;
+    let content = std::fs::read_to_string(path).ok()?;
+    let body = content
+        .strip_prefix("---")
+        .and_then(|rest| rest.find("\n---\n").map(|end| &rest[end + 5..]))
+        .unwrap_or(&content);
+    Some(body.to_string())
+}
+
+/// Print the numbered list of proposed bullet changes.
+fn print_review(diff: &ReflectionDiff) {
+    println!();
+    if diff.changes.is_empty() {
+        println!(
+            "  {} No bullet changes since the last reflection",
+            "○".dimmed()
+        );
+    } else {
+        let count = |kind: ChangeKind| diff.changes.iter().filter(|c| c.kind == kind).count();
+        println!(
+            "  {} Review: {} added, {} removed, {} reworded",
+            "◆".cyan(),
+            count(ChangeKind::Added),
+            count(ChangeKind::Removed),
+            count(ChangeKind::Reworded)
+        );
+        for change in &diff.changes {
+            let section = change
+                .section
+                .as_deref()
+                .map(|s| format!("[{}] ", s))
+                .unwrap_or_default();
+            let line = format!("{} {}{}", change.kind.symbol(), section, change.text);
+            let line = match change.kind {
+                ChangeKind::Added => line.green().to_string(),
+                ChangeKind::Removed => line.red().to_string(),
+                ChangeKind::Reworded => line.yellow().to_string(),
+            };
+            println!("  {:>3} {}", change.id, line);
+            if let Some(previous) = &change.previous {
+                println!("        {} {}", "was:".dimmed(), previous.dimmed());
+            }
+        }
+    }
+    if !diff.suppressed.is_empty() {
+        println!(
+            "  {} {} previously rejected change(s) skipped",
+            "○".dimmed(),
+            diff.suppressed.len()
+        );
+    }
+}
+
+/// Ask about each proposed change; returns the IDs the user rejected.
+fn prompt_review(diff: &ReflectionDiff) -> Result<Vec<usize>> {
+    let mut rejected = Vec::new();
+    for change in &diff.changes {
+        let question = match (change.kind, &change.previous) {
+            (ChangeKind::Reworded, Some(previous)) => {
+                format!("Reword \"{}\" → \"{}\"?", previous, change.text)
+            }
+            (ChangeKind::Removed, _) => format!("Remove \"{}\"?", change.text),
+            _ => format!("Add \"{}\"?", change.text),
+        };
+        let accept = cliclack::confirm(question)
+            .initial_value(true)
+            .interact()
+            .into_diagnostic()?;
+        if !accept {
+            rejected.push(change.id);
+        }
+    }
+    Ok(rejected)
+}
+
+fn review_payload(
+    project: &str,
+    path: &Path,
+    written: bool,
+    diff: &ReflectionDiff,
+    rejected: &[usize],
+) -> ReflectReviewPayload {
+    ReflectReviewPayload {
+        project: project.to_string(),
+        path: path.display().to_string(),
+        written,
+        changes: diff
+            .changes
+            .iter()
+            .map(|c| ReflectChange {
+                id: c.id,
+                kind: c.kind.name().to_string(),
+                section: c.section.clone(),
+                text: c.text.clone(),
+                previous: c.previous.clone(),
+            })
+            .collect(),
+        rejected: rejected.to_vec(),
+        suppressed: diff.suppressed.len(),
+    }
+}
+
 pub struct ReflectArgs {
     pub project: Option<String>,
     pub conversation: Option<PathBuf>,
+    pub no_traces: bool,
     pub output: Option<String>,
     pub dry_run: bool,
     pub yes: bool,
     pub inject_content: Option<String>,
     pub verbose: u8,
     pub save_memories: bool,
+    pub reject: Vec<usize>,
 }
 
 pub fn run(args: ReflectArgs) -> Result<()> {
     let ReflectArgs {
         project,
         conversation,
+        no_traces,
         output,
         dry_run,
-        yes: _yes,
+        yes,
         inject_content,
         verbose: _verbose,
         save_memories,
+        reject,
     } = args;
     let project_root = super::require_project()?;
+    let cli = current_context();
+    // Progress lines would corrupt the JSON document on stdout.
+    let chatty = !cli.json;
 
     let today = chrono::Local::now().format("%Y-%m-%d").to_string();
 
@@ -330,6 +480,10 @@ pub fn run(args: ReflectArgs) -> Result<()> {
     // Detect output targets (CLAUDE.md / AGENTS.md).
     let targets = detect_output_targets(&project_root, output.as_deref())?;
 
+    // Content of a legacy WAI:REFLECT block, if one is migrated below. Used as
+    // the review baseline when the project has no reflection resource yet.
+    let mut legacy_block: Option<String> = None;
+
     // ── Migration step (3.1–3.2) ──────────────────────────────────────────────
     // Scan target files for old WAI:REFLECT:START/END blocks.
     // If any exist, migrate once and replace with slim REF blocks.
@@ -398,6 +552,7 @@ pub fn run(args: ReflectArgs) -> Result<()> {
         }
 
         // Write migrated resource file if we found content and no migrated file exists.
+        legacy_block.clone_from(&first_content);
         if let Some(content) = first_content
             && !migrated_exists
         {
@@ -417,7 +572,7 @@ pub fn run(args: ReflectArgs) -> Result<()> {
             std::fs::write(&migrated_path, front_matter).into_diagnostic()?;
         }
 
-        if migration_notice_printed {
+        if migration_notice_printed && chatty {
             println!();
             println!(
                 "  {} Migrated WAI:REFLECT block(s) to resource file.",
@@ -427,16 +582,35 @@ pub fn run(args: ReflectArgs) -> Result<()> {
     }
 
     // Gather context.
-    println!();
-    println!("  {} Gathering context …", "◆".cyan());
-    let ctx = gather_reflect_context(&project_root, conversation.as_deref(), &targets)?;
+    if chatty {
+        println!();
+        println!("  {} Gathering context …", "◆".cyan());
+    }
+    let source = match conversation.as_deref() {
+        Some(path) => ConversationSource::File(path),
+        None if no_traces => ConversationSource::None,
+        None => ConversationSource::Traces {
+            since: last_reflected(&project_root, project_name.as_deref()),
+        },
+    };
+    let ctx = gather_reflect_context(&project_root, source, &targets)?;
+    for t in ctx.traces.iter().filter(|_| chatty) {
+        println!(
+            "  {} Using trace {} ({})",
+            "○".dimmed(),
+            t.id.bold(),
+            t.fidelity.name()
+        );
+    }
 
     // Call LLM (or use injected content / agent-mode sentinel path).
     let raw_response = if let Some(content) = inject_content {
         // Agent provided the content directly via --inject-content.
         content
     } else {
-        println!("  {} Calling LLM …", "○".dimmed());
+        if chatty {
+            println!("  {} Calling LLM …", "○".dimmed());
+        }
         let prompt = build_reflect_prompt(&ctx, &today);
         let raw = call_llm(&project_root, &prompt)?;
         if raw == AGENT_SENTINEL {
@@ -461,10 +635,31 @@ pub fn run(args: ReflectArgs) -> Result<()> {
     // Extract REFLECT content from LLM response.
     let new_content = extract_reflect_content(&raw_response);
 
+    // Review the new reflection bullet by bullet against the previous one.
+    let project_str = project_name.as_deref().unwrap_or("project");
+    let baseline = previous_reflection(&project_root, project_str).or(legacy_block);
+    let mut memory = RejectedBullets::load(&project_root, project_str);
+    let diff = review::diff_reflections(baseline.as_deref().unwrap_or(""), &new_content, &memory);
+    if let Some(unknown) = reject
+        .iter()
+        .find(|id| !diff.changes.iter().any(|c| c.id == **id))
+    {
+        miette::bail!(
+            "No proposed change with ID {} (there are {}).",
+            unknown,
+            diff.changes.len()
+        );
+    }
+    if chatty {
+        print_review(&diff);
+    }
+
     // --dry-run: show the resource file path that would be written, then exit.
     if dry_run {
-        let project_str = project_name.as_deref().unwrap_or("project");
         let would_write = predict_reflect_resource_path(&project_root, project_str);
+        if cli.json {
+            return print_envelope_ok(review_payload(project_str, &would_write, false, &diff, &[]));
+        }
         println!();
         println!("  {} Dry run — would write:", "○".dimmed());
         println!("  {}", would_write.display());
@@ -472,8 +667,28 @@ pub fn run(args: ReflectArgs) -> Result<()> {
         return Ok(());
     }
 
+    let interactive = !(yes || cli.yes || cli.json || cli.no_input)
+        && reject.is_empty()
+        && !diff.changes.is_empty()
+        && std::io::stdin().is_terminal();
+    let rejected_ids = if interactive {
+        prompt_review(&diff)?
+    } else {
+        reject
+    };
+    let rejected: Vec<&BulletChange> = diff
+        .changes
+        .iter()
+        .filter(|c| rejected_ids.contains(&c.id))
+        .chain(diff.suppressed.iter())
+        .collect();
+    let new_content = review::apply_review(&new_content, &rejected);
+    for change in diff.changes.iter().filter(|c| rejected_ids.contains(&c.id)) {
+        memory.record(change);
+    }
+    memory.save()?;
+
     // Write resource file.
-    let project_str = project_name.as_deref().unwrap_or("project");
     let resource_path =
         write_reflect_resource(&project_root, project_str, &new_content, ctx.handoff_count)?;
 
@@ -484,23 +699,42 @@ pub fn run(args: ReflectArgs) -> Result<()> {
             let existing_meta = read_reflect_meta(&project_dir)?.unwrap_or(ReflectMeta {
                 last_reflected: today.clone(),
                 session_count: 0,
+                reflected_at: None,
             });
             let new_meta = ReflectMeta {
                 last_reflected: today.clone(),
                 session_count: existing_meta.session_count + 1,
+                reflected_at: Some(chrono::Local::now().to_rfc3339()),
             };
             write_reflect_meta(&project_dir, &new_meta)?;
         }
     }
 
-    // Print success with the resource file path.
-    println!();
-    println!(
-        "  {} Wrote {}",
-        "✓".green(),
-        resource_path.display().to_string().bold()
-    );
-    println!();
+    if cli.json {
+        print_envelope_ok(review_payload(
+            project_str,
+            &resource_path,
+            true,
+            &diff,
+            &rejected_ids,
+        ))?;
+    } else {
+        // Print success with the resource file path.
+        println!();
+        if !rejected_ids.is_empty() {
+            println!(
+                "  {} Rejected {} change(s); they won't be proposed again",
+                "○".dimmed(),
+                rejected_ids.len()
+            );
+        }
+        println!(
+            "  {} Wrote {}",
+            "✓".green(),
+            resource_path.display().to_string().bold()
+        );
+        println!();
+    }
 
     if save_memories {
         let bullets = extract_top_level_bullets(&new_content);
@@ -597,6 +831,7 @@ mod tests {
     fn empty_context() -> ReflectContext {
         ReflectContext {
             conversation: None,
+            traces: Vec::new(),
             handoffs: vec![],
             handoff_count: 0,
             secondary: vec![],
@@ -625,6 +860,7 @@ mod tests {
     fn build_reflect_prompt_includes_conversation_when_provided() {
         let ctx = ReflectContext {
             conversation: Some("session transcript here".to_string()),
+            traces: Vec::new(),
             handoffs: vec![],
             handoff_count: 0,
             secondary: vec![],
@@ -642,6 +878,7 @@ mod tests {
         use context::HandoffEntry;
         let ctx = ReflectContext {
             conversation: None,
+            traces: Vec::new(),
             handoffs: vec![HandoffEntry {
                 rel_path: ".wai/projects/foo/handoffs/h.md".to_string(),
                 content: "handoff notes here".to_string(),
@@ -661,6 +898,7 @@ mod tests {
     fn build_reflect_prompt_includes_existing_blocks() {
         let ctx = ReflectContext {
             conversation: None,
+            traces: Vec::new(),
             handoffs: vec![],
             handoff_count: 0,
             secondary: vec![],
@@ -688,6 +926,7 @@ mod tests {
     fn build_reflect_prompt_escapes_triple_backticks_in_artifacts() {
         let ctx = ReflectContext {
             conversation: Some("some ```code``` here".to_string()),
+            traces: Vec::new(),
             handoffs: vec![],
             handoff_count: 0,
             secondary: vec![],
@@ -701,6 +940,30 @@ mod tests {
         assert!(prompt.contains("~~~code~~~"));
     }
 
+    #[test]
+    fn build_reflect_prompt_labels_diff_only_traces_as_reduced_fidelity() {
+        let ctx = ReflectContext {
+            traces: vec![
+                context::TraceEntry {
+                    id: "claude-code:abc".to_string(),
+                    fidelity: Fidelity::FullTranscript,
+                    content: "[2026-10-01 10:00] user: fix it".to_string(),
+                },
+                context::TraceEntry {
+                    id: "amp:T-1".to_string(),
+                    fidelity: Fidelity::DiffOnly,
+                    content: "src/lib.rs".to_string(),
+                },
+            ],
+            ..empty_context()
+        };
+        let prompt = build_reflect_prompt(&ctx, "2026-10-02");
+        assert!(prompt.contains("# Local Session Traces"));
+        assert!(prompt.contains("## claude-code:abc (full-transcript)"));
+        assert!(prompt.contains("## amp:T-1 (diff-only, reduced-fidelity)"));
+        assert!(prompt.contains("Do NOT infer"));
+    }
+
     // ── REFLECT block extraction tests ────────────────────────────────────
 
     #[test]
@@ -776,12 +1039,14 @@ mod tests {
         let result = run(ReflectArgs {
             project: None,
             conversation: None,
+            no_traces: true,
             output: None,
             dry_run: false,
             yes: true,
             inject_content: Some("# Patterns\ntest content from inject".to_string()),
             verbose: 0,
             save_memories: false,
+            reject: vec![],
         });
 
         // Restore working directory before asserting, so failures don't break
@@ -0,0 +1,444 @@
+//! Bullet-level review of a new reflection against the previous one.
+//!
+//! Reflections are compared as lists of top-level bullets grouped by `###`
+//! section. Each bullet in the new reflection is either unchanged, new, or a
+//! rewording of an old bullet; old bullets with no counterpart were removed.
+//! Rejected changes are remembered per project so later runs don't propose
+//! them again.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use miette::{IntoDiagnostic, Result};
+
+/// Minimum word overlap (Dice coefficient) for two bullets to count as a
+/// rewording.
+const REWORD_THRESHOLD: f64 = 0.5;
+
+/// A top-level bullet and the `###` section it sits under.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bullet {
+    pub section: Option<String>,
+    /// Text of the first line, without the bullet marker.
+    pub text: String,
+    /// The bullet as written, including indented continuation lines.
+    raw: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Reworded,
+}
+
+impl ChangeKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Reworded => "reworded",
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            ChangeKind::Added => "+",
+            ChangeKind::Removed => "-",
+            ChangeKind::Reworded => "~",
+        }
+    }
+}
+
+/// One proposed change, numbered for `--reject`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulletChange {
+    pub id: usize,
+    pub kind: ChangeKind,
+    pub section: Option<String>,
+    /// New text (added/reworded) or the removed text.
+    pub text: String,
+    /// The old wording of a reworded bullet.
+    pub previous: Option<String>,
+    new: Option<Bullet>,
+    old: Option<Bullet>,
+}
+
+/// Changes between two reflections, minus those rejected in earlier reviews.
+#[derive(Debug, Default)]
+pub struct ReflectionDiff {
+    pub changes: Vec<BulletChange>,
+    /// Changes matching a remembered rejection; always rejected again.
+    pub suppressed: Vec<BulletChange>,
+}
+
+/// Parse top-level bullets (`- ` or `* ` at column 0) with their sections.
+pub fn parse_bullets(content: &str) -> Vec<Bullet> {
+    let mut bullets: Vec<Bullet> = Vec::new();
+    let mut section = None;
+    let mut open = false;
+    for line in content.lines() {
+        if let Some(heading) = line.strip_prefix("### ") {
+            section = Some(heading.trim().to_string());
+            open = false;
+        } else if let Some(text) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+            bullets.push(Bullet {
+                section: section.clone(),
+                text: text.trim().to_string(),
+                raw: line.to_string(),
+            });
+            open = true;
+        } else if open && line.starts_with([' ', '\t']) && !line.trim().is_empty() {
+            if let Some(last) = bullets.last_mut() {
+                last.raw.push('\n');
+                last.raw.push_str(line);
+            }
+        } else {
+            open = false;
+        }
+    }
+    bullets
+}
+
+/// Lowercased words, used to compare bullets regardless of punctuation.
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn similarity(a: &str, b: &str) -> f64 {
+    let a: HashSet<&str> = a.split(' ').filter(|w| !w.is_empty()).collect();
+    let b: HashSet<&str> = b.split(' ').filter(|w| !w.is_empty()).collect();
+    let total = a.len() + b.len();
+    if total == 0 {
+        return 0.0;
+    }
+    2.0 * a.intersection(&b).count() as f64 / total as f64
+}
+
+/// Compare `new` against `old` bullet by bullet.
+pub fn diff_reflections(old: &str, new: &str, memory: &RejectedBullets) -> ReflectionDiff {
+    let mut old_left: Vec<Bullet> = parse_bullets(old);
+    let mut new_left: Vec<Bullet> = Vec::new();
+
+    for bullet in parse_bullets(new) {
+        let key = normalize(&bullet.text);
+        match old_left.iter().position(|o| normalize(&o.text) == key) {
+            Some(i) => {
+                old_left.remove(i);
+            }
+            None => new_left.push(bullet),
+        }
+    }
+
+    let mut proposed = Vec::new();
+    for bullet in new_left {
+        let key = normalize(&bullet.text);
+        let best = old_left
+            .iter()
+            .enumerate()
+            .map(|(i, o)| (i, similarity(&key, &normalize(&o.text))))
+            .filter(|(_, score)| *score >= REWORD_THRESHOLD)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        let change = match best {
+            Some((i, _)) => {
+                let old = old_left.remove(i);
+                BulletChange {
+                    id: 0,
+                    kind: ChangeKind::Reworded,
+                    section: bullet.section.clone(),
+                    text: bullet.text.clone(),
+                    previous: Some(old.text.clone()),
+                    new: Some(bullet),
+                    old: Some(old),
+                }
+            }
+            None => BulletChange {
+                id: 0,
+                kind: ChangeKind::Added,
+                section: bullet.section.clone(),
+                text: bullet.text.clone(),
+                previous: None,
+                new: Some(bullet),
+                old: None,
+            },
+        };
+        proposed.push(change);
+    }
+    for old in old_left {
+        proposed.push(BulletChange {
+            id: 0,
+            kind: ChangeKind::Removed,
+            section: old.section.clone(),
+            text: old.text.clone(),
+            previous: None,
+            new: None,
+            old: Some(old),
+        });
+    }
+
+    let mut diff = ReflectionDiff::default();
+    for change in proposed {
+        if memory.rejects(&change) {
+            diff.suppressed.push(change);
+        } else {
+            diff.changes.push(change);
+        }
+    }
+    for (i, change) in diff.changes.iter_mut().enumerate() {
+        change.id = i + 1;
+    }
+    diff
+}
+
+/// Build the reflection to write: `new` with every rejected change undone.
+///
+/// A rejected addition is dropped, a rejected rewording restores the old
+/// wording, and a rejected removal puts the old bullet back under its section.
+pub fn apply_review(new: &str, rejected: &[&BulletChange]) -> String {
+    let mut out = new.to_string();
+    for change in rejected {
+        match (change.kind, &change.new, &change.old) {
+            (ChangeKind::Added, Some(bullet), _) => {
+                out = remove_block(&out, &bullet.raw);
+            }
+            (ChangeKind::Reworded, Some(bullet), Some(old)) => {
+                out = out.replacen(&bullet.raw, &old.raw, 1);
+            }
+            (ChangeKind::Removed, _, Some(old)) => {
+                out = insert_into_section(&out, old.section.as_deref(), &old.raw);
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+fn remove_block(content: &str, raw: &str) -> String {
+    let with_newline = format!("{}\n", raw);
+    if content.contains(&with_newline) {
+        content.replacen(&with_newline, "", 1)
+    } else {
+        content.replacen(raw, "", 1).trim_end().to_string()
+    }
+}
+
+/// Append `raw` after the last line of `section` (or at the end when the
+/// section is missing, adding its heading).
+fn insert_into_section(content: &str, section: Option<&str>, raw: &str) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let heading = section.map(|s| format!("### {}", s));
+    let start = match &heading {
+        Some(h) => lines.iter().position(|l| l.trim_end() == h),
+        None => Some(0),
+    };
+    let Some(start) = start else {
+        let mut out = content.trim_end().to_string();
+        out.push_str(&format!("\n\n{}\n{}\n", heading.unwrap_or_default(), raw));
+        return out;
+    };
+    let end = lines
+        .iter()
+        .enumerate()
+        .skip(start + 1)
+        .find(|(_, l)| l.starts_with("## ") || l.starts_with("### "))
+        .map(|(i, _)| i)
+        .unwrap_or(lines.len());
+    // Insert after the last non-blank line of the section.
+    let mut at = end;
+    while at > start + 1 && lines[at - 1].trim().is_empty() {
+        at -= 1;
+    }
+    lines.insert(at, raw);
+    let mut out = lines.join("\n");
+    if content.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+// ── Remembered rejections ────────────────────────────────────────────────────
+
+/// Bullets rejected in earlier reviews of a project's reflection.
+///
+/// Stored in `.wai/resources/reflections/.rejected-<project>` as one bullet
+/// per line: `+ text` for additions (and rewordings) not to propose again,
+/// `- text` for bullets not to propose removing again.
+#[derive(Debug, Default)]
+pub struct RejectedBullets {
+    path: PathBuf,
+    additions: Vec<String>,
+    removals: Vec<String>,
+}
+
+const REJECTED_HEADER: &str =
+    "# Reflection bullets rejected in review. Delete a line to allow it again.\n";
+
+impl RejectedBullets {
+    pub fn path_for(project_root: &Path, project: &str) -> PathBuf {
+        crate::config::reflections_dir(project_root)
+            .join(format!(".rejected-{}", slug::slugify(project)))
+    }
+
+    pub fn load(project_root: &Path, project: &str) -> Self {
+        let path = Self::path_for(project_root, project);
+        let mut memory = Self {
+            path,
+            ..Self::default()
+        };
+        let Ok(content) = std::fs::read_to_string(&memory.path) else {
+            return memory;
+        };
+        for line in content.lines() {
+            if let Some(text) = line.strip_prefix("+ ") {
+                memory.additions.push(text.to_string());
+            } else if let Some(text) = line.strip_prefix("- ") {
+                memory.removals.push(text.to_string());
+            }
+        }
+        memory
+    }
+
+    fn rejects(&self, change: &BulletChange) -> bool {
+        let key = normalize(&change.text);
+        let list = match change.kind {
+            ChangeKind::Added | ChangeKind::Reworded => &self.additions,
+            ChangeKind::Removed => &self.removals,
+        };
+        list.iter().any(|t| normalize(t) == key)
+    }
+
+    /// Remember `change` as rejected.
+    pub fn record(&mut self, change: &BulletChange) {
+        if self.rejects(change) {
+            return;
+        }
+        match change.kind {
+            ChangeKind::Added | ChangeKind::Reworded => self.additions.push(change.text.clone()),
+            ChangeKind::Removed => self.removals.push(change.text.clone()),
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if self.additions.is_empty() && self.removals.is_empty() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).into_diagnostic()?;
+        }
+        let mut content = String::from(REJECTED_HEADER);
+        for text in &self.additions {
+            content.push_str(&format!("+ {}\n", text));
+        }
+        for text in &self.removals {
+            content.push_str(&format!("- {}\n", text));
+        }
+        std::fs::write(&self.path, content).into_diagnostic()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const OLD: &str = "## Project-Specific AI Context\n\n\
+                       ### Conventions\n\
+                       - Use TDD always\n\
+                       - Run cargo fmt before committing\n\
+                       \n\
+                       ### Common Gotchas\n\
+                       - Config loads lazily\n  so call load() first\n";
+
+    const NEW: &str = "## Project-Specific AI Context\n\n\
+                       ### Conventions\n\
+                       - Use TDD always.\n\
+                       - Run cargo fmt and clippy before committing\n\
+                       - Prefer miette for errors\n";
+
+    #[test]
+    fn parse_bullets_tracks_sections_and_continuations() {
+        let bullets = parse_bullets(OLD);
+        assert_eq!(bullets.len(), 3);
+        assert_eq!(bullets[0].section.as_deref(), Some("Conventions"));
+        assert_eq!(bullets[2].section.as_deref(), Some("Common Gotchas"));
+        assert_eq!(
+            bullets[2].raw,
+            "- Config loads lazily\n  so call load() first"
+        );
+    }
+
+    #[test]
+    fn diff_classifies_added_removed_and_reworded() {
+        let diff = diff_reflections(OLD, NEW, &RejectedBullets::default());
+        let summary: Vec<(usize, ChangeKind, &str)> = diff
+            .changes
+            .iter()
+            .map(|c| (c.id, c.kind, c.text.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    1,
+                    ChangeKind::Reworded,
+                    "Run cargo fmt and clippy before committing"
+                ),
+                (2, ChangeKind::Added, "Prefer miette for errors"),
+                (3, ChangeKind::Removed, "Config loads lazily"),
+            ]
+        );
+        assert_eq!(
+            diff.changes[0].previous.as_deref(),
+            Some("Run cargo fmt before committing")
+        );
+    }
+
+    #[test]
+    fn apply_review_undoes_rejected_changes() {
+        let diff = diff_reflections(OLD, NEW, &RejectedBullets::default());
+        let rejected: Vec<&BulletChange> = diff.changes.iter().collect();
+        let out = apply_review(NEW, &rejected);
+        assert!(out.contains("- Run cargo fmt before committing\n"));
+        assert!(!out.contains("clippy"));
+        assert!(!out.contains("miette"));
+        assert!(
+            out.contains("### Common Gotchas\n- Config loads lazily\n  so call load() first\n")
+        );
+
+        // Accepting everything leaves the new reflection untouched.
+        assert_eq!(apply_review(NEW, &[]), NEW);
+    }
+
+    #[test]
+    fn rejected_removal_returns_to_existing_section() {
+        let old = "### Conventions\n- Keep me\n- Also here\n\n### Other\n- x\n";
+        let new = "### Conventions\n- Also here\n\n### Other\n- x\n";
+        let diff = diff_reflections(old, new, &RejectedBullets::default());
+        let rejected: Vec<&BulletChange> = diff.changes.iter().collect();
+        assert_eq!(
+            apply_review(new, &rejected),
+            "### Conventions\n- Also here\n- Keep me\n\n### Other\n- x\n"
+        );
+    }
+
+    #[test]
+    fn remembered_rejections_are_suppressed() {
+        let tmp = TempDir::new().unwrap();
+        let diff = diff_reflections(OLD, NEW, &RejectedBullets::default());
+
+        let mut memory = RejectedBullets::load(tmp.path(), "demo");
+        memory.record(&diff.changes[1]);
+        memory.record(&diff.changes[2]);
+        memory.save().unwrap();
+
+        let memory = RejectedBullets::load(tmp.path(), "demo");
+        let again = diff_reflections(OLD, NEW, &memory);
+        assert_eq!(again.changes.len(), 1);
+        assert_eq!(again.changes[0].kind, ChangeKind::Reworded);
+        assert_eq!(again.suppressed.len(), 2);
+    }
+}
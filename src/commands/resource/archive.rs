@@ -5,12 +5,17 @@ use flate2::write::GzEncoder;
 use miette::{IntoDiagnostic, Result};
 use owo_colors::OwoColorize;
 use std::fs;
-use std::io::BufReader;
+use std::io::{BufReader, IsTerminal};
 use std::path::Path;
 
 use crate::config::{SKILLS_DIR, agent_config_dir, global_skills_dir};
 use crate::context::current_context;
+use crate::plugin::TrustStore;
 
+use super::provenance::{
+    MANIFEST_ENTRY, Provenance, SIGNATURE_ENTRY, build_manifest, load_or_create_signing_key,
+    sign_manifest, signing_key_path, verify_archive,
+};
 use super::validation::validate_skill_name;
 use crate::commands::require_project;
 
@@ -64,16 +69,17 @@ pub(super) fn validate_archive_entry_path(path_str: &str) -> Result<()> {
 }
 
 /// Export named skills to a tar.gz archive.
-pub(super) fn export_skills(skill_names: &[String], output_path: &str) -> Result<()> {
+///
+/// Every archive carries a `wai-manifest.toml` listing the SHA-256 of each
+/// entry. With `sign`, the manifest is also signed with the user's ed25519
+/// key (generated on first use) so importers can verify provenance.
+pub(super) fn export_skills(skill_names: &[String], output_path: &str, sign: bool) -> Result<()> {
     let project_root = require_project()?;
 
     let local_skills_dir = agent_config_dir(&project_root).join(SKILLS_DIR);
     let global_dir = global_skills_dir();
 
-    let out_file = fs::File::create(output_path).into_diagnostic()?;
-    let gz = GzEncoder::new(out_file, Compression::default());
-    let mut archive = tar::Builder::new(gz);
-
+    let mut entries: Vec<(String, Vec<u8>)> = Vec::new();
     for name in skill_names {
         validate_skill_name(name)?;
 
@@ -94,10 +100,30 @@ pub(super) fn export_skills(skill_names: &[String], output_path: &str) -> Result
 
         // Archive path: preserve <name>/SKILL.md or <cat>/<action>/SKILL.md structure
         let archive_path = format!("{}/SKILL.md", name);
+        let content = fs::read(&skill_md_file).into_diagnostic()?;
+        entries.push((archive_path, content));
+    }
 
-        archive
-            .append_path_with_name(&skill_md_file, &archive_path)
-            .into_diagnostic()?;
+    let manifest = toml::to_string_pretty(&build_manifest(&entries)).into_diagnostic()?;
+    let signing = if sign {
+        let (key, created) = load_or_create_signing_key()?;
+        let signature = sign_manifest(manifest.as_bytes(), &key);
+        Some((signature, created))
+    } else {
+        None
+    };
+
+    let out_file = fs::File::create(output_path).into_diagnostic()?;
+    let gz = GzEncoder::new(out_file, Compression::default());
+    let mut archive = tar::Builder::new(gz);
+
+    append_bytes(&mut archive, MANIFEST_ENTRY, manifest.as_bytes())?;
+    if let Some((signature, _)) = &signing {
+        let signature = toml::to_string_pretty(signature).into_diagnostic()?;
+        append_bytes(&mut archive, SIGNATURE_ENTRY, signature.as_bytes())?;
+    }
+    for (archive_path, content) in &entries {
+        append_bytes(&mut archive, archive_path, content)?;
     }
 
     archive.finish().into_diagnostic()?;
@@ -112,11 +138,46 @@ pub(super) fn export_skills(skill_names: &[String], output_path: &str) -> Result
     ))
     .into_diagnostic()?;
 
+    if let Some((signature, created)) = signing {
+        if created {
+            log::info(format!(
+                "Generated archive signing key at {}",
+                signing_key_path().display()
+            ))
+            .into_diagnostic()?;
+        }
+        log::info(format!(
+            "Signed with public key {}\n  Recipients trust it with: wai resource trust {} --label <name>",
+            signature.public_key, signature.public_key
+        ))
+        .into_diagnostic()?;
+    }
+
     Ok(())
 }
 
+/// Append an in-memory file to a tar archive.
+fn append_bytes<W: std::io::Write>(
+    archive: &mut tar::Builder<W>,
+    path: &str,
+    content: &[u8],
+) -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(content.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(chrono::Utc::now().timestamp().max(0) as u64);
+    header.set_cksum();
+    archive
+        .append_data(&mut header, path, content)
+        .into_diagnostic()
+}
+
 /// Import skills from a tar.gz archive into the current project.
-pub(super) fn import_skills_archive(archive_path: &str, yes: bool) -> Result<()> {
+pub(super) fn import_skills_archive(
+    archive_path: &str,
+    yes: bool,
+    allow_untrusted: bool,
+) -> Result<()> {
     // Merge local --yes with global --yes so both
     // `wai resource import archive f --yes` and `wai --yes resource import archive f` work.
     let yes = yes || current_context().yes;
@@ -126,19 +187,32 @@ pub(super) fn import_skills_archive(archive_path: &str, yes: bool) -> Result<()>
     let local_skills_dir = agent_config_dir(&project_root).join(SKILLS_DIR);
     fs::create_dir_all(&local_skills_dir).into_diagnostic()?;
 
-    import_archive_into_dir(archive_path, &local_skills_dir, yes)
+    import_archive_into_dir(archive_path, &local_skills_dir, yes, allow_untrusted)
+}
+
+/// Raw contents of a skill archive: validated skill entries plus the
+/// optional provenance manifest and signature.
+pub(crate) struct SkillArchive {
+    pub entries: Vec<(String, Vec<u8>)>,
+    pub manifest: Option<Vec<u8>>,
+    pub signature: Option<Vec<u8>>,
 }
 
-/// Extract validated skill entries from a tar.gz archive.
+/// Read a tar.gz skill archive.
 ///
-/// Returns a list of `(archive_path, content)` pairs. Validates all paths
-/// before returning — callers can trust the results are safe to write.
-pub(crate) fn read_archive_entries(archive_path: &str) -> Result<Vec<(String, Vec<u8>)>> {
+/// Validates all skill entry paths before returning — callers can trust the
+/// entries are safe to write. Content is not verified here; see
+/// [`verify_archive`].
+pub(crate) fn read_archive(archive_path: &str) -> Result<SkillArchive> {
     let archive_file = fs::File::open(archive_path).into_diagnostic()?;
     let gz = GzDecoder::new(BufReader::new(archive_file));
     let mut archive = tar::Archive::new(gz);
 
-    let mut entries: Vec<(String, Vec<u8>)> = Vec::new();
+    let mut contents = SkillArchive {
+        entries: Vec::new(),
+        manifest: None,
+        signature: None,
+    };
     for entry in archive.entries().into_diagnostic()? {
         let mut entry = entry.into_diagnostic()?;
         let entry_path = entry.path().into_diagnostic()?;
@@ -148,13 +222,96 @@ pub(crate) fn read_archive_entries(archive_path: &str) -> Result<Vec<(String, Ve
             continue;
         }
 
+        let mut content = Vec::new();
+        if path_str == MANIFEST_ENTRY || path_str == SIGNATURE_ENTRY {
+            let slot = if path_str == MANIFEST_ENTRY {
+                &mut contents.manifest
+            } else {
+                &mut contents.signature
+            };
+            if slot.is_some() {
+                miette::bail!("Invalid archive: duplicate '{}' entry", path_str);
+            }
+            std::io::copy(&mut entry, &mut content).into_diagnostic()?;
+            *slot = Some(content);
+            continue;
+        }
+
         validate_archive_entry_path(&path_str)?;
 
-        let mut content = Vec::new();
         std::io::copy(&mut entry, &mut content).into_diagnostic()?;
-        entries.push((path_str, content));
+        contents.entries.push((path_str, content));
     }
-    Ok(entries)
+    Ok(contents)
+}
+
+/// Decide whether an archive with the given provenance may be imported.
+///
+/// Trusted signatures pass. Unsigned archives and archives signed by an
+/// unknown key pass with `allow_untrusted`, otherwise require interactive
+/// confirmation — non-interactive sessions refuse them.
+fn confirm_provenance(provenance: &Provenance, allow_untrusted: bool) -> Result<()> {
+    let concern = match provenance {
+        Provenance::Trusted { label, public_key } => {
+            log::success(format!(
+                "Verified signature from '{}' ({})",
+                label,
+                short_key(public_key)
+            ))
+            .into_diagnostic()?;
+            return Ok(());
+        }
+        Provenance::UntrustedSigner { public_key } => format!(
+            "Archive is signed by a key that is not in your trust store: {}",
+            public_key
+        ),
+        Provenance::Unsigned { has_manifest: true } => {
+            "Archive is unsigned (content matches its manifest, but its origin cannot be verified)"
+                .to_string()
+        }
+        Provenance::Unsigned {
+            has_manifest: false,
+        } => {
+            "Archive is unsigned and has no manifest (created by an older wai version)".to_string()
+        }
+    };
+
+    if allow_untrusted {
+        log::warning(format!(
+            "{} — importing because of --allow-untrusted",
+            concern
+        ))
+        .into_diagnostic()?;
+        return Ok(());
+    }
+
+    let ctx = current_context();
+    if ctx.no_input || !std::io::stdin().is_terminal() {
+        let hint = match provenance {
+            Provenance::UntrustedSigner { public_key } => format!(
+                "Trust the signer with `wai resource trust {} --label <name>`, or re-run with --allow-untrusted",
+                public_key
+            ),
+            _ => "Ask the author to export with `--sign`, or re-run with --allow-untrusted"
+                .to_string(),
+        };
+        miette::bail!("{}. {}", concern, hint);
+    }
+
+    log::warning(&concern).into_diagnostic()?;
+    let confirmed = cliclack::confirm("Import skills from this archive anyway?")
+        .initial_value(false)
+        .interact()
+        .into_diagnostic()?;
+    if !confirmed {
+        miette::bail!("Import cancelled: archive provenance not accepted");
+    }
+    Ok(())
+}
+
+/// Abbreviate a hex public key for display.
+fn short_key(public_key: &str) -> &str {
+    &public_key[..public_key.len().min(16)]
 }
 
 /// Write validated archive entries into a skills directory.
@@ -208,8 +365,13 @@ pub(crate) fn import_archive_into_dir(
     archive_path: &str,
     skills_dir: &Path,
     yes: bool,
+    allow_untrusted: bool,
 ) -> Result<()> {
-    let entries = read_archive_entries(archive_path)?;
+    let SkillArchive {
+        entries,
+        manifest,
+        signature,
+    } = read_archive(archive_path)?;
 
     if entries.is_empty() {
         println!();
@@ -218,6 +380,14 @@ pub(crate) fn import_archive_into_dir(
         return Ok(());
     }
 
+    let provenance = verify_archive(
+        &entries,
+        manifest.as_deref(),
+        signature.as_deref(),
+        &TrustStore::load(),
+    )?;
+    confirm_provenance(&provenance, allow_untrusted)?;
+
     let (imported, overwritten, skipped) = write_archive_entries(entries, skills_dir, yes)?;
 
     println!();
@@ -456,7 +626,9 @@ mod tests {
         gz_encoder.finish().unwrap();
 
         // Import with yes=true
-        let entries = read_archive_entries(archive_path.to_str().unwrap()).unwrap();
+        let entries = read_archive(archive_path.to_str().unwrap())
+            .unwrap()
+            .entries;
         let (imported, overwritten, skipped) =
             write_archive_entries(entries, dst_skills.path(), true).unwrap();
 
@@ -467,4 +639,25 @@ mod tests {
         let result = fs::read_to_string(dst_skills.path().join("gather").join("SKILL.md")).unwrap();
         assert_eq!(result, updated);
     }
+
+    // ── manifest entries ─────────────────────────────────────────────────────
+
+    #[test]
+    fn test_read_archive_separates_manifest_and_signature() {
+        let archive_dir = tempfile::tempdir().unwrap();
+        let archive_path = archive_dir.path().join("skills.tar.gz");
+
+        let out_file = fs::File::create(&archive_path).unwrap();
+        let mut builder = tar::Builder::new(GzEncoder::new(out_file, Compression::default()));
+        append_bytes(&mut builder, MANIFEST_ENTRY, b"format = 1").unwrap();
+        append_bytes(&mut builder, SIGNATURE_ENTRY, b"algorithm = 'ed25519'").unwrap();
+        append_bytes(&mut builder, "gather/SKILL.md", b"gather").unwrap();
+        builder.into_inner().unwrap().finish().unwrap();
+
+        let contents = read_archive(archive_path.to_str().unwrap()).unwrap();
+        assert_eq!(contents.entries.len(), 1);
+        assert_eq!(contents.entries[0].0, "gather/SKILL.md");
+        assert_eq!(contents.manifest.as_deref(), Some(&b"format = 1"[..]));
+        assert!(contents.signature.is_some());
+    }
 }
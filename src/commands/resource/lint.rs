@@ -0,0 +1,814 @@
+use miette::{IntoDiagnostic, Result};
+use owo_colors::OwoColorize;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use crate::config::{SKILLS_DIR, agent_config_dir, global_skills_dir, pipelines_dir};
+use crate::context::{current_context, require_safe_mode};
+
+use super::skills::hardcoded_content_findings;
+use super::validation::validate_skill_name;
+use crate::commands::require_project;
+
+/// Directory (under agent-config) holding golden projection fixtures,
+/// laid out as `skill-fixtures/<skill>/<target>.md`.
+const FIXTURES_DIR: &str = "skill-fixtures";
+
+/// Frontmatter keys understood by wai and the agent tools it projects to.
+const KNOWN_FRONTMATTER_KEYS: &[&str] = &["name", "description", "aliases"];
+
+/// Descriptions shorter than this rarely give agents enough to decide when to use a skill.
+const MIN_DESCRIPTION_CHARS: usize = 20;
+
+/// Upper bound enforced by agent tools that load skill descriptions into their prompt.
+const MAX_DESCRIPTION_CHARS: usize = 1024;
+
+/// Verbs that accept arbitrary positional names in place of a subcommand
+/// (`allow_external_subcommands`), e.g. `wai project my-app`.
+const OPEN_SUBCOMMAND_VERBS: &[&str] = &["project"];
+
+/// Projection targets that golden fixtures can be rendered for.
+const GOLDEN_TARGETS: &[&str] = &["agents", "claude-code"];
+
+static WAI_COMMAND_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[\s;&|($])wai\s+([a-z][a-z0-9-]*)(?:\s+([a-z][a-z0-9-]*))?").unwrap()
+});
+
+static MARKDOWN_LINK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\]\(([^)\s]+)(?:\s+"[^"]*")?\)"#).unwrap());
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum LintLevel {
+    Error,
+    Warning,
+}
+
+/// One problem found while linting a skill or pipeline definition.
+#[derive(Debug, Clone, Serialize)]
+struct LintFinding {
+    /// Skill the finding belongs to (`None` for pipeline-level findings).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skill: Option<String>,
+    /// Check identifier (e.g. "frontmatter", "command-ref", "golden").
+    check: &'static str,
+    level: LintLevel,
+    message: String,
+    /// File the finding refers to, relative to the repo root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+}
+
+/// JSON payload for `wai resource lint`.
+#[derive(Debug, Serialize)]
+struct LintPayload {
+    skills_checked: usize,
+    errors: usize,
+    warnings: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    golden_updated: Vec<String>,
+    findings: Vec<LintFinding>,
+}
+
+/// Everything a single skill lint needs to know about its surroundings.
+struct LintContext<'a> {
+    project_root: &'a Path,
+    fixtures_dir: PathBuf,
+    verbs: HashSet<String>,
+    verbs_with_subcommands: HashSet<String>,
+    patterns: HashSet<(String, String)>,
+}
+
+impl LintContext<'_> {
+    fn is_known_command(&self, verb: &str, sub: Option<&str>) -> Result<(), String> {
+        if !self.verbs.contains(verb) {
+            return Err(format!("unknown command 'wai {}'", verb));
+        }
+        if let Some(sub) = sub
+            && self.verbs_with_subcommands.contains(verb)
+            && !OPEN_SUBCOMMAND_VERBS.contains(&verb)
+            && !self.patterns.contains(&(verb.to_string(), sub.to_string()))
+        {
+            return Err(format!("unknown subcommand 'wai {} {}'", verb, sub));
+        }
+        Ok(())
+    }
+}
+
+/// Run `wai resource lint`.
+pub(super) fn run_lint(names: &[String], update_golden: bool, json: bool) -> Result<()> {
+    let json = json || current_context().json;
+    let project_root = require_project()?;
+    if update_golden {
+        require_safe_mode("update golden fixtures")?;
+    }
+
+    let config_dir = agent_config_dir(&project_root);
+    let skills = collect_skills(&config_dir.join(SKILLS_DIR));
+
+    let selected: Vec<&(String, PathBuf)> = if names.is_empty() {
+        skills.iter().collect()
+    } else {
+        let mut selected = Vec::new();
+        for name in names {
+            validate_skill_name(name)?;
+            match skills.iter().find(|(n, _)| n == name) {
+                Some(entry) => selected.push(entry),
+                None => miette::bail!("Skill '{}' not found in current project", name),
+            }
+        }
+        selected
+    };
+
+    let ctx = build_context(&project_root);
+    let mut findings = Vec::new();
+    let mut golden_updated = Vec::new();
+
+    for (name, skill_dir) in &selected {
+        findings.extend(lint_skill(&ctx, name, skill_dir));
+        if update_golden {
+            golden_updated.extend(write_golden(&ctx, name, skill_dir)?);
+        } else {
+            findings.extend(check_golden(&ctx, name, skill_dir));
+        }
+    }
+
+    // Pipeline links are a property of the whole skill set, so only check
+    // them when linting everything.
+    if names.is_empty() {
+        findings.extend(check_pipeline_skills(&project_root, &skills));
+    }
+
+    let errors = findings
+        .iter()
+        .filter(|f| f.level == LintLevel::Error)
+        .count();
+    let warnings = findings.len() - errors;
+
+    if json {
+        crate::output::print_envelope_check(&LintPayload {
+            skills_checked: selected.len(),
+            errors,
+            warnings,
+            golden_updated,
+            findings,
+        })?;
+    } else {
+        print_findings(selected.len(), &findings, &golden_updated);
+    }
+
+    if errors > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn build_context(project_root: &Path) -> LintContext<'_> {
+    let mut verbs: HashSet<String> = crate::cli::wai_subcommand_names().into_iter().collect();
+    // clap adds `help` at parse time, so it is not among the derived names.
+    verbs.insert("help".to_string());
+    // Plugin pass-through commands (`wai beads list`) are valid verbs too.
+    for plugin in crate::plugin::detect_plugins(project_root) {
+        verbs.insert(plugin.def.name);
+    }
+
+    let patterns: HashSet<(String, String)> =
+        crate::cli::wai_subcommand_patterns().into_iter().collect();
+    let verbs_with_subcommands = patterns.iter().map(|(v, _)| v.clone()).collect();
+
+    LintContext {
+        project_root,
+        fixtures_dir: agent_config_dir(project_root).join(FIXTURES_DIR),
+        verbs,
+        verbs_with_subcommands,
+        patterns,
+    }
+}
+
+/// Collect `(name, dir)` for every flat and hierarchical skill directory that
+/// contains a SKILL.md, sorted by name.
+fn collect_skills(skills_dir: &Path) -> Vec<(String, PathBuf)> {
+    let mut skills = Vec::new();
+    let Ok(read_dir) = fs::read_dir(skills_dir) else {
+        return skills;
+    };
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let dir_name = entry.file_name().to_string_lossy().to_string();
+        if path.join("SKILL.md").exists() {
+            skills.push((dir_name, path));
+            continue;
+        }
+        let Ok(sub_read) = fs::read_dir(&path) else {
+            continue;
+        };
+        for sub_entry in sub_read.flatten() {
+            let sub_path = sub_entry.path();
+            if sub_path.is_dir() && sub_path.join("SKILL.md").exists() {
+                let sub_name = sub_entry.file_name().to_string_lossy().to_string();
+                skills.push((format!("{}/{}", dir_name, sub_name), sub_path));
+            }
+        }
+    }
+    skills.sort_by(|a, b| a.0.cmp(&b.0));
+    skills
+}
+
+/// Split a SKILL.md into `(frontmatter_yaml, body)`.
+///
+/// Returns `None` when the file does not open with a `---` delimiter.
+fn split_frontmatter(content: &str) -> Option<(String, String)> {
+    let mut lines = content.lines();
+    if lines.next()?.trim() != "---" {
+        return None;
+    }
+    let mut fm_lines = Vec::new();
+    for line in lines.by_ref() {
+        if line.trim() == "---" {
+            break;
+        }
+        fm_lines.push(line);
+    }
+    let body: Vec<&str> = lines.collect();
+    Some((fm_lines.join("\n"), body.join("\n")))
+}
+
+fn lint_skill(ctx: &LintContext<'_>, name: &str, skill_dir: &Path) -> Vec<LintFinding> {
+    let skill_file = skill_dir.join("SKILL.md");
+    let rel_path = relative(ctx.project_root, &skill_file);
+    let mut findings = Vec::new();
+    let mut push = |check: &'static str, level: LintLevel, message: String| {
+        findings.push(LintFinding {
+            skill: Some(name.to_string()),
+            check,
+            level,
+            message,
+            path: Some(rel_path.clone()),
+        });
+    };
+
+    let content = match fs::read_to_string(&skill_file) {
+        Ok(c) => c,
+        Err(e) => {
+            push(
+                "frontmatter",
+                LintLevel::Error,
+                format!("cannot read SKILL.md: {}", e),
+            );
+            return findings;
+        }
+    };
+
+    let Some((frontmatter, body)) = split_frontmatter(&content) else {
+        push(
+            "frontmatter",
+            LintLevel::Error,
+            "missing YAML frontmatter (expected an opening '---' line)".to_string(),
+        );
+        return findings;
+    };
+
+    for message in check_frontmatter(name, &frontmatter) {
+        push("frontmatter", message.0, message.1);
+    }
+
+    for (line, message) in check_command_refs(ctx, &body) {
+        push(
+            "command-ref",
+            LintLevel::Error,
+            format!("line {}: {}", line, message),
+        );
+    }
+
+    for (line, target) in check_file_refs(ctx.project_root, skill_dir, &body) {
+        push(
+            "file-ref",
+            LintLevel::Error,
+            format!("line {}: referenced file '{}' does not exist", line, target),
+        );
+    }
+
+    for message in hardcoded_content_findings(&content, "", ctx.project_root) {
+        push(
+            "hardcoded",
+            LintLevel::Warning,
+            format!(
+                "skill {} (use $REPO_ROOT or a relative path instead)",
+                message
+            ),
+        );
+    }
+
+    findings
+}
+
+/// Validate frontmatter keys, name, and description length.
+fn check_frontmatter(dir_name: &str, frontmatter: &str) -> Vec<(LintLevel, String)> {
+    let mut out = Vec::new();
+    let mapping: serde_yml::Mapping = match serde_yml::from_str(frontmatter) {
+        Ok(serde_yml::Value::Mapping(m)) => m,
+        Ok(serde_yml::Value::Null) => {
+            out.push((LintLevel::Error, "frontmatter is empty".to_string()));
+            return out;
+        }
+        Ok(_) => {
+            out.push((
+                LintLevel::Error,
+                "frontmatter must be a YAML mapping".to_string(),
+            ));
+            return out;
+        }
+        Err(e) => {
+            out.push((
+                LintLevel::Error,
+                format!("frontmatter is not valid YAML: {}", e),
+            ));
+            return out;
+        }
+    };
+
+    for key in mapping.keys() {
+        let key = key.as_str().unwrap_or("<non-string>");
+        if !KNOWN_FRONTMATTER_KEYS.contains(&key) {
+            out.push((
+                LintLevel::Warning,
+                format!(
+                    "unknown frontmatter key '{}' (expected: {})",
+                    key,
+                    KNOWN_FRONTMATTER_KEYS.join(", ")
+                ),
+            ));
+        }
+    }
+
+    match mapping.get("name").map(|v| v.as_str()) {
+        None => out.push((
+            LintLevel::Error,
+            "missing required field 'name'".to_string(),
+        )),
+        Some(None) => out.push((LintLevel::Error, "'name' must be a string".to_string())),
+        Some(Some(name)) => {
+            if name != dir_name {
+                out.push((
+                    LintLevel::Error,
+                    format!("name '{}' does not match skill path '{}'", name, dir_name),
+                ));
+            }
+            if let Err(e) = validate_skill_name(name) {
+                out.push((LintLevel::Error, e.to_string()));
+            }
+        }
+    }
+
+    match mapping.get("description").map(|v| v.as_str()) {
+        None => out.push((
+            LintLevel::Error,
+            "missing required field 'description'".to_string(),
+        )),
+        Some(None) => out.push((
+            LintLevel::Error,
+            "'description' must be a string".to_string(),
+        )),
+        Some(Some(description)) => {
+            let len = description.trim().chars().count();
+            if len == 0 {
+                out.push((LintLevel::Error, "description is empty".to_string()));
+            } else if len > MAX_DESCRIPTION_CHARS {
+                out.push((
+                    LintLevel::Error,
+                    format!(
+                        "description is {} characters (max {})",
+                        len, MAX_DESCRIPTION_CHARS
+                    ),
+                ));
+            } else if len < MIN_DESCRIPTION_CHARS {
+                out.push((
+                    LintLevel::Warning,
+                    format!(
+                        "description is only {} characters; say what the skill does and when to use it",
+                        len
+                    ),
+                ));
+            }
+        }
+    }
+
+    if let Some(aliases) = mapping.get("aliases") {
+        let valid = aliases
+            .as_sequence()
+            .is_some_and(|seq| seq.iter().all(|a| a.as_str().is_some()));
+        if !valid {
+            out.push((
+                LintLevel::Error,
+                "'aliases' must be a list of strings".to_string(),
+            ));
+        }
+    }
+
+    out
+}
+
+/// Code spans and fenced code lines in a markdown body, with 1-based line numbers.
+fn code_segments(body: &str) -> Vec<(usize, String)> {
+    let mut segments = Vec::new();
+    let mut in_fence = false;
+    for (idx, line) in body.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            segments.push((idx + 1, line.to_string()));
+        } else {
+            for (i, part) in line.split('`').enumerate() {
+                if i % 2 == 1 && !part.is_empty() {
+                    segments.push((idx + 1, part.to_string()));
+                }
+            }
+        }
+    }
+    segments
+}
+
+/// Find `wai <verb> [<sub>]` invocations in code that the CLI does not know.
+fn check_command_refs(ctx: &LintContext<'_>, body: &str) -> Vec<(usize, String)> {
+    let mut out = Vec::new();
+    for (line, code) in code_segments(body) {
+        for caps in WAI_COMMAND_RE.captures_iter(&code) {
+            let verb = &caps[1];
+            let sub = caps.get(2).map(|m| m.as_str());
+            if let Err(message) = ctx.is_known_command(verb, sub) {
+                out.push((line, message));
+            }
+        }
+    }
+    out
+}
+
+/// Find relative markdown link targets (outside code) that resolve to nothing,
+/// looking next to the skill first and then at the repo root.
+fn check_file_refs(project_root: &Path, skill_dir: &Path, body: &str) -> Vec<(usize, String)> {
+    let mut out = Vec::new();
+    let mut in_fence = false;
+    for (idx, line) in body.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        let prose: String = line
+            .split('`')
+            .enumerate()
+            .filter(|(i, _)| i % 2 == 0)
+            .map(|(_, part)| part)
+            .collect::<Vec<_>>()
+            .join(" ");
+        for caps in MARKDOWN_LINK_RE.captures_iter(&prose) {
+            let target = &caps[1];
+            if target.starts_with('#') || target.starts_with('$') || target.contains("://") {
+                continue;
+            }
+            if target.starts_with("mailto:") {
+                continue;
+            }
+            let path = target.split('#').next().unwrap_or(target);
+            if path.is_empty() {
+                continue;
+            }
+            let exists = if Path::new(path).is_absolute() {
+                Path::new(path).exists()
+            } else {
+                skill_dir.join(path).exists() || project_root.join(path).exists()
+            };
+            if !exists {
+                out.push((idx + 1, target.to_string()));
+            }
+        }
+    }
+    out
+}
+
+/// Report pipeline `[pipeline.metadata] skills` entries that match no skill name or alias.
+fn check_pipeline_skills(project_root: &Path, local: &[(String, PathBuf)]) -> Vec<LintFinding> {
+    let mut known: HashSet<String> = HashSet::new();
+    let mut add_skill_names = |skills: &[(String, PathBuf)]| {
+        for (name, dir) in skills {
+            known.insert(name.clone());
+            if let Some(meta) = super::parse_skill_frontmatter(&dir.join("SKILL.md")) {
+                known.insert(meta.name);
+                known.extend(meta.aliases);
+            }
+        }
+    };
+    add_skill_names(local);
+    add_skill_names(&collect_skills(&global_skills_dir()));
+
+    let mut findings = Vec::new();
+    let Ok(entries) = fs::read_dir(pipelines_dir(project_root)) else {
+        return findings;
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("toml"))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let rel_path = relative(project_root, &path);
+        let def = match crate::commands::pipeline::load_pipeline_toml(&path) {
+            Ok(def) => def,
+            Err(e) => {
+                findings.push(LintFinding {
+                    skill: None,
+                    check: "pipeline-skill",
+                    level: LintLevel::Warning,
+                    message: format!("could not load pipeline: {}", e),
+                    path: Some(rel_path),
+                });
+                continue;
+            }
+        };
+        let Some(metadata) = def.metadata else {
+            continue;
+        };
+        for skill in metadata.skills {
+            if !known.contains(&skill) {
+                findings.push(LintFinding {
+                    skill: None,
+                    check: "pipeline-skill",
+                    level: LintLevel::Error,
+                    message: format!(
+                        "pipeline '{}' references unknown skill '{}'",
+                        def.name, skill
+                    ),
+                    path: Some(rel_path.clone()),
+                });
+            }
+        }
+    }
+    findings
+}
+
+/// Render a skill's projection for `target`, or `None` if the target does not
+/// apply to this skill (claude-code only projects hierarchical skills).
+fn render_projection(target: &str, name: &str, skill_dir: &Path) -> Option<String> {
+    let skill_file = skill_dir.join("SKILL.md");
+    match target {
+        // The agents projection symlinks the skill directory verbatim.
+        "agents" => fs::read_to_string(&skill_file).ok(),
+        "claude-code" => {
+            let (category, action) = name.split_once('/')?;
+            crate::sync_core::render_claude_code_command(category, action, &skill_file)
+        }
+        _ => None,
+    }
+}
+
+fn golden_path(ctx: &LintContext<'_>, name: &str, target: &str) -> PathBuf {
+    ctx.fixtures_dir.join(name).join(format!("{}.md", target))
+}
+
+/// Compare each existing golden fixture against a fresh render.
+fn check_golden(ctx: &LintContext<'_>, name: &str, skill_dir: &Path) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    for target in GOLDEN_TARGETS {
+        let path = golden_path(ctx, name, target);
+        let Ok(expected) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let message = match render_projection(target, name, skill_dir) {
+            None => format!(
+                "golden fixture exists but the {} projection does not apply to this skill",
+                target
+            ),
+            Some(actual) if actual == expected => continue,
+            Some(actual) => format!(
+                "{} projection differs from golden fixture ({})",
+                target,
+                first_difference(&expected, &actual)
+            ),
+        };
+        findings.push(LintFinding {
+            skill: Some(name.to_string()),
+            check: "golden",
+            level: LintLevel::Error,
+            message,
+            path: Some(relative(ctx.project_root, &path)),
+        });
+    }
+    findings
+}
+
+/// Write golden fixtures for every target that applies to the skill.
+fn write_golden(ctx: &LintContext<'_>, name: &str, skill_dir: &Path) -> Result<Vec<String>> {
+    let mut written = Vec::new();
+    for target in GOLDEN_TARGETS {
+        let Some(rendered) = render_projection(target, name, skill_dir) else {
+            continue;
+        };
+        let path = golden_path(ctx, name, target);
+        if fs::read_to_string(&path).is_ok_and(|existing| existing == rendered) {
+            continue;
+        }
+        crate::sync_core::ensure_parent_dirs(&path)?;
+        fs::write(&path, rendered).into_diagnostic()?;
+        written.push(relative(ctx.project_root, &path));
+    }
+    Ok(written)
+}
+
+/// Describe the first line at which two renders diverge.
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (Some(e), Some(a)) => {
+                return format!("line {}: expected {:?}, got {:?}", line, e, a);
+            }
+            (Some(e), None) => return format!("line {}: expected {:?}, got end of file", line, e),
+            (None, Some(a)) => return format!("line {}: unexpected {:?}", line, a),
+            (None, None) => return "trailing whitespace differs".to_string(),
+        }
+    }
+}
+
+fn relative(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+fn print_findings(checked: usize, findings: &[LintFinding], golden_updated: &[String]) {
+    println!();
+    for path in golden_updated {
+        println!("  {} Updated golden fixture {}", "✓".green(), path);
+    }
+    for finding in findings {
+        let glyph = match finding.level {
+            LintLevel::Error => "✗".red().to_string(),
+            LintLevel::Warning => "⚠".yellow().to_string(),
+        };
+        let subject = finding
+            .skill
+            .clone()
+            .or_else(|| finding.path.clone())
+            .unwrap_or_default();
+        println!(
+            "  {} {} {} {}",
+            glyph,
+            subject.bold(),
+            format!("[{}]", finding.check).dimmed(),
+            finding.message
+        );
+    }
+
+    let errors = findings
+        .iter()
+        .filter(|f| f.level == LintLevel::Error)
+        .count();
+    let warnings = findings.len() - errors;
+    if findings.is_empty() {
+        println!("  {} {} skill(s) passed lint", "✓".green(), checked);
+    } else {
+        println!();
+        println!(
+            "  {} skill(s) checked: {} error(s), {} warning(s)",
+            checked, errors, warnings
+        );
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_context(root: &Path) -> LintContext<'_> {
+        build_context(root)
+    }
+
+    #[test]
+    fn frontmatter_flags_unknown_keys_and_mismatched_name() {
+        let findings = check_frontmatter(
+            "my-skill",
+            "name: other-skill\ndescription: Does a useful thing for the project\ntags: [x]",
+        );
+        assert!(
+            findings
+                .iter()
+                .any(|(l, m)| *l == LintLevel::Warning
+                    && m.contains("unknown frontmatter key 'tags'"))
+        );
+        assert!(
+            findings
+                .iter()
+                .any(|(l, m)| *l == LintLevel::Error && m.contains("does not match"))
+        );
+    }
+
+    #[test]
+    fn frontmatter_enforces_description_length() {
+        let empty = check_frontmatter("s", "name: s\ndescription: \"\"");
+        assert!(empty.iter().any(|(_, m)| m == "description is empty"));
+
+        let short = check_frontmatter("s", "name: s\ndescription: Short");
+        assert!(
+            short
+                .iter()
+                .any(|(l, m)| *l == LintLevel::Warning && m.contains("only 5 characters"))
+        );
+
+        let long = format!(
+            "name: s\ndescription: {}",
+            "x".repeat(MAX_DESCRIPTION_CHARS + 1)
+        );
+        assert!(
+            check_frontmatter("s", &long)
+                .iter()
+                .any(|(l, m)| *l == LintLevel::Error && m.contains("max 1024"))
+        );
+    }
+
+    #[test]
+    fn command_refs_validate_verbs_and_subcommands() {
+        let tmp = TempDir::new().unwrap();
+        let ctx = test_context(tmp.path());
+        let body = "Run `wai status` then `wai handoff create my-app`.\n\
+                    \n\
+                    ```bash\n\
+                    wai handoff explode\n\
+                    wai frobnicate\n\
+                    ```\n\
+                    Prose mentioning wai frobnicate is ignored.\n";
+        let refs = check_command_refs(&ctx, body);
+        assert_eq!(refs.len(), 2, "{:?}", refs);
+        assert!(refs[0].1.contains("wai handoff explode"));
+        assert_eq!(refs[0].0, 4);
+        assert!(refs[1].1.contains("wai frobnicate"));
+    }
+
+    #[test]
+    fn command_refs_allow_open_subcommand_verbs() {
+        let tmp = TempDir::new().unwrap();
+        let ctx = test_context(tmp.path());
+        assert!(check_command_refs(&ctx, "`wai project my-app`").is_empty());
+    }
+
+    #[test]
+    fn file_refs_resolve_against_skill_dir_then_repo_root() {
+        let tmp = TempDir::new().unwrap();
+        let skill_dir = tmp.path().join("skills/demo");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(skill_dir.join("notes.md"), "").unwrap();
+        fs::write(tmp.path().join("README.md"), "").unwrap();
+
+        let body = "See [notes](notes.md), [readme](README.md#usage), \
+                    [site](https://example.com), [gone](missing.md).\n\
+                    `[code](ignored.md)`\n";
+        let missing = check_file_refs(tmp.path(), &skill_dir, body);
+        assert_eq!(missing, vec![(1, "missing.md".to_string())]);
+    }
+
+    #[test]
+    fn golden_reports_first_differing_line() {
+        assert_eq!(
+            first_difference("a\nb\n", "a\nc\n"),
+            "line 2: expected \"b\", got \"c\""
+        );
+    }
+
+    #[test]
+    fn render_projection_claude_code_only_for_hierarchical_skills() {
+        let tmp = TempDir::new().unwrap();
+        let flat = tmp.path().join("flat");
+        fs::create_dir_all(&flat).unwrap();
+        fs::write(
+            flat.join("SKILL.md"),
+            "---\nname: flat\ndescription: A flat skill\n---\n\nBody\n",
+        )
+        .unwrap();
+        assert!(render_projection("claude-code", "flat", &flat).is_none());
+        assert!(render_projection("agents", "flat", &flat).is_some());
+
+        let rendered = render_projection("claude-code", "issue/gather", &flat).unwrap();
+        assert!(rendered.contains("name: \"Issue: Gather\""));
+        assert!(rendered.ends_with("Body\n") || rendered.ends_with("Body"));
+    }
+}
@@ -1,5 +1,7 @@
 mod archive;
+mod lint;
 mod metadata;
+mod provenance;
 mod skills;
 mod validation;
 
@@ -7,7 +9,7 @@ use miette::Result;
 
 use crate::cli::{
     ResourceAddCommands, ResourceExportArgs, ResourceImportCommands, ResourceInstallArgs,
-    ResourceListCommands,
+    ResourceLintArgs, ResourceListCommands,
 };
 
 // Re-export public items consumed by other modules
@@ -51,7 +53,11 @@ pub fn run_list(cmd: ResourceListCommands) -> Result<()> {
 pub fn run_import(cmd: ResourceImportCommands) -> Result<()> {
     match cmd {
         ResourceImportCommands::Skills { from } => skills::import_skills(from),
-        ResourceImportCommands::Archive { file, yes } => archive::import_skills_archive(&file, yes),
+        ResourceImportCommands::Archive {
+            file,
+            yes,
+            allow_untrusted,
+        } => archive::import_skills_archive(&file, yes, allow_untrusted),
     }
 }
 
@@ -68,5 +74,18 @@ pub fn run_install(args: ResourceInstallArgs) -> Result<()> {
 }
 
 pub fn run_export(args: ResourceExportArgs) -> Result<()> {
-    archive::export_skills(&args.skills, &args.output)
+    archive::export_skills(&args.skills, &args.output, args.sign)
+}
+
+pub fn run_trust(
+    public_key: Option<String>,
+    label: Option<String>,
+    list: bool,
+    revoke: Option<String>,
+) -> Result<()> {
+    provenance::run_trust(public_key, label, list, revoke)
+}
+
+pub fn run_lint(args: ResourceLintArgs) -> Result<()> {
+    lint::run_lint(&args.skills, args.update_golden, args.json)
 }
@@ -0,0 +1,459 @@
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use miette::{IntoDiagnostic, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::context::current_context;
+use crate::error::WaiError;
+use crate::output::print_envelope_list;
+use crate::plugin::{TrustStore, TrustedKey, wai_data_dir};
+
+/// Archive entry holding the per-file SHA-256 manifest.
+pub(super) const MANIFEST_ENTRY: &str = "wai-manifest.toml";
+
+/// Archive entry holding the detached ed25519 signature over the manifest.
+pub(super) const SIGNATURE_ENTRY: &str = "wai-manifest.sig";
+
+/// File name of the user's archive signing key inside the wai data directory.
+const SIGNING_KEY_FILE: &str = "archive-signing.key";
+
+const MANIFEST_FORMAT: u32 = 1;
+const SIGNATURE_ALGORITHM: &str = "ed25519";
+
+/// Manifest embedded in every exported skill archive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ArchiveManifest {
+    pub format: u32,
+    pub created_at: String,
+    #[serde(default)]
+    pub files: Vec<ManifestFile>,
+}
+
+/// A single archive entry and its content digest.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct ManifestFile {
+    pub path: String,
+    pub sha256: String,
+}
+
+/// Detached signature over the raw manifest bytes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ManifestSignature {
+    pub algorithm: String,
+    /// Hex-encoded ed25519 public key of the signer.
+    pub public_key: String,
+    /// Hex-encoded ed25519 signature.
+    pub signature: String,
+}
+
+/// Outcome of verifying an archive that passed integrity checks.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Provenance {
+    /// Signed by a key in the trust store.
+    Trusted { label: String, public_key: String },
+    /// Correctly signed, but by a key that is not in the trust store.
+    UntrustedSigner { public_key: String },
+    /// No signature. `has_manifest` is false for archives from older wai versions.
+    Unsigned { has_manifest: bool },
+}
+
+/// Hex-encoded SHA-256 of `content`.
+pub(crate) fn sha256_hex(content: &[u8]) -> String {
+    hex::encode(Sha256::digest(content))
+}
+
+/// Build a manifest covering every `(archive_path, content)` entry.
+pub(crate) fn build_manifest(entries: &[(String, Vec<u8>)]) -> ArchiveManifest {
+    ArchiveManifest {
+        format: MANIFEST_FORMAT,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        files: entries
+            .iter()
+            .map(|(path, content)| ManifestFile {
+                path: path.clone(),
+                sha256: sha256_hex(content),
+            })
+            .collect(),
+    }
+}
+
+/// Sign the raw manifest bytes with `key`.
+pub(crate) fn sign_manifest(manifest_bytes: &[u8], key: &SigningKey) -> ManifestSignature {
+    ManifestSignature {
+        algorithm: SIGNATURE_ALGORITHM.to_string(),
+        public_key: hex::encode(key.verifying_key().to_bytes()),
+        signature: hex::encode(key.sign(manifest_bytes).to_bytes()),
+    }
+}
+
+/// Verify archive contents against the embedded manifest and signature.
+///
+/// Returns an error when the archive has been tampered with: a digest
+/// mismatch, a file missing from or not covered by the manifest, or an
+/// invalid signature. Otherwise reports whether the signer is trusted.
+pub(crate) fn verify_archive(
+    entries: &[(String, Vec<u8>)],
+    manifest_bytes: Option<&[u8]>,
+    signature_bytes: Option<&[u8]>,
+    trust_store: &TrustStore,
+) -> Result<Provenance> {
+    let Some(manifest_bytes) = manifest_bytes else {
+        if signature_bytes.is_some() {
+            miette::bail!(
+                "Archive is tampered: '{}' present without '{}'",
+                SIGNATURE_ENTRY,
+                MANIFEST_ENTRY
+            );
+        }
+        return Ok(Provenance::Unsigned {
+            has_manifest: false,
+        });
+    };
+
+    let manifest_str = std::str::from_utf8(manifest_bytes)
+        .map_err(|_| miette::miette!("Archive manifest is not valid UTF-8"))?;
+    let manifest: ArchiveManifest = toml::from_str(manifest_str)
+        .map_err(|e| miette::miette!("Archive manifest is malformed: {}", e))?;
+    if manifest.format != MANIFEST_FORMAT {
+        miette::bail!(
+            "Unsupported archive manifest format {} (expected {})",
+            manifest.format,
+            MANIFEST_FORMAT
+        );
+    }
+
+    for (path, content) in entries {
+        let Some(file) = manifest.files.iter().find(|f| &f.path == path) else {
+            miette::bail!(
+                "Archive is tampered: '{}' is not listed in the manifest",
+                path
+            );
+        };
+        if !file.sha256.eq_ignore_ascii_case(&sha256_hex(content)) {
+            miette::bail!("Archive is tampered: SHA-256 mismatch for '{}'", path);
+        }
+    }
+    for file in &manifest.files {
+        if !entries.iter().any(|(path, _)| path == &file.path) {
+            miette::bail!(
+                "Archive is tampered: '{}' is listed in the manifest but missing",
+                file.path
+            );
+        }
+    }
+
+    let Some(signature_bytes) = signature_bytes else {
+        return Ok(Provenance::Unsigned { has_manifest: true });
+    };
+
+    let signature_str = std::str::from_utf8(signature_bytes)
+        .map_err(|_| miette::miette!("Archive signature is not valid UTF-8"))?;
+    let signature: ManifestSignature = toml::from_str(signature_str)
+        .map_err(|e| miette::miette!("Archive signature is malformed: {}", e))?;
+    if signature.algorithm != SIGNATURE_ALGORITHM {
+        miette::bail!(
+            "Unsupported archive signature algorithm '{}'",
+            signature.algorithm
+        );
+    }
+
+    let verifying_key = parse_public_key(&signature.public_key)?;
+    let sig_bytes: [u8; 64] = hex::decode(&signature.signature)
+        .ok()
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| miette::miette!("Archive signature is malformed: bad signature bytes"))?;
+    verifying_key
+        .verify(manifest_bytes, &Signature::from_bytes(&sig_bytes))
+        .map_err(|_| miette::miette!("Archive is tampered: manifest signature does not verify"))?;
+
+    let public_key = hex::encode(verifying_key.to_bytes());
+    Ok(match trust_store.trusted_key(&public_key) {
+        Some(key) => Provenance::Trusted {
+            label: key.label.clone(),
+            public_key,
+        },
+        None => Provenance::UntrustedSigner { public_key },
+    })
+}
+
+/// Parse a hex-encoded ed25519 public key.
+pub(crate) fn parse_public_key(public_key: &str) -> Result<VerifyingKey> {
+    let bytes: [u8; 32] = hex::decode(public_key.trim())
+        .ok()
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| {
+            miette::miette!(
+                "Invalid public key '{}': expected 64 hex characters",
+                public_key
+            )
+        })?;
+    VerifyingKey::from_bytes(&bytes)
+        .map_err(|_| miette::miette!("Invalid public key '{}': not an ed25519 key", public_key))
+}
+
+/// Get the path to the user's archive signing key.
+pub(crate) fn signing_key_path() -> PathBuf {
+    wai_data_dir().join(SIGNING_KEY_FILE)
+}
+
+/// Load the user's archive signing key, generating one on first use.
+///
+/// Returns the key and whether it was newly created.
+pub(crate) fn load_or_create_signing_key() -> Result<(SigningKey, bool)> {
+    let path = signing_key_path();
+    if path.exists() {
+        let content = fs::read_to_string(&path).into_diagnostic()?;
+        let seed: [u8; 32] = hex::decode(content.trim())
+            .ok()
+            .and_then(|b| b.try_into().ok())
+            .ok_or_else(|| miette::miette!("Signing key at '{}' is corrupted", path.display()))?;
+        return Ok((SigningKey::from_bytes(&seed), false));
+    }
+
+    let key = SigningKey::generate(&mut rand_core::OsRng);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).into_diagnostic()?;
+    }
+    fs::write(&path, hex::encode(key.to_bytes())).into_diagnostic()?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).into_diagnostic()?;
+    }
+    Ok((key, true))
+}
+
+/// `wai resource trust`: list, trust, or revoke archive signing keys.
+pub(crate) fn run_trust(
+    public_key: Option<String>,
+    label: Option<String>,
+    list: bool,
+    revoke: Option<String>,
+) -> Result<()> {
+    use owo_colors::OwoColorize;
+
+    let context = current_context();
+
+    if list {
+        let store = TrustStore::load();
+        let keys = store.keys();
+        if context.json {
+            return print_envelope_list(keys);
+        }
+        println!();
+        println!("  {} Trusted archive signing keys", "◆".cyan());
+        println!();
+        if keys.is_empty() {
+            println!("    No trusted keys yet.");
+            println!("    Trust a signer with: `wai resource trust <public-key> --label <name>`");
+        } else {
+            for k in keys {
+                println!("    {} {}", "•".dimmed(), k.label.bold());
+                println!("        {} {}", "↳".dimmed(), k.public_key.dimmed());
+            }
+        }
+        println!();
+        return Ok(());
+    }
+
+    if let Some(key) = revoke {
+        let mut store = TrustStore::load();
+        let removed = store.revoke_key(key.trim());
+        store
+            .save()
+            .map_err(|e| WaiError::PluginTrustError { message: e })?;
+        if context.json {
+            return print_envelope_list(serde_json::json!({
+                "revoked": removed,
+                "public_key": key,
+            }));
+        }
+        if removed {
+            println!("  {} Revoked trust for key {}", "✓".green(), key.dimmed());
+        } else {
+            println!("  {} Key {} was not trusted", "○".dimmed(), key.dimmed());
+        }
+        return Ok(());
+    }
+
+    let Some(key) = public_key else {
+        return Err(WaiError::PluginTrustError {
+            message: "provide a public key to trust, or use --list/--revoke".to_string(),
+        }
+        .into());
+    };
+    crate::context::require_safe_mode("trust archive signing key")?;
+
+    // Normalise through the parser so the store only ever holds valid keys.
+    let key = hex::encode(parse_public_key(&key)?.to_bytes());
+    let label = label.unwrap_or_else(|| key[..16].to_string());
+
+    let mut store = TrustStore::load();
+    store.trust_key(TrustedKey {
+        label: label.clone(),
+        public_key: key.clone(),
+        added_at: chrono::Utc::now().to_rfc3339(),
+    });
+    store
+        .save()
+        .map_err(|e| WaiError::PluginTrustError { message: e })?;
+
+    if context.json {
+        return print_envelope_list(serde_json::json!({
+            "label": label,
+            "public_key": key,
+        }));
+    }
+    println!(
+        "  {} Trusted archive signing key '{}' ({})",
+        "✓".green(),
+        label,
+        &key[..16]
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::TrustedKey;
+
+    fn test_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    fn sample_entries() -> Vec<(String, Vec<u8>)> {
+        vec![
+            ("gather/SKILL.md".to_string(), b"gather".to_vec()),
+            ("issue/create/SKILL.md".to_string(), b"create".to_vec()),
+        ]
+    }
+
+    fn signed(entries: &[(String, Vec<u8>)], key: &SigningKey) -> (Vec<u8>, Vec<u8>) {
+        let manifest = toml::to_string_pretty(&build_manifest(entries)).unwrap();
+        let signature = sign_manifest(manifest.as_bytes(), key);
+        (
+            manifest.into_bytes(),
+            toml::to_string_pretty(&signature).unwrap().into_bytes(),
+        )
+    }
+
+    fn store_trusting(key: &SigningKey) -> TrustStore {
+        let mut store = TrustStore::default();
+        store.trust_key(TrustedKey {
+            label: "team".to_string(),
+            public_key: hex::encode(key.verifying_key().to_bytes()),
+            added_at: "t".to_string(),
+        });
+        store
+    }
+
+    #[test]
+    fn legacy_archive_without_manifest_is_unsigned() {
+        let result = verify_archive(&sample_entries(), None, None, &TrustStore::default());
+        assert_eq!(
+            result.unwrap(),
+            Provenance::Unsigned {
+                has_manifest: false
+            }
+        );
+    }
+
+    #[test]
+    fn signed_archive_with_trusted_key_verifies() {
+        let key = test_key(7);
+        let entries = sample_entries();
+        let (manifest, sig) = signed(&entries, &key);
+        let result = verify_archive(&entries, Some(&manifest), Some(&sig), &store_trusting(&key));
+        assert!(matches!(result.unwrap(), Provenance::Trusted { label, .. } if label == "team"));
+    }
+
+    #[test]
+    fn signed_archive_with_unknown_key_is_untrusted() {
+        let key = test_key(7);
+        let entries = sample_entries();
+        let (manifest, sig) = signed(&entries, &key);
+        let result = verify_archive(
+            &entries,
+            Some(&manifest),
+            Some(&sig),
+            &TrustStore::default(),
+        );
+        assert!(matches!(
+            result.unwrap(),
+            Provenance::UntrustedSigner { .. }
+        ));
+    }
+
+    #[test]
+    fn modified_content_is_rejected() {
+        let key = test_key(7);
+        let mut entries = sample_entries();
+        let (manifest, sig) = signed(&entries, &key);
+        entries[0].1 = b"evil".to_vec();
+        let err = verify_archive(&entries, Some(&manifest), Some(&sig), &store_trusting(&key))
+            .unwrap_err();
+        assert!(err.to_string().contains("SHA-256 mismatch"));
+    }
+
+    #[test]
+    fn extra_and_missing_entries_are_rejected() {
+        let key = test_key(7);
+        let entries = sample_entries();
+        let (manifest, sig) = signed(&entries, &key);
+
+        let mut extra = entries.clone();
+        extra.push(("evil/SKILL.md".to_string(), b"evil".to_vec()));
+        let err = verify_archive(&extra, Some(&manifest), Some(&sig), &TrustStore::default())
+            .unwrap_err();
+        assert!(err.to_string().contains("not listed in the manifest"));
+
+        let missing = entries[..1].to_vec();
+        let err = verify_archive(
+            &missing,
+            Some(&manifest),
+            Some(&sig),
+            &TrustStore::default(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("missing"));
+    }
+
+    #[test]
+    fn rewritten_manifest_breaks_signature() {
+        let key = test_key(7);
+        let entries = sample_entries();
+        let (_, sig) = signed(&entries, &key);
+
+        // An attacker recomputes the manifest for modified content but cannot re-sign it.
+        let mut tampered = entries.clone();
+        tampered[0].1 = b"evil".to_vec();
+        let manifest = toml::to_string_pretty(&build_manifest(&tampered)).unwrap();
+        let err = verify_archive(
+            &tampered,
+            Some(manifest.as_bytes()),
+            Some(&sig),
+            &store_trusting(&key),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("signature does not verify"));
+    }
+
+    #[test]
+    fn signature_without_manifest_is_rejected() {
+        let key = test_key(7);
+        let entries = sample_entries();
+        let (_, sig) = signed(&entries, &key);
+        assert!(verify_archive(&entries, None, Some(&sig), &TrustStore::default()).is_err());
+    }
+
+    #[test]
+    fn parse_public_key_rejects_garbage() {
+        assert!(parse_public_key("not-hex").is_err());
+        assert!(parse_public_key("abcd").is_err());
+        let key = test_key(3);
+        assert!(parse_public_key(&hex::encode(key.verifying_key().to_bytes())).is_ok());
+    }
+}
@@ -544,6 +544,22 @@ fn warn_hardcoded_content(skill_path: &Path, project_name: &str, project_root: &
     let Ok(content) = fs::read_to_string(skill_path) else {
         return;
     };
+    let warnings = hardcoded_content_findings(&content, project_name, project_root);
+
+    if !warnings.is_empty() {
+        let _ = log::warning(format!(
+            "Skill may have hardcoded content ({}). Consider using $PROJECT, $REPO_ROOT, $ARGUMENTS instead.",
+            warnings.join("; ")
+        ));
+    }
+}
+
+/// Describe hardcoded project names or absolute repo paths found in skill content.
+pub(super) fn hardcoded_content_findings(
+    content: &str,
+    project_name: &str,
+    project_root: &Path,
+) -> Vec<String> {
     let root_str = project_root.to_string_lossy();
     let mut warnings: Vec<String> = Vec::new();
 
@@ -555,13 +571,7 @@ fn warn_hardcoded_content(skill_path: &Path, project_name: &str, project_root: &
     if root_str.len() > 1 && content.contains(root_str.as_ref()) {
         warnings.push(format!("contains absolute path '{}'", root_str));
     }
-
-    if !warnings.is_empty() {
-        let _ = log::warning(format!(
-            "Skill may have hardcoded content ({}). Consider using $PROJECT, $REPO_ROOT, $ARGUMENTS instead.",
-            warnings.join("; ")
-        ));
-    }
+    warnings
 }
 
 /// Install a skill from the current project into the global skills library
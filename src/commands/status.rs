@@ -21,6 +21,7 @@ use crate::workflows;
 
 use super::beads_summary;
 use super::pipeline::{PipelineDefinition, PipelineRun, load_pipeline_toml};
+use super::reflect::{ReflectDebt, workspace_debts};
 
 // ─── Pipeline state detection ─────────────────────────────────────────────────
 
@@ -208,6 +209,19 @@ pub fn run(verbose: u8) -> Result<()> {
                 println!("    {} beads: {}", "•".dimmed(), summary);
                 continue;
             }
+            if let Some(ref data) = output.data {
+                for entry in &data.status {
+                    println!(
+                        "    {} {}: {}",
+                        "•".dimmed(),
+                        entry.label.bold(),
+                        entry.value
+                    );
+                }
+            }
+            if output.content.is_empty() {
+                continue;
+            }
             println!("    {} {}:", "•".dimmed(), output.label.bold());
             for line in output.content.lines().take(5) {
                 println!("      {}", line.dimmed());
@@ -311,6 +325,21 @@ pub fn run(verbose: u8) -> Result<()> {
         PipelineStatusInfo::None => {}
     }
 
+    // Reflection debt — only projects whose policy says a reflection is due.
+    let reflect_due = due_reflections(&project_root);
+    if !reflect_due.is_empty() {
+        println!();
+        println!("  {} Reflection due", "◆".cyan());
+        for debt in &reflect_due {
+            println!(
+                "    {} {}  {}",
+                "•".dimmed(),
+                debt.project.bold(),
+                debt.reason().dimmed()
+            );
+        }
+    }
+
     // Doctor health summary — one line when not clean, silent when green.
     let health = crate::commands::doctor::health_summary(&project_root);
     if !health.is_healthy() {
@@ -386,6 +415,18 @@ pub fn run(verbose: u8) -> Result<()> {
         }
     }
 
+    // Plugin suggestions from JSON-protocol hooks
+    for s in plugin::collect_plugin_suggestions(&hook_outputs) {
+        println!("    {} {}: {}", "→".dimmed(), s.label, s.command);
+        suggestions.push(s);
+    }
+
+    for debt in &reflect_due {
+        let s = reflect_suggestion(debt);
+        println!("    {} {}: {}", "→".dimmed(), s.label, s.command);
+        suggestions.push(s);
+    }
+
     // Pipeline suggestions — always appended after workflow suggestions
     match &pipeline_state {
         PipelineStatusInfo::Active { .. } => {
@@ -454,8 +495,12 @@ fn render_json(project_root: &std::path::Path, _project_name: &str) -> Result<()
         })
         .collect();
 
-    let hook_outputs = plugin::run_hooks(project_root, "on_status")
+    let raw_hook_outputs = plugin::run_hooks(project_root, "on_status");
+    let plugin_status = plugin::collect_plugin_status(&raw_hook_outputs);
+    let plugin_suggestions = plugin::collect_plugin_suggestions(&raw_hook_outputs);
+    let hook_outputs = raw_hook_outputs
         .into_iter()
+        .filter(|output| !output.content.is_empty())
         .map(|output| HookOutput {
             label: output.label,
             content: output.content,
@@ -521,6 +566,11 @@ fn render_json(project_root: &std::path::Path, _project_name: &str) -> Result<()
             .collect(),
     });
 
+    suggestions.extend(plugin_suggestions);
+
+    let reflect_due = due_reflections(project_root);
+    suggestions.extend(reflect_due.iter().map(reflect_suggestion));
+
     // Pipeline state for JSON
     let pipeline_state = detect_pipeline_state(project_root);
     let pipeline = match &pipeline_state {
@@ -563,8 +613,10 @@ fn render_json(project_root: &std::path::Path, _project_name: &str) -> Result<()
         projects,
         plugins,
         hook_outputs,
+        plugin_status,
         openspec,
         pipeline,
+        reflect_debt: reflect_due.iter().map(ReflectDebt::entry).collect(),
         suggestions,
     };
 
@@ -572,6 +624,21 @@ fn render_json(project_root: &std::path::Path, _project_name: &str) -> Result<()
     Ok(())
 }
 
+/// Projects whose reflection is due, sorted by name.
+fn due_reflections(project_root: &Path) -> Vec<ReflectDebt> {
+    workspace_debts(project_root)
+        .into_iter()
+        .filter(ReflectDebt::is_due)
+        .collect()
+}
+
+fn reflect_suggestion(debt: &ReflectDebt) -> Suggestion {
+    Suggestion {
+        label: format!("Reflect on {}", debt.project),
+        command: format!("wai reflect -p {}", debt.project),
+    }
+}
+
 /// Build a genesis StatusBuilder with wai's status contributors registered.
 #[allow(dead_code)]
 ///
@@ -0,0 +1,178 @@
+use miette::{IntoDiagnostic, Result, miette};
+use owo_colors::OwoColorize;
+
+use crate::cli::TraceCommands;
+use crate::context::current_context;
+use crate::json::TraceListItem;
+use crate::output::{print_envelope_list, print_envelope_ok};
+use crate::trace::{self, Trace, TraceSource, discover_traces, find_trace, format_time};
+
+use super::require_project;
+
+pub fn run(cmd: TraceCommands) -> Result<()> {
+    match cmd {
+        TraceCommands::List { source, limit } => run_list(source.as_deref(), limit),
+        TraceCommands::Show { id } => run_show(&id),
+        TraceCommands::Import { id, latest } => run_import(id.as_deref(), latest),
+    }
+}
+
+fn list_item(t: &Trace) -> TraceListItem {
+    TraceListItem {
+        id: t.id(),
+        source: t.source.name().to_string(),
+        session_id: t.session_id.clone(),
+        fidelity: t.fidelity.name().to_string(),
+        started: t.started().map(|d| d.to_rfc3339()),
+        ended: t.ended().map(|d| d.to_rfc3339()),
+        turns: t.turns.len(),
+        tool_calls: t.tool_call_count(),
+        file_changes: t.file_changes.len(),
+        title: t.title(),
+        path: t.path.display().to_string(),
+    }
+}
+
+fn run_list(source: Option<&str>, limit: usize) -> Result<()> {
+    let project_root = require_project()?;
+    let source = match source {
+        Some(name) => Some(TraceSource::from_name(name).ok_or_else(|| {
+            miette!(
+                "Unknown trace source '{}'. Expected one of: claude-code, codex, gemini, amp",
+                name
+            )
+        })?),
+        None => None,
+    };
+    let traces: Vec<Trace> = discover_traces(&project_root)
+        .into_iter()
+        .filter(|t| source.is_none_or(|s| t.source == s))
+        .take(limit)
+        .collect();
+
+    if current_context().json {
+        return print_envelope_list(traces.iter().map(list_item).collect::<Vec<_>>());
+    }
+
+    println!();
+    println!("  {} Local Traces", "◆".cyan());
+    println!();
+    if traces.is_empty() {
+        println!(
+            "  {} No local traces found for this repository.",
+            "○".dimmed()
+        );
+        println!(
+            "  {} Have an exported transcript? Use: wai reflect --conversation <file>",
+            "→".dimmed()
+        );
+        println!();
+        return Ok(());
+    }
+
+    for t in &traces {
+        let counts = if t.file_changes.is_empty() {
+            format!(
+                "{} turns, {} tool calls",
+                t.turns.len(),
+                t.tool_call_count()
+            )
+        } else {
+            format!("{} file changes", t.file_changes.len())
+        };
+        println!(
+            "    {} {}  {}  [{}]  {}",
+            "•".dimmed(),
+            t.id().bold(),
+            format_time(t.ended()),
+            t.fidelity.name(),
+            counts.dimmed()
+        );
+        if let Some(title) = t.title() {
+            println!("      {} {}", "↳".dimmed(), title.dimmed());
+        }
+    }
+    println!();
+    Ok(())
+}
+
+fn run_show(id: &str) -> Result<()> {
+    let project_root = require_project()?;
+    let traces = discover_traces(&project_root);
+    let trace = find_trace(&traces, id).map_err(|e| miette!("{}", e))?;
+
+    if current_context().json {
+        return print_envelope_ok(trace);
+    }
+
+    println!(
+        "# {} ({}, {})",
+        trace.id(),
+        trace.fidelity.name(),
+        format_time(trace.started())
+    );
+    print!("{}", trace::render_trace(trace));
+    Ok(())
+}
+
+fn run_import(id: Option<&str>, latest: bool) -> Result<()> {
+    let project_root = require_project()?;
+    let traces = discover_traces(&project_root);
+    let trace = match (id, latest) {
+        (Some(id), _) => find_trace(&traces, id).map_err(|e| miette!("{}", e))?,
+        (None, _) => traces
+            .first()
+            .ok_or_else(|| miette!("No local traces found for this repository"))?,
+    };
+
+    let dir = crate::config::traces_dir(&project_root);
+    std::fs::create_dir_all(&dir).into_diagnostic()?;
+    let now = chrono::Local::now();
+    let short_id: String = trace.session_id.chars().take(8).collect();
+    let path = dir.join(format!(
+        "{}-{}-{}.md",
+        now.format("%Y-%m-%d"),
+        trace.source.name(),
+        slug::slugify(&short_id)
+    ));
+
+    let mut content = format!(
+        "---\nsource: {}\nsession_id: \"{}\"\nsource_path: \"{}\"\nfidelity: {}\nimported: \"{}\"\n",
+        trace.source.name(),
+        trace.session_id,
+        trace.path.display(),
+        trace.fidelity.name(),
+        now.to_rfc3339()
+    );
+    if let Some(started) = trace.started() {
+        content.push_str(&format!("started: \"{}\"\n", started.to_rfc3339()));
+    }
+    if let Some(ended) = trace.ended() {
+        content.push_str(&format!("ended: \"{}\"\n", ended.to_rfc3339()));
+    }
+    content.push_str("---\n\n");
+    content.push_str(&format!("# Trace {}\n", trace.id()));
+    content.push_str(&trace::render_trace(trace));
+    std::fs::write(&path, content).into_diagnostic()?;
+
+    let rel = path
+        .strip_prefix(&project_root)
+        .unwrap_or(&path)
+        .display()
+        .to_string();
+    if current_context().json {
+        return print_envelope_ok(serde_json::json!({
+            "id": trace.id(),
+            "fidelity": trace.fidelity.name(),
+            "path": rel,
+        }));
+    }
+    println!(
+        "  {} Imported {} ({}) → {}",
+        "✓".green(),
+        trace.id().bold(),
+        trace.fidelity.name(),
+        rel
+    );
+    Ok(())
+}
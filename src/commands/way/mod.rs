@@ -64,7 +64,7 @@ pub fn run(topic: Option<String>, fix: Option<String>) -> Result<()> {
 
     let context = current_context();
 
-    let checks = vec![
+    let mut checks = vec![
         check_task_runner(&repo_root),
         hooks::check_git_hooks(&repo_root),
         check_editorconfig(&repo_root),
@@ -88,6 +88,7 @@ pub fn run(topic: Option<String>, fix: Option<String>) -> Result<()> {
         check_beads(&repo_root),
         check_openspec(&repo_root),
     ];
+    checks.extend(check_plugin_conventions(&repo_root));
 
     let summary = Summary {
         pass: checks
@@ -1496,6 +1497,28 @@ fn fix_skills(repo_root: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Team conventions declared as `kind = "way"` checks by custom plugins.
+///
+/// Way checks are recommendations, so a failing check is reported as a
+/// recommendation regardless of its declared severity.
+fn check_plugin_conventions(repo_root: &Path) -> Vec<WayCheckEntry> {
+    crate::plugin::run_plugin_checks(repo_root, crate::plugin::PluginCheckKind::Way)
+        .into_iter()
+        .map(|result| WayCheckEntry {
+            name: format!("{}: {}", result.plugin, result.name),
+            status: if result.status == crate::plugin::HookCheckStatus::Pass {
+                CheckStatus::Pass
+            } else {
+                CheckStatus::Warn
+            },
+            message: result.message,
+            intent: result.intent,
+            success_criteria: result.success_criteria,
+            suggestion: result.fix.map(|fix| format!("Run: {}", fix)),
+        })
+        .collect()
+}
+
 fn render_human(checks: &[WayCheckEntry], summary: &Summary, verbose: u8) -> Result<()> {
     use cliclack::outro;
     use miette::IntoDiagnostic;
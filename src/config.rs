@@ -36,11 +36,15 @@ pub const BUILTIN_PIPELINE_TEMPLATES_HELP: &str =
 
 /// Template and pattern resource files within resources/
 pub const TEMPLATES_DIR: &str = "templates";
+pub const HANDOFF_TEMPLATES_DIR: &str = "handoff";
 pub const PATTERNS_DIR: &str = "patterns";
 
 /// Reflection resource files within resources/
 pub const REFLECTIONS_DIR: &str = "reflections";
 
+/// Imported agent session traces within resources/
+pub const TRACES_DIR: &str = "traces";
+
 /// Per-project subdirectories
 pub const RESEARCH_DIR: &str = "research";
 pub const PLANS_DIR: &str = "plans";
@@ -69,6 +73,12 @@ pub struct ProjectConfig {
     /// Use [`ProjectConfig::llm_config`] to access the effective settings.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub why: Option<LlmConfig>,
+    /// Section selection and size budget for managed blocks (`[managed_block]`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub managed_block: Option<ManagedBlockConfig>,
+    /// Reflection cadence (`[reflect]`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reflect: Option<ReflectConfig>,
 }
 
 impl ProjectConfig {
@@ -118,6 +128,194 @@ pub struct LlmConfig {
     pub privacy_notice_shown: Option<bool>,
 }
 
+/// Controls what wai writes into the managed blocks of agent instruction files.
+///
+/// Stored under `[managed_block]` in `.wai/config.toml`. Top-level keys apply
+/// to every target; `[managed_block.targets."<file>"]` overrides them for one
+/// file (e.g. `CLAUDE.md`, `AGENTS.md`, or `.wai/AGENTS.md`).
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct ManagedBlockConfig {
+    #[serde(flatten)]
+    pub settings: ManagedBlockSettings,
+
+    /// Per-file overrides keyed by path relative to the repo root.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub targets: std::collections::BTreeMap<String, ManagedBlockSettings>,
+}
+
+impl ManagedBlockConfig {
+    /// Effective settings for `target`: the target override where set,
+    /// otherwise the top-level value.
+    pub fn for_target(&self, target: &str) -> ManagedBlockSettings {
+        let base = &self.settings;
+        let Some(over) = self.targets.get(target) else {
+            return base.clone();
+        };
+        ManagedBlockSettings {
+            detail: over.detail.or(base.detail),
+            sections: over.sections.clone().or_else(|| base.sections.clone()),
+            exclude: over.exclude.clone().or_else(|| base.exclude.clone()),
+            max_chars: over.max_chars.or(base.max_chars),
+            max_tokens: over.max_tokens.or(base.max_tokens),
+            pointer: over.pointer.clone().or_else(|| base.pointer.clone()),
+            // File-specific keys never inherit from the top level.
+            comment: over.comment,
+            source: over.source.clone(),
+        }
+    }
+
+    /// Configured targets beyond the built-in `CLAUDE.md`, `AGENTS.md` and
+    /// `.wai/AGENTS.md`, sorted by path.
+    pub fn extra_targets(&self) -> impl Iterator<Item = &str> {
+        self.targets
+            .keys()
+            .map(String::as_str)
+            .filter(|t| !matches!(*t, "CLAUDE.md" | "AGENTS.md" | ".wai/AGENTS.md"))
+    }
+}
+
+/// How often each project should be reflected on.
+///
+/// Stored under `[reflect]` in `.wai/config.toml`. Top-level keys apply to
+/// every project; `[reflect.projects.<name>]` overrides them for one project.
+/// A reflection is due once either limit is reached.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct ReflectConfig {
+    #[serde(flatten)]
+    pub policy: ReflectPolicy,
+
+    /// Per-project overrides keyed by project name.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub projects: std::collections::BTreeMap<String, ReflectPolicy>,
+}
+
+impl ReflectConfig {
+    /// Effective policy for `project`: the project override where set,
+    /// otherwise the top-level value.
+    pub fn for_project(&self, project: &str) -> ReflectPolicy {
+        let base = &self.policy;
+        let Some(over) = self.projects.get(project) else {
+            return base.clone();
+        };
+        ReflectPolicy {
+            every_handoffs: over.every_handoffs.or(base.every_handoffs),
+            every_days: over.every_days.or(base.every_days),
+        }
+    }
+}
+
+/// One layer of reflection cadence settings. `0` disables a limit.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct ReflectPolicy {
+    /// Reflect after this many handoffs. Defaults to 5.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every_handoffs: Option<u32>,
+
+    /// Reflect once this many days have passed since the last reflection.
+    /// Off by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every_days: Option<u32>,
+}
+
+/// One layer of managed block settings. Every field is optional so target
+/// overrides only replace what they set.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct ManagedBlockSettings {
+    /// How much to write: "pointer", "standard" (default), or "full".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<BlockDetail>,
+
+    /// Optional sections to include. Omit to use the defaults.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sections: Option<Vec<BlockSection>>,
+
+    /// Optional sections to leave out, applied after `sections`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<Vec<BlockSection>>,
+
+    /// Upper bound on the block size in characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_chars: Option<usize>,
+
+    /// Upper bound on the block size in (estimated) tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<usize>,
+
+    /// File a pointer block sends agents to. Defaults to `.wai/AGENTS.md`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pointer: Option<String>,
+
+    /// Comment syntax for the block markers. Detected from the file name
+    /// when omitted. Only read from target tables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<CommentStyle>,
+
+    /// File (relative to the repo root) injected verbatim instead of the
+    /// generated instructions. Only read from target tables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+/// Comment syntax used for managed block markers in a target file.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentStyle {
+    /// `<!-- WAI:START -->` — markdown and other prose files.
+    Html,
+    /// `# WAI:START` — YAML, TOML, justfiles, shell, `.editorconfig`.
+    Hash,
+    /// `// WAI:START` — C-family and JavaScript-like sources.
+    Slash,
+}
+
+/// How much of the workflow reference a managed block carries.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockDetail {
+    /// A few lines pointing at the detailed reference.
+    Pointer,
+    /// Orientation, pipelines, and policy; details live in `.wai/AGENTS.md`.
+    #[default]
+    Standard,
+    /// The full workflow reference inline.
+    Full,
+}
+
+/// Optional managed block sections that config can switch on or off.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum BlockSection {
+    /// The "Available Pipelines" table.
+    Pipelines,
+    /// A list of installed skills. Off unless selected.
+    Skills,
+    /// openspec steps in the end-of-session checklist and quick reference.
+    Openspec,
+    /// The ubiquitous language navigation note.
+    UbiquitousLanguage,
+    /// The Rule of 5 reminder.
+    Ro5,
+}
+
+impl BlockSection {
+    /// Sections written when config does not list any.
+    pub const DEFAULTS: [BlockSection; 4] = [
+        BlockSection::Pipelines,
+        BlockSection::Openspec,
+        BlockSection::UbiquitousLanguage,
+        BlockSection::Ro5,
+    ];
+
+    /// Order in which sections are dropped to meet a budget — least useful first.
+    pub const DROP_ORDER: [BlockSection; 5] = [
+        BlockSection::Skills,
+        BlockSection::UbiquitousLanguage,
+        BlockSection::Ro5,
+        BlockSection::Openspec,
+        BlockSection::Pipelines,
+    ];
+}
+
 /// `WhyConfig` is a deprecated alias for [`LlmConfig`].
 ///
 /// Kept so that any external code compiled against this crate continues to
@@ -258,6 +456,18 @@ pub fn reflections_dir(project_root: &Path) -> PathBuf {
     resources_dir(project_root).join(REFLECTIONS_DIR)
 }
 
+/// Get the imported traces directory path (.wai/resources/traces/).
+pub fn traces_dir(project_root: &Path) -> PathBuf {
+    resources_dir(project_root).join(TRACES_DIR)
+}
+
+/// Get the handoff templates directory path (.wai/resources/templates/handoff/).
+pub fn handoff_templates_dir(project_root: &Path) -> PathBuf {
+    resources_dir(project_root)
+        .join(TEMPLATES_DIR)
+        .join(HANDOFF_TEMPLATES_DIR)
+}
+
 /// Get a specific project's directory path.
 pub fn project_path(project_root: &Path, name: &str) -> PathBuf {
     projects_dir(project_root).join(name)
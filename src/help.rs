@@ -268,16 +268,29 @@ pub fn command_help(name: &str) -> Option<HelpContent> {
         }),
         "handoff" => Some(HelpContent {
             about: "Generate handoff documents",
-            examples: &[(
-                "wai handoff create my-app",
-                "Generate handoff for a project",
-            )],
-            options: &[],
+            examples: &[
+                (
+                    "wai handoff create my-app",
+                    "Generate handoff for a project",
+                ),
+                (
+                    "wai handoff create my-app --draft",
+                    "Pre-fill sections from git, pipeline, beads and reviews",
+                ),
+            ],
+            options: &[
+                "--draft          Pre-fill What Was Done, Next Steps and Open Questions",
+                "--summarize      Summarize drafted work with the configured LLM (implies --draft)",
+                "--author <NAME>  Handoff author (default: $WAI_AGENT, then git user.name)",
+                "--session <ID>   Session ID (default: $WAI_SESSION_ID)",
+            ],
             advanced_options: &[],
             env_vars: &[("NO_COLOR", "Disable colored output")],
             internals: &[
                 "Aggregates research, plans, and designs into a single document",
                 "Includes phase history and timeline",
+                "--draft reads commits and diffstat since the previous handoff, the active pipeline step, `bd ready` and unresolved reviews",
+                "Templates: .wai/resources/templates/handoff/{project-<name>,phase-<phase>,default}.md",
             ],
         }),
         "plugin" => Some(HelpContent {
@@ -415,11 +428,20 @@ pub fn command_help(name: &str) -> Option<HelpContent> {
             options: &[],
             advanced_options: &[
                 "-p, --project <NAME>    Project name (auto-detected when only one exists)",
+                "--author <NAME>         Handoff author (default: $WAI_AGENT, then git user.name)",
+                "--session <ID>          Session ID (default: $WAI_SESSION_ID)",
+                "--reflect               Run `wai reflect` after writing the handoff",
+            ],
+            env_vars: &[
+                ("NO_COLOR", "Disable colored output"),
+                ("WAI_AGENT", "Author recorded on the handoff"),
+                ("WAI_SESSION_ID", "Session the handoff belongs to"),
             ],
-            env_vars: &[("NO_COLOR", "Disable colored output")],
             internals: &[
                 "Creates a handoff artifact under .wai/projects/<name>/",
-                "Writes .wai/.pending-resume signal for wai prime to detect",
+                "Handoffs are named <date>-<author>-<session>-session-end.md; same-day closes from one session update it in place",
+                "Records the handoff in .pending-resume, one line per session",
+                "Reminds you to reflect once the [reflect] policy in .wai/config.toml is reached",
             ],
         }),
         "prime" => Some(HelpContent {
@@ -433,15 +455,21 @@ pub fn command_help(name: &str) -> Option<HelpContent> {
                     "wai prime --project my-app",
                     "Orient for a specific project",
                 ),
+                (
+                    "wai prime --budget 2000",
+                    "Ranked context pack that fits in ~2000 tokens",
+                ),
             ],
             options: &[],
             advanced_options: &[
                 "-p, --project <NAME>    Project name (auto-detected when only one exists)",
+                "--budget <TOKENS>       Assemble a ranked context pack within the budget",
             ],
             env_vars: &[("NO_COLOR", "Disable colored output")],
             internals: &[
                 "Detects .wai/.pending-resume and shows RESUMING banner when present",
                 "Reads the most recent handoff artifact for next-step suggestions",
+                "Shows one line per author/session when several handoffs share the latest day",
             ],
         }),
         "why" => Some(HelpContent {
@@ -490,9 +518,11 @@ pub fn command_help(name: &str) -> Option<HelpContent> {
             advanced_options: &[
                 "-p, --project <NAME>         Project name (auto-detected when only one exists)",
                 "-c, --conversation <FILE>    Path to conversation transcript (richest context)",
+                "    --no-traces              Don't auto-select local agent traces",
                 "-o, --output <TARGET>        Output target: claude.md, agents.md, or both",
                 "    --dry-run                Show what would change without writing",
-                "-y, --yes                    Skip confirmation prompt",
+                "-y, --yes                    Skip confirmation prompt and accept all changes",
+                "    --reject <IDS>           Reject proposed bullet changes by ID (comma-separated)",
                 "    --save-memories          Save insights as beads memories",
             ],
             env_vars: &[
@@ -512,17 +542,30 @@ pub fn command_help(name: &str) -> Option<HelpContent> {
                 ("wai ls --root ~/dev", "Scan a custom root directory"),
                 ("wai ls --depth 2", "Limit scan to 2 levels deep"),
                 ("wai ls --timeout 5", "Stop scanning after 5 seconds"),
+                (
+                    "wai ls --detail --sort stale",
+                    "Dashboard, most stale artifacts first",
+                ),
+                (
+                    "wai ls --phase implement,review",
+                    "Only projects in these phases",
+                ),
             ],
             options: &[],
             advanced_options: &[
                 "-r, --root <PATH>    Root directory to scan (default: $HOME)",
                 "-d, --depth <N>      Maximum scan depth (default: 3)",
                 "-t, --timeout <S>    Stop scanning after this many seconds (default: 10)",
+                "--detail             Add last handoff, pipeline step, stale artifacts, health and reflect debt",
+                "--sort <KEY>         name, phase, stale, handoff, health or debt (default: name)",
+                "--phase <PHASES>     Only list projects in these phases (comma-separated)",
+                "--stale              Only list projects with stale artifacts",
             ],
             env_vars: &[("NO_COLOR", "Disable colored output")],
             internals: &[
                 "Walks the filesystem looking for .wai/ directories",
                 "Reads phase and beads issue counts from each workspace",
+                "--detail/--json also run a freshness scan and doctor per workspace",
             ],
         }),
         "tutorial" => Some(HelpContent {
@@ -558,6 +601,11 @@ pub fn command_help(name: &str) -> Option<HelpContent> {
                     "wai resource import archive skills.tar.gz",
                     "Import skills from archive",
                 ),
+                (
+                    "wai resource trust <PUBLIC_KEY> --label team",
+                    "Trust an archive signing key",
+                ),
+                ("wai resource lint", "Lint skills and run golden tests"),
             ],
             options: &[],
             advanced_options: &[
@@ -565,13 +613,20 @@ pub fn command_help(name: &str) -> Option<HelpContent> {
                 "install   --global               Install to ~/.wai/resources/skills/",
                 "install   --from-repo <PATH>     Copy skill from another repository",
                 "export    --output <FILE>        Output tar.gz archive path",
+                "export    --sign                 Sign the archive manifest with your ed25519 key",
                 "import archive --yes             Overwrite existing skills without prompting",
+                "import archive --allow-untrusted Accept unsigned or untrusted-signer archives",
+                "trust     --list | --revoke <KEY> List or revoke trusted signing keys",
+                "lint      --update-golden        Rewrite golden projection fixtures",
             ],
             env_vars: &[("NO_COLOR", "Disable colored output")],
             internals: &[
                 "Skills stored in .wai/resources/agent-config/skills/<name>/SKILL.md",
                 "Hierarchical skill names use one '/' separator (e.g. issue/gather)",
                 "Global skills stored in ~/.wai/resources/skills/",
+                "Archives embed wai-manifest.toml (SHA-256 per file) and optional wai-manifest.sig",
+                "Signing key and trusted keys live in the wai data dir (WAI_DATA_DIR)",
+                "Golden fixtures stored in .wai/resources/agent-config/skill-fixtures/<skill>/<target>.md",
             ],
         }),
         "way" => Some(HelpContent {
@@ -641,6 +696,7 @@ pub fn render_main_help(verbose: u8) -> String {
     out.push_str("  status    Check project status and suggest next steps\n");
     out.push_str("  sync      Sync agent configs to tool-specific locations\n");
     out.push_str("  timeline  View chronological timeline of artifacts\n");
+    out.push_str("  trace     Discover and import local agent session traces\n");
     out.push_str("  tutorial  Run the interactive quickstart tutorial\n");
     out.push_str("  way       Check repository best practices\n");
     out.push_str("  why       Ask why a decision was made (LLM-powered)\n");
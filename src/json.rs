@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::config::CONFIG_DIR;
 
@@ -65,13 +65,35 @@ pub struct StatusPayload {
     pub projects: Vec<StatusProject>,
     pub plugins: Vec<StatusPlugin>,
     pub hook_outputs: Vec<HookOutput>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub plugin_status: Vec<PluginStatusEntry>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub openspec: Option<StatusOpenSpec>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pipeline: Option<StatusPipeline>,
+    /// Projects whose reflection is due under their `[reflect]` policy.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reflect_debt: Vec<ReflectDebtEntry>,
     pub suggestions: Vec<Suggestion>,
 }
 
+/// Reflection debt of one project, as reported by `status` and `prime`.
+#[derive(Debug, Serialize)]
+pub struct ReflectDebtEntry {
+    pub project: String,
+    pub handoffs: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_reflected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub every_handoffs: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub every_days: Option<u32>,
+    pub due: bool,
+    pub reason: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct StatusOpenSpec {
     pub specs: Vec<String>,
@@ -99,6 +121,14 @@ pub struct HookOutput {
     pub content: String,
 }
 
+/// A status line contributed by a JSON-protocol plugin hook.
+#[derive(Debug, Serialize)]
+pub struct PluginStatusEntry {
+    pub plugin: String,
+    pub label: String,
+    pub value: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct SearchResult {
     pub path: String,
@@ -128,15 +158,35 @@ pub struct TimelinePayload {
     pub entries: Vec<TimelineEntry>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct TraceListItem {
+    pub id: String,
+    pub source: String,
+    pub session_id: String,
+    pub fidelity: String,
+    pub started: Option<String>,
+    pub ended: Option<String>,
+    pub turns: usize,
+    pub tool_calls: usize,
+    pub file_changes: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub path: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct PluginListItem {
     pub name: String,
     pub description: String,
     pub status: String,
     pub detected: bool,
+    /// Why the plugin's detector did or did not match.
+    pub reason: String,
     pub detector: Option<PluginDetector>,
     pub commands: Vec<PluginCommandInfo>,
     pub hooks: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub checks: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -197,18 +247,134 @@ pub struct OpenspecEntry {
     pub total: usize,
 }
 
+/// `wai ls --json`: one entry per project across all discovered workspaces.
+#[derive(Debug, Serialize)]
+pub struct LsPayload {
+    pub root: String,
+    pub projects: Vec<LsProject>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LsProject {
+    pub name: String,
+    pub workspace: String,
+    pub phase: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beads: Option<BeadsSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_handoff: Option<String>,
+    /// Active pipeline run of the project's workspace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pipeline: Option<LsPipeline>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stale_artifacts: Option<usize>,
+    /// Doctor summary of the project's workspace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health: Option<LsHealth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reflect_debt: Option<ReflectDebtEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LsPipeline {
+    pub name: String,
+    pub run_id: String,
+    pub step: usize,
+    pub total: usize,
+    pub step_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LsHealth {
+    pub warn: usize,
+    pub fail: usize,
+}
+
 #[derive(Debug, Serialize)]
 pub struct PrimePayload {
     pub project: Option<String>,
     pub phase: Option<String>,
     pub resume: bool,
     pub handoff_summary: Option<String>,
+    /// Latest handoff of each concurrent session (or each resumed session).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub handoffs: Vec<PrimeHandoff>,
     pub next_steps: Vec<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub plans: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub beads: Option<BeadsSummary>,
     pub openspec: Vec<OpenspecEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub plugin_status: Vec<PluginStatusEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub memories: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reflect_debt: Option<ReflectDebtEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<Suggestion>,
+}
+
+/// `wai prime --budget` output: the context pack that fit, in rank order,
+/// and what was left out.
+#[derive(Debug, Serialize)]
+pub struct PrimePackPayload {
+    pub project: String,
+    pub phase: String,
+    pub budget_tokens: usize,
+    pub used_tokens: usize,
+    pub included: Vec<PrimePackItem>,
+    pub dropped: Vec<PrimePackItem>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PrimePackItem {
+    /// `project`, `handoff`, `open-decisions`, `stale-artifacts`,
+    /// `pipeline-step` or `research`.
+    pub kind: String,
+    pub source: String,
+    /// Estimated size; for dropped items, what including them would have cost.
+    pub tokens: usize,
+    /// True when a shortened form was included instead of the full item.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub condensed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PrimeHandoff {
+    pub date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+    pub summary: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReflectReviewPayload {
+    pub project: String,
+    /// Reflection resource written, or that would be written on a dry run.
+    pub path: String,
+    pub written: bool,
+    pub changes: Vec<ReflectChange>,
+    /// IDs of changes rejected in this run.
+    pub rejected: Vec<usize>,
+    /// Changes skipped because they were rejected in an earlier review.
+    pub suppressed: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReflectChange {
+    pub id: usize,
+    /// "added", "removed", or "reworded".
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -230,6 +396,42 @@ pub struct PipelineCurrentPayload {
     pub next_command: Option<String>,
 }
 
+/// One structured finding reported by a pipeline oracle on stdout.
+///
+/// Oracles may print a JSON array of findings, or an object with a
+/// `findings` array; `oracle` and `artifact` are filled in by wai when the
+/// oracle leaves them out.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OracleFinding {
+    #[serde(default)]
+    pub oracle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact: Option<String>,
+    /// `critical`, `high`, `medium`, `low` or `info`.
+    #[serde(default = "default_finding_severity")]
+    pub severity: String,
+    /// Free-form location inside the artifact, e.g. a section heading.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    pub message: String,
+}
+
+fn default_finding_severity() -> String {
+    "info".to_string()
+}
+
+/// `wai pipeline check --json`.
+#[derive(Debug, Serialize)]
+pub struct PipelineCheckPayload {
+    pub run_id: String,
+    pub step: String,
+    pub passed: bool,
+    pub failures: Vec<String>,
+    pub findings: Vec<OracleFinding>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct PipelineCurrentStep {
     pub index: usize,
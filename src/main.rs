@@ -19,6 +19,7 @@ mod output;
 pub mod plugin;
 mod state;
 mod sync_core;
+mod trace;
 mod tutorial;
 #[allow(dead_code)]
 mod workflows;
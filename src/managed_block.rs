@@ -1,6 +1,9 @@
 use genesis::managed_block::{BlockDef, BlockInjector, BlockRegistry, InjectResult};
+use std::collections::BTreeSet;
 use std::path::Path;
 
+use crate::config::{BlockDetail, BlockSection, CommentStyle, ManagedBlockSettings, ProjectConfig};
+
 const WAI_START: &str = "<!-- WAI:START -->";
 const WAI_END: &str = "<!-- WAI:END -->";
 
@@ -15,6 +18,227 @@ fn ubiquitous_language_note(repo_root: &Path) -> Option<&'static str> {
     )
 }
 
+/// Rough characters-per-token ratio used to turn `max_tokens` into a size cap.
+pub const CHARS_PER_TOKEN: usize = 4;
+
+/// Target name under which `[managed_block.targets]` configures `.wai/AGENTS.md`.
+pub const DETAILED_TARGET: &str = ".wai/AGENTS.md";
+
+/// Managed block settings resolved for one target file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockOptions {
+    pub detail: BlockDetail,
+    pub sections: BTreeSet<BlockSection>,
+    /// Size cap in characters (the tighter of `max_chars` and `max_tokens`).
+    pub max_chars: Option<usize>,
+    /// File a pointer block refers agents to.
+    pub pointer: String,
+    /// Marker syntax for the target file.
+    pub comment: CommentStyle,
+    /// File injected verbatim instead of generated instructions.
+    pub source: Option<String>,
+}
+
+impl Default for BlockOptions {
+    fn default() -> Self {
+        Self::from_settings(&ManagedBlockSettings::default())
+    }
+}
+
+impl BlockOptions {
+    pub fn from_settings(settings: &ManagedBlockSettings) -> Self {
+        let mut sections: BTreeSet<BlockSection> = match &settings.sections {
+            Some(list) => list.iter().copied().collect(),
+            None => BlockSection::DEFAULTS.into_iter().collect(),
+        };
+        for excluded in settings.exclude.iter().flatten() {
+            sections.remove(excluded);
+        }
+        let token_cap = settings
+            .max_tokens
+            .map(|t| t.saturating_mul(CHARS_PER_TOKEN));
+        let max_chars = match (settings.max_chars, token_cap) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        Self {
+            detail: settings.detail.unwrap_or_default(),
+            sections,
+            max_chars,
+            pointer: settings
+                .pointer
+                .clone()
+                .unwrap_or_else(|| DETAILED_TARGET.to_string()),
+            comment: settings.comment.unwrap_or(CommentStyle::Html),
+            source: settings.source.clone(),
+        }
+    }
+
+    /// Options for `target` (a path relative to `repo_root`) from
+    /// `.wai/config.toml`. Falls back to the defaults when the config is
+    /// missing or has no `[managed_block]` section.
+    ///
+    /// The comment style is detected from the file name unless configured,
+    /// and files that are not prose default to a pointer block.
+    pub fn for_target(repo_root: &Path, target: &str) -> Self {
+        let settings = ProjectConfig::load(repo_root)
+            .ok()
+            .and_then(|c| c.managed_block)
+            .map(|mb| mb.for_target(target))
+            .unwrap_or_default();
+        let mut opts = Self::from_settings(&settings);
+        opts.comment = settings
+            .comment
+            .unwrap_or_else(|| comment_style_for(target));
+        if settings.detail.is_none() && opts.comment != CommentStyle::Html {
+            opts.detail = BlockDetail::Pointer;
+        }
+        opts
+    }
+
+    fn includes(&self, section: BlockSection) -> bool {
+        self.sections.contains(&section)
+    }
+
+    fn fits(&self, content: &str) -> bool {
+        self.max_chars
+            .is_none_or(|max| content.chars().count() <= max)
+    }
+}
+
+/// Comment syntax wai uses for `target` when config does not set one.
+pub fn comment_style_for(target: &str) -> CommentStyle {
+    let name = Path::new(target)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if matches!(
+        name.as_str(),
+        "justfile" | ".justfile" | "makefile" | "dockerfile" | ".editorconfig" | ".gitignore"
+    ) {
+        return CommentStyle::Hash;
+    }
+    let ext = name.rsplit_once('.').map(|(_, e)| e).unwrap_or("");
+    match ext {
+        "yml" | "yaml" | "toml" | "sh" | "bash" | "py" | "rb" | "just" | "mk" => CommentStyle::Hash,
+        "rs" | "js" | "mjs" | "ts" | "jsx" | "tsx" | "go" | "java" | "kt" | "swift" | "c" | "h"
+        | "cpp" | "jsonc" => CommentStyle::Slash,
+        _ => CommentStyle::Html,
+    }
+}
+
+fn start_marker(style: CommentStyle) -> &'static str {
+    match style {
+        CommentStyle::Html => WAI_START,
+        CommentStyle::Hash => "# WAI:START",
+        CommentStyle::Slash => "// WAI:START",
+    }
+}
+
+fn end_marker(style: CommentStyle) -> &'static str {
+    match style {
+        CommentStyle::Html => WAI_END,
+        CommentStyle::Hash => "# WAI:END",
+        CommentStyle::Slash => "// WAI:END",
+    }
+}
+
+/// Prefix every line of `text` with the line comment for `style`.
+fn comment_lines(text: &str, style: CommentStyle) -> String {
+    let prefix = match style {
+        CommentStyle::Html => return text.to_string(),
+        CommentStyle::Hash => "#",
+        CommentStyle::Slash => "//",
+    };
+    let mut out = String::from("\n");
+    for line in text.trim_end().lines() {
+        if line.is_empty() {
+            out.push_str(prefix);
+        } else {
+            out.push_str(&format!("{} {}", prefix, line));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Render with `render` under `opts`, shrinking until the result fits the
+/// budget: optional sections are dropped in [`BlockSection::DROP_ORDER`],
+/// then each lower level in `fallbacks` is tried. Returns the smallest
+/// rendering when nothing fits.
+fn fit_budget(
+    opts: &BlockOptions,
+    fallbacks: &[BlockDetail],
+    render: impl Fn(&BlockOptions) -> String,
+) -> String {
+    let mut attempt = opts.clone();
+    let mut output = render(&attempt);
+    if opts.max_chars.is_none() {
+        return output;
+    }
+    let mut drops = BlockSection::DROP_ORDER.iter();
+    let mut levels = fallbacks.iter();
+    while !attempt.fits(&output) {
+        if let Some(section) = drops.by_ref().find(|s| attempt.sections.contains(s)) {
+            attempt.sections.remove(section);
+        } else if let Some(level) = levels.next() {
+            attempt.detail = *level;
+        } else {
+            break;
+        }
+        output = render(&attempt);
+    }
+    output
+}
+
+/// Detail levels to fall back to, in order, when `detail` is over budget.
+fn lower_levels(detail: BlockDetail) -> &'static [BlockDetail] {
+    match detail {
+        BlockDetail::Full => &[BlockDetail::Standard, BlockDetail::Pointer],
+        BlockDetail::Standard => &[BlockDetail::Pointer],
+        BlockDetail::Pointer => &[],
+    }
+}
+
+fn has_ro5_skill(installed_skills: &[&str]) -> bool {
+    installed_skills
+        .iter()
+        .any(|s| *s == "ro5" || *s == "rule-of-5" || *s == "rule-of-5-universal")
+}
+
+fn skills_section(installed_skills: &[&str]) -> Option<String> {
+    if installed_skills.is_empty() {
+        return None;
+    }
+    let mut names = installed_skills.to_vec();
+    names.sort_unstable();
+    let mut section = String::from(
+        "## Installed Skills\n\n\
+         Defined in `.wai/resources/agent-config/skills/` and projected by `wai sync`:\n",
+    );
+    for name in names {
+        section.push_str(&format!("- `{}`\n", name));
+    }
+    Some(section)
+}
+
+const AUTONOMOUS_WORK_POLICY: &str = "\n\
+     ## Autonomous Work Policy\n\
+     \n\
+     Proceed without routine confirmation when the next step is clear.\n\
+     Do not ask to continue, fix, or commit — just do it.\n\
+     \n\
+     **Stop and ask** only when:\n\
+     - Conflicting requirements or ambiguous intent\n\
+     - Destructive actions (data loss, force-push, drop table)\n\
+     - Credentials, secrets, or external services not yet authorized\n\
+     - Unresolved test failures after two attempts\n\
+     - Push, deploy, or release — always get explicit authorization\n\
+     - Context approaching 40% — recommend `wai close` then `/clear`\n";
+
+const KEEP_BLOCK_NOTE: &str =
+    "Keep this managed block so `wai init` can refresh the instructions.\n\n";
+
 /// Info about an installed pipeline with metadata, for managed block generation.
 #[derive(Debug, Clone)]
 pub struct InstalledPipeline {
@@ -24,15 +248,17 @@ pub struct InstalledPipeline {
     pub step_count: usize,
 }
 
-/// Generate the **slim** managed block for CLAUDE.md / AGENTS.md.
+/// Generate the managed block for CLAUDE.md / AGENTS.md, markers included.
 ///
-/// This is Layer 1 of progressive disclosure: orient the agent, surface
-/// pipelines, and point to `.wai/AGENTS.md` for the full reference.
+/// With default options this is the **slim** block — Layer 1 of progressive
+/// disclosure: orient the agent, surface pipelines, and point to
+/// `.wai/AGENTS.md` for the full reference.
 pub fn wai_block_content(
     repo_root: &Path,
     detected_plugins: &[&str],
     installed_skills: &[&str],
     installed_pipelines: &[InstalledPipeline],
+    opts: &BlockOptions,
 ) -> String {
     // Match the on-disk form produced by `inject_managed_block` (genesis
     // `BlockInjector::inject` writes `start_marker + inner + end_marker` with no
@@ -43,26 +269,102 @@ pub fn wai_block_content(
         detected_plugins,
         installed_skills,
         installed_pipelines,
+        opts,
     ));
     block.push_str(WAI_END);
     block
 }
 
-/// Generate the inner content of the slim managed block (without WAI:START/WAI:END markers).
+/// Generate the inner content of the managed block (without WAI:START/WAI:END markers).
 ///
-/// Used by the genesis BlockInjector which adds its own markers.
+/// Used by the genesis BlockInjector which adds its own markers. `opts`
+/// selects the detail level and optional sections, and the result is shrunk
+/// to fit `opts.max_chars` when set.
 pub fn wai_block_inner(
     repo_root: &Path,
     detected_plugins: &[&str],
     installed_skills: &[&str],
     installed_pipelines: &[InstalledPipeline],
+    opts: &BlockOptions,
+) -> String {
+    fit_budget(opts, lower_levels(opts.detail), |attempt| {
+        match attempt.detail {
+            BlockDetail::Pointer => pointer_block(&attempt.pointer),
+            BlockDetail::Standard => slim_block(
+                repo_root,
+                detected_plugins,
+                installed_skills,
+                installed_pipelines,
+                attempt,
+            ),
+            BlockDetail::Full => full_block(
+                repo_root,
+                detected_plugins,
+                installed_skills,
+                installed_pipelines,
+                attempt,
+            ),
+        }
+    })
+}
+
+/// A terse block that only sends the agent to `pointer`.
+fn pointer_block(pointer: &str) -> String {
+    format!(
+        "# Workflow Tools\n\
+         \n\
+         This project uses **wai** to track the *why* behind decisions. Run\n\
+         `wai status` first to orient yourself, then read **`{}`** for the\n\
+         workflow reference before starting work.\n\
+         \n\
+         {}",
+        pointer, KEEP_BLOCK_NOTE
+    )
+}
+
+/// The detailed reference inline, for targets that want everything in one file.
+fn full_block(
+    repo_root: &Path,
+    detected_plugins: &[&str],
+    installed_skills: &[&str],
+    installed_pipelines: &[InstalledPipeline],
+    opts: &BlockOptions,
+) -> String {
+    let mut block = String::from(
+        "# Workflow Tools\n\
+         \n\
+         This project uses **wai** to track the *why* behind decisions — research,\n\
+         reasoning, and design choices that shaped the code. Run `wai status` first\n\
+         to orient yourself.\n",
+    );
+    let body = detailed_body(
+        repo_root,
+        detected_plugins,
+        installed_skills,
+        installed_pipelines,
+        opts,
+    );
+    if !body.starts_with('\n') {
+        block.push('\n');
+    }
+    block.push_str(&body);
+    block.push_str(AUTONOMOUS_WORK_POLICY);
+    block.push('\n');
+    block.push_str(KEEP_BLOCK_NOTE);
+    block
+}
+
+fn slim_block(
+    repo_root: &Path,
+    detected_plugins: &[&str],
+    installed_skills: &[&str],
+    installed_pipelines: &[InstalledPipeline],
+    opts: &BlockOptions,
 ) -> String {
     let has_beads = detected_plugins.contains(&"beads");
     let has_openspec = detected_plugins.contains(&"openspec");
     let has_companions = has_beads || has_openspec;
-    let has_ro5 = installed_skills
-        .iter()
-        .any(|s| *s == "ro5" || *s == "rule-of-5" || *s == "rule-of-5-universal");
+    let has_ro5 = opts.includes(BlockSection::Ro5) && has_ro5_skill(installed_skills);
 
     let mut block = String::new();
 
@@ -125,7 +427,7 @@ pub fn wai_block_inner(
     );
 
     // Available Pipelines — discovery-critical, stays in the slim block
-    if !installed_pipelines.is_empty() {
+    if opts.includes(BlockSection::Pipelines) && !installed_pipelines.is_empty() {
         block.push_str(
             "\n\
              ## Available Pipelines\n\
@@ -146,40 +448,35 @@ pub fn wai_block_inner(
         );
     }
 
-    if let Some(note) = ubiquitous_language_note(repo_root) {
+    if opts.includes(BlockSection::Skills)
+        && let Some(section) = skills_section(installed_skills)
+    {
+        block.push('\n');
+        block.push_str(&section);
+    }
+
+    if opts.includes(BlockSection::UbiquitousLanguage)
+        && let Some(note) = ubiquitous_language_note(repo_root)
+    {
         block.push('\n');
         block.push_str(note);
     }
 
     // Autonomous Work Policy — always present in slim block
-    block.push_str(
-        "\n\
-         ## Autonomous Work Policy\n\
-         \n\
-         Proceed without routine confirmation when the next step is clear.\n\
-         Do not ask to continue, fix, or commit — just do it.\n\
-         \n\
-         **Stop and ask** only when:\n\
-         - Conflicting requirements or ambiguous intent\n\
-         - Destructive actions (data loss, force-push, drop table)\n\
-         - Credentials, secrets, or external services not yet authorized\n\
-         - Unresolved test failures after two attempts\n\
-         - Push, deploy, or release — always get explicit authorization\n\
-         - Context approaching 40% — recommend `wai close` then `/clear`\n",
-    );
+    block.push_str(AUTONOMOUS_WORK_POLICY);
 
     // Pointer to detailed instructions
-    block.push_str(
+    block.push_str(&format!(
         "\n\
          ## Detailed Instructions\n\
          \n\
          Full workflow reference — session lifecycle, capturing work, command cheat\n\
-         sheets, cross-tool sync, and PARA structure — lives in **`.wai/AGENTS.md`**.\n\
+         sheets, cross-tool sync, and PARA structure — lives in **`{}`**.\n\
          Read it at the start of your first session or when you need detailed guidance.\n\
          \n\
-         Keep this managed block so `wai init` can refresh the instructions.\n\
-         \n",
-    );
+         {}",
+        opts.pointer, KEEP_BLOCK_NOTE
+    ));
 
     block
 }
@@ -188,25 +485,48 @@ pub fn wai_block_inner(
 ///
 /// This is Layer 2 of progressive disclosure: full session lifecycle,
 /// command reference, capturing work, cross-tool sync, PARA structure.
+/// `opts` selects optional sections and the size budget; the detail level
+/// does not apply here.
 pub fn wai_detailed_content(
     repo_root: &Path,
     detected_plugins: &[&str],
     installed_skills: &[&str],
     installed_pipelines: &[InstalledPipeline],
+    opts: &BlockOptions,
+) -> String {
+    fit_budget(opts, &[], |attempt| {
+        let mut doc = String::new();
+        doc.push_str("# wai Workflow Reference\n\n");
+        doc.push_str(
+            "> This file is managed by `wai init`. Do not edit manually.\n\
+             > Changes will be overwritten on the next init.\n\n",
+        );
+        doc.push_str(&detailed_body(
+            repo_root,
+            detected_plugins,
+            installed_skills,
+            installed_pipelines,
+            attempt,
+        ));
+        doc
+    })
+}
+
+/// Everything in the detailed reference below its title and notice.
+fn detailed_body(
+    repo_root: &Path,
+    detected_plugins: &[&str],
+    installed_skills: &[&str],
+    installed_pipelines: &[InstalledPipeline],
+    opts: &BlockOptions,
 ) -> String {
     let has_beads = detected_plugins.contains(&"beads");
     let has_openspec = detected_plugins.contains(&"openspec");
     let has_companions = has_beads || has_openspec;
-    let has_ro5 = installed_skills
-        .iter()
-        .any(|s| *s == "ro5" || *s == "rule-of-5" || *s == "rule-of-5-universal");
+    let has_ro5 = opts.includes(BlockSection::Ro5) && has_ro5_skill(installed_skills);
+    let openspec_checklist = has_openspec && opts.includes(BlockSection::Openspec);
 
     let mut doc = String::new();
-    doc.push_str("# wai Workflow Reference\n\n");
-    doc.push_str(
-        "> This file is managed by `wai init`. Do not edit manually.\n\
-         > Changes will be overwritten on the next init.\n\n",
-    );
 
     // When to Use What (only when companion tools detected)
     if has_companions {
@@ -340,7 +660,7 @@ pub fn wai_detailed_content(
             "[ ] bd close <id>                  # close completed issues; also close parent epic if last sub-task\n",
         );
     }
-    if has_openspec {
+    if openspec_checklist {
         doc.push_str(
             "[ ] openspec tasks.md — mark completed tasks [x]\n\
              [ ] openspec list — archive any ✓ Complete changes (`openspec archive <id> --yes`)\n",
@@ -431,7 +751,7 @@ pub fn wai_detailed_content(
              ```\n",
         );
     }
-    if has_openspec {
+    if openspec_checklist {
         doc.push_str(
             "\n\
              ### openspec\n\
@@ -444,7 +764,7 @@ pub fn wai_detailed_content(
     }
 
     // Available Pipelines
-    if !installed_pipelines.is_empty() {
+    if opts.includes(BlockSection::Pipelines) && !installed_pipelines.is_empty() {
         doc.push_str(
             "\n\
              ## Available Pipelines\n\
@@ -471,7 +791,16 @@ pub fn wai_detailed_content(
         );
     }
 
-    if let Some(note) = ubiquitous_language_note(repo_root) {
+    if opts.includes(BlockSection::Skills)
+        && let Some(section) = skills_section(installed_skills)
+    {
+        doc.push('\n');
+        doc.push_str(&section);
+    }
+
+    if opts.includes(BlockSection::UbiquitousLanguage)
+        && let Some(note) = ubiquitous_language_note(repo_root)
+    {
         doc.push('\n');
         doc.push_str(note);
     }
@@ -509,6 +838,7 @@ pub fn write_detailed_agents_file(
         detected_plugins,
         installed_skills,
         installed_pipelines,
+        &BlockOptions::for_target(repo_root, DETAILED_TARGET),
     );
     let existed = path.exists();
     std::fs::write(&path, &content)?;
@@ -535,6 +865,8 @@ impl DetailedFileResult {
     }
 }
 
+/// Inject or refresh the managed block in `path`, using the
+/// `[managed_block]` settings configured for its file name.
 pub fn inject_managed_block(
     path: &Path,
     detected_plugins: &[&str],
@@ -542,12 +874,137 @@ pub fn inject_managed_block(
     installed_pipelines: &[InstalledPipeline],
 ) -> Result<InjectResult, std::io::Error> {
     let repo_root = path.parent().unwrap_or(Path::new("."));
+    let target = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
     let wai_content = wai_block_inner(
         repo_root,
         detected_plugins,
         installed_skills,
         installed_pipelines,
+        &BlockOptions::for_target(repo_root, &target),
     );
+    inject_markdown_block(path, &wai_content)
+}
+
+/// Files that receive a managed block: `AGENTS.md` and `CLAUDE.md`, then any
+/// other file configured under `[managed_block.targets]`.
+pub fn managed_targets(repo_root: &Path) -> Vec<String> {
+    let mut targets = vec!["AGENTS.md".to_string(), "CLAUDE.md".to_string()];
+    if let Some(mb) = ProjectConfig::load(repo_root)
+        .ok()
+        .and_then(|c| c.managed_block)
+    {
+        targets.extend(mb.extra_targets().map(str::to_string));
+    }
+    targets
+}
+
+/// Content between the markers for `target`: the configured `source` file
+/// verbatim, or the generated instructions in the target's comment syntax.
+fn target_block_inner(
+    repo_root: &Path,
+    opts: &BlockOptions,
+    detected_plugins: &[&str],
+    installed_skills: &[&str],
+    installed_pipelines: &[InstalledPipeline],
+) -> Result<String, std::io::Error> {
+    if let Some(source) = &opts.source {
+        let content = std::fs::read_to_string(repo_root.join(source)).map_err(|e| {
+            std::io::Error::new(
+                e.kind(),
+                format!("cannot read block source {}: {}", source, e),
+            )
+        })?;
+        return Ok(format!("\n{}\n", content.trim_end()));
+    }
+    let inner = wai_block_inner(
+        repo_root,
+        detected_plugins,
+        installed_skills,
+        installed_pipelines,
+        opts,
+    );
+    Ok(comment_lines(&inner, opts.comment))
+}
+
+/// Expected on-disk block for `target` (relative to `repo_root`), markers
+/// included, for comparison with [`read_target_block`].
+pub fn target_block_content(
+    repo_root: &Path,
+    target: &str,
+    detected_plugins: &[&str],
+    installed_skills: &[&str],
+    installed_pipelines: &[InstalledPipeline],
+) -> Result<String, std::io::Error> {
+    let opts = BlockOptions::for_target(repo_root, target);
+    let inner = target_block_inner(
+        repo_root,
+        &opts,
+        detected_plugins,
+        installed_skills,
+        installed_pipelines,
+    )?;
+    Ok(format!(
+        "{}{}{}",
+        start_marker(opts.comment),
+        inner,
+        end_marker(opts.comment)
+    ))
+}
+
+/// Read the managed block (markers included) from `target`, using the
+/// target's comment syntax. Returns `None` if the file or block is missing.
+pub fn read_target_block(repo_root: &Path, target: &str) -> Option<String> {
+    let opts = BlockOptions::for_target(repo_root, target);
+    let mut reg = BlockRegistry::new();
+    reg.register(BlockDef::with_markers(
+        "WAI",
+        start_marker(opts.comment),
+        end_marker(opts.comment),
+    ));
+    BlockInjector::new(reg).read_block(&repo_root.join(target), "WAI")
+}
+
+/// Inject or refresh the managed block in `target` (relative to
+/// `repo_root`), creating parent directories as needed. Markdown targets
+/// with generated content also get the REFLECT:REF block, like
+/// [`inject_managed_block`].
+pub fn inject_target_block(
+    repo_root: &Path,
+    target: &str,
+    detected_plugins: &[&str],
+    installed_skills: &[&str],
+    installed_pipelines: &[InstalledPipeline],
+) -> Result<InjectResult, std::io::Error> {
+    let path = repo_root.join(target);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let opts = BlockOptions::for_target(repo_root, target);
+    let inner = target_block_inner(
+        repo_root,
+        &opts,
+        detected_plugins,
+        installed_skills,
+        installed_pipelines,
+    )?;
+    if opts.comment == CommentStyle::Html && opts.source.is_none() {
+        return inject_markdown_block(&path, &inner);
+    }
+    let mut reg = BlockRegistry::new();
+    reg.register(BlockDef::with_markers(
+        "WAI",
+        start_marker(opts.comment),
+        end_marker(opts.comment),
+    ));
+    BlockInjector::new(reg).inject(&path, "WAI", &inner)
+}
+
+/// Write `wai_content` as the WAI block in a markdown file and keep the
+/// REFLECT:REF block right after it.
+fn inject_markdown_block(path: &Path, wai_content: &str) -> Result<InjectResult, std::io::Error> {
     let ref_inner = format!("\n{}\n", wai_reflect_ref_content());
     // Full REF block with markers, used for direct file append
     let ref_full = format!(
@@ -566,7 +1023,7 @@ pub fn inject_managed_block(
     ));
     let injector = BlockInjector::new(reg);
 
-    let wai_result = injector.inject(path, "WAI", &wai_content)?;
+    let wai_result = injector.inject(path, "WAI", wai_content)?;
 
     // For the REFLECT:REF block, handle ordering:
     // - If the file was just created, append REF block after WAI block
@@ -671,7 +1128,7 @@ mod wai_block_tests {
 
     #[test]
     fn slim_block_contains_wai_sync() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(
             output.contains("wai sync"),
             "expected 'wai sync' in slim block"
@@ -680,7 +1137,7 @@ mod wai_block_tests {
 
     #[test]
     fn slim_block_contains_wai_status() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(
             output.contains("wai status"),
             "expected 'wai status' in slim block"
@@ -689,7 +1146,7 @@ mod wai_block_tests {
 
     #[test]
     fn slim_block_points_to_detailed_instructions() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(
             output.contains(".wai/AGENTS.md"),
             "expected pointer to .wai/AGENTS.md in slim block"
@@ -698,7 +1155,7 @@ mod wai_block_tests {
 
     #[test]
     fn slim_block_does_not_contain_quick_reference() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(
             !output.contains("## Quick Reference"),
             "slim block should not contain full Quick Reference"
@@ -707,7 +1164,7 @@ mod wai_block_tests {
 
     #[test]
     fn slim_block_does_not_contain_capturing_work() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(
             !output.contains("## Capturing Work"),
             "slim block should not contain Capturing Work section"
@@ -716,7 +1173,7 @@ mod wai_block_tests {
 
     #[test]
     fn slim_block_does_not_contain_ending_session() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(
             !output.contains("## Ending a Session"),
             "slim block should not contain Ending a Session section"
@@ -725,7 +1182,13 @@ mod wai_block_tests {
 
     #[test]
     fn tdd_disclaimer_present_with_companion_tools() {
-        let output = wai_block_content(Path::new("."), &["beads", "openspec"], &[], &[]);
+        let output = wai_block_content(
+            Path::new("."),
+            &["beads", "openspec"],
+            &[],
+            &[],
+            &BlockOptions::default(),
+        );
         assert!(
             output.contains("CRITICAL"),
             "expected CRITICAL disclaimer in slim block with companion tools"
@@ -739,7 +1202,13 @@ mod wai_block_tests {
 
     #[test]
     fn tdd_disclaimer_present_with_beads_only() {
-        let output = wai_block_content(Path::new("."), &["beads"], &[], &[]);
+        let output = wai_block_content(
+            Path::new("."),
+            &["beads"],
+            &[],
+            &[],
+            &BlockOptions::default(),
+        );
         assert!(
             output.contains("CRITICAL"),
             "expected CRITICAL disclaimer with beads"
@@ -748,7 +1217,7 @@ mod wai_block_tests {
 
     #[test]
     fn tdd_disclaimer_absent_without_companion_tools() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(
             !output.contains("Tidy First"),
             "unexpected 'Tidy First' without companion tools"
@@ -758,7 +1227,8 @@ mod wai_block_tests {
     #[test]
     fn ro5_reminder_present_when_skill_installed() {
         for name in &["ro5", "rule-of-5", "rule-of-5-universal"] {
-            let output = wai_block_content(Path::new("."), &[], &[name], &[]);
+            let output =
+                wai_block_content(Path::new("."), &[], &[name], &[], &BlockOptions::default());
             assert!(
                 output.contains("/ro5"),
                 "expected '/ro5' when skill '{name}' installed"
@@ -768,7 +1238,13 @@ mod wai_block_tests {
 
     #[test]
     fn ro5_reminder_absent_without_skill() {
-        let output = wai_block_content(Path::new("."), &["beads", "openspec"], &[], &[]);
+        let output = wai_block_content(
+            Path::new("."),
+            &["beads", "openspec"],
+            &[],
+            &[],
+            &BlockOptions::default(),
+        );
         assert!(!output.contains("/ro5"), "unexpected '/ro5' without skill");
     }
 
@@ -781,7 +1257,8 @@ mod wai_block_tests {
             &["openspec"][..],
             &["beads", "openspec"][..],
         ] {
-            let output = wai_block_content(Path::new("."), plugins, &[], &[]);
+            let output =
+                wai_block_content(Path::new("."), plugins, &[], &[], &BlockOptions::default());
             assert!(
                 output.contains(SEARCH_INSTRUCTION),
                 "expected search instruction with plugins {:?}",
@@ -792,7 +1269,7 @@ mod wai_block_tests {
 
     #[test]
     fn search_before_research_absent_without_companions() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(
             !output.contains(SEARCH_INSTRUCTION),
             "unexpected search instruction without companion tools"
@@ -801,7 +1278,13 @@ mod wai_block_tests {
 
     #[test]
     fn search_before_research_after_tdd_disclaimer() {
-        let output = wai_block_content(Path::new("."), &["beads"], &[], &[]);
+        let output = wai_block_content(
+            Path::new("."),
+            &["beads"],
+            &[],
+            &[],
+            &BlockOptions::default(),
+        );
         let tdd_pos = output.find("CRITICAL").expect("CRITICAL not found");
         let search_pos = output
             .find(SEARCH_INSTRUCTION)
@@ -811,7 +1294,7 @@ mod wai_block_tests {
 
     #[test]
     fn context_pressure_tells_user() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(output.contains("stop and tell the user"));
         assert!(output.contains("responses degrade"));
     }
@@ -823,7 +1306,7 @@ mod wai_block_tests {
         std::fs::create_dir_all(&note_root).unwrap();
         std::fs::write(note_root.join("README.md"), "# Index\n").unwrap();
 
-        let output = wai_block_content(dir.path(), &[], &[], &[]);
+        let output = wai_block_content(dir.path(), &[], &[], &[], &BlockOptions::default());
         assert!(output.contains("## Ubiquitous Language"));
         assert!(output.contains("read it first as the"));
         assert!(output.contains("Avoid loading every terminology file"));
@@ -832,7 +1315,7 @@ mod wai_block_tests {
     #[test]
     fn slim_block_omits_ubiquitous_language_note_without_index() {
         let dir = tempfile::tempdir().expect("tempdir");
-        let output = wai_block_content(dir.path(), &[], &[], &[]);
+        let output = wai_block_content(dir.path(), &[], &[], &[], &BlockOptions::default());
         assert!(!output.contains("## Ubiquitous Language"));
     }
 
@@ -845,7 +1328,7 @@ mod wai_block_tests {
         std::fs::create_dir_all(&contexts).unwrap();
         std::fs::write(contexts.join("billing.md"), "# Billing\n").unwrap();
 
-        let output = wai_block_content(dir.path(), &[], &[], &[]);
+        let output = wai_block_content(dir.path(), &[], &[], &[], &BlockOptions::default());
         assert!(!output.contains("## Ubiquitous Language"));
     }
 
@@ -856,7 +1339,7 @@ mod wai_block_tests {
         std::fs::create_dir_all(&note_root).unwrap();
         std::fs::write(note_root.join("README.md"), "# Index\n").unwrap();
 
-        let output = wai_detailed_content(dir.path(), &[], &[], &[]);
+        let output = wai_detailed_content(dir.path(), &[], &[], &[], &BlockOptions::default());
         assert!(output.contains("## Ubiquitous Language"));
         assert!(output.contains("read it first as the"));
         assert!(output.contains("Avoid loading every terminology file"));
@@ -865,7 +1348,7 @@ mod wai_block_tests {
     #[test]
     fn detailed_block_omits_ubiquitous_language_note_without_index() {
         let dir = tempfile::tempdir().expect("tempdir");
-        let output = wai_detailed_content(dir.path(), &[], &[], &[]);
+        let output = wai_detailed_content(dir.path(), &[], &[], &[], &BlockOptions::default());
         assert!(!output.contains("## Ubiquitous Language"));
     }
 
@@ -879,7 +1362,13 @@ mod wai_block_tests {
             when: "Frontier-level research requiring systematic validation".to_string(),
             step_count: 8,
         }];
-        let output = wai_block_content(Path::new("."), &[], &[], &pipelines);
+        let output = wai_block_content(
+            Path::new("."),
+            &[],
+            &[],
+            &pipelines,
+            &BlockOptions::default(),
+        );
         assert!(output.contains("## Available Pipelines"));
         assert!(output.contains("scientific-research"));
         assert!(output.contains("Frontier-level research"));
@@ -888,7 +1377,7 @@ mod wai_block_tests {
 
     #[test]
     fn pipeline_section_absent_when_no_pipelines() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(!output.contains("Available Pipelines"));
     }
 
@@ -900,7 +1389,13 @@ mod wai_block_tests {
             when: "Testing".to_string(),
             step_count: 2,
         }];
-        let output = wai_block_content(Path::new("."), &[], &[], &pipelines);
+        let output = wai_block_content(
+            Path::new("."),
+            &[],
+            &[],
+            &pipelines,
+            &BlockOptions::default(),
+        );
         assert!(output.contains("gates"));
     }
 
@@ -920,7 +1415,13 @@ mod wai_block_tests {
                 step_count: 5,
             },
         ];
-        let output = wai_block_content(Path::new("."), &[], &[], &pipelines);
+        let output = wai_block_content(
+            Path::new("."),
+            &[],
+            &[],
+            &pipelines,
+            &BlockOptions::default(),
+        );
         assert!(output.contains("alpha"));
         assert!(output.contains("beta"));
     }
@@ -929,7 +1430,7 @@ mod wai_block_tests {
 
     #[test]
     fn slim_block_contains_autonomous_work_policy_section() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(
             output.contains("## Autonomous Work Policy"),
             "expected Autonomous Work Policy section in slim block"
@@ -938,7 +1439,7 @@ mod wai_block_tests {
 
     #[test]
     fn slim_block_policy_says_no_routine_confirmations() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(
             output.contains("routine confirmation"),
             "policy should mention not asking routine confirmations"
@@ -947,7 +1448,7 @@ mod wai_block_tests {
 
     #[test]
     fn slim_block_policy_lists_stop_conditions() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         for condition in [
             "Conflicting requirements",
             "Destructive action",
@@ -965,7 +1466,7 @@ mod wai_block_tests {
 
     #[test]
     fn slim_block_policy_before_detailed_instructions() {
-        let output = wai_block_content(Path::new("."), &[], &[], &[]);
+        let output = wai_block_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         let policy_pos = output
             .find("Autonomous Work Policy")
             .expect("Autonomous Work Policy not found");
@@ -982,13 +1483,13 @@ mod wai_block_tests {
 
     #[test]
     fn detailed_contains_session_start() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(output.contains("## Starting a Session"));
     }
 
     #[test]
     fn detailed_wai_sync_before_status() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         let sync_pos = output.find("wai sync").expect("wai sync not found");
         let status_pos = output.find("wai status").expect("wai status not found");
         assert!(sync_pos < status_pos);
@@ -996,73 +1497,109 @@ mod wai_block_tests {
 
     #[test]
     fn detailed_contains_capturing_work() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(output.contains("## Capturing Work"));
     }
 
     #[test]
     fn detailed_contains_ending_session() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(output.contains("## Ending a Session"));
     }
 
     #[test]
     fn detailed_contains_quick_reference() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(output.contains("## Quick Reference"));
     }
 
     #[test]
     fn detailed_contains_structure() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(output.contains("## Structure"));
     }
 
     #[test]
     fn detailed_openspec_checklist_present_when_detected() {
-        let output = wai_detailed_content(Path::new("."), &["openspec"], &[], &[]);
+        let output = wai_detailed_content(
+            Path::new("."),
+            &["openspec"],
+            &[],
+            &[],
+            &BlockOptions::default(),
+        );
         assert!(output.contains("openspec tasks.md"));
     }
 
     #[test]
     fn detailed_openspec_checklist_absent_without_openspec() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(!output.contains("openspec tasks.md"));
     }
 
     #[test]
     fn detailed_openspec_archive_present_when_detected() {
-        let output = wai_detailed_content(Path::new("."), &["openspec"], &[], &[]);
+        let output = wai_detailed_content(
+            Path::new("."),
+            &["openspec"],
+            &[],
+            &[],
+            &BlockOptions::default(),
+        );
         assert!(output.contains("openspec archive"));
     }
 
     #[test]
     fn detailed_tracking_section_present_when_both() {
-        let output = wai_detailed_content(Path::new("."), &["beads", "openspec"], &[], &[]);
+        let output = wai_detailed_content(
+            Path::new("."),
+            &["beads", "openspec"],
+            &[],
+            &[],
+            &BlockOptions::default(),
+        );
         assert!(output.contains("Tracking Work Across Tools"));
     }
 
     #[test]
     fn detailed_tracking_section_absent_with_only_beads() {
-        let output = wai_detailed_content(Path::new("."), &["beads"], &[], &[]);
+        let output = wai_detailed_content(
+            Path::new("."),
+            &["beads"],
+            &[],
+            &[],
+            &BlockOptions::default(),
+        );
         assert!(!output.contains("Tracking Work Across Tools"));
     }
 
     #[test]
     fn detailed_pre_claim_note_present_with_beads() {
-        let output = wai_detailed_content(Path::new("."), &["beads"], &[], &[]);
+        let output = wai_detailed_content(
+            Path::new("."),
+            &["beads"],
+            &[],
+            &[],
+            &BlockOptions::default(),
+        );
         assert!(output.contains("already implemented"));
     }
 
     #[test]
     fn detailed_pre_claim_note_absent_without_beads() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(!output.contains("already implemented"));
     }
 
     #[test]
     fn detailed_bd_close_mentions_epic_with_beads() {
-        let output = wai_detailed_content(Path::new("."), &["beads"], &[], &[]);
+        let output = wai_detailed_content(
+            Path::new("."),
+            &["beads"],
+            &[],
+            &[],
+            &BlockOptions::default(),
+        );
         let bd_close_line = output
             .lines()
             .find(|l| l.contains("bd close <id>"))
@@ -1075,19 +1612,25 @@ mod wai_block_tests {
 
     #[test]
     fn detailed_beads_note_present_with_beads() {
-        let output = wai_detailed_content(Path::new("."), &["beads"], &[], &[]);
+        let output = wai_detailed_content(
+            Path::new("."),
+            &["beads"],
+            &[],
+            &[],
+            &BlockOptions::default(),
+        );
         assert!(output.contains("Do not assume a hard-coded sync"));
     }
 
     #[test]
     fn detailed_beads_note_absent_without_beads() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(!output.contains("Do not assume a hard-coded sync"));
     }
 
     #[test]
     fn detailed_contains_autonomous_loop() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(output.contains("Autonomous Loop"));
     }
 
@@ -1095,7 +1638,7 @@ mod wai_block_tests {
 
     #[test]
     fn detailed_contains_quality_gate_section() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(
             output.contains("## Quality Gate"),
             "expected Quality Gate section in detailed content"
@@ -1104,7 +1647,7 @@ mod wai_block_tests {
 
     #[test]
     fn detailed_quality_gate_requires_ledger_fields() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         for field in ["Changed", "Verified", "Review", "Risks", "Next"] {
             assert!(
                 output.contains(field),
@@ -1115,7 +1658,7 @@ mod wai_block_tests {
 
     #[test]
     fn detailed_quality_gate_after_ending_session() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         let ending_pos = output
             .find("Ending a Session")
             .expect("Ending a Session not found");
@@ -1128,12 +1671,286 @@ mod wai_block_tests {
 
     #[test]
     fn detailed_does_not_have_wai_markers() {
-        let output = wai_detailed_content(Path::new("."), &[], &[], &[]);
+        let output = wai_detailed_content(Path::new("."), &[], &[], &[], &BlockOptions::default());
         assert!(!output.contains(WAI_START));
         assert!(!output.contains(WAI_END));
     }
 }
 
+#[cfg(test)]
+mod block_options_tests {
+    use super::*;
+    use crate::config::ManagedBlockConfig;
+    use tempfile::TempDir;
+
+    fn pipeline() -> InstalledPipeline {
+        InstalledPipeline {
+            name: "feature".to_string(),
+            description: "Feature workflow".to_string(),
+            when: "New features".to_string(),
+            step_count: 3,
+        }
+    }
+
+    fn opts(toml_src: &str) -> BlockOptions {
+        let settings: ManagedBlockSettings = toml::from_str(toml_src).unwrap();
+        BlockOptions::from_settings(&settings)
+    }
+
+    #[test]
+    fn default_options_select_default_sections() {
+        let o = BlockOptions::default();
+        assert_eq!(o.detail, BlockDetail::Standard);
+        assert!(o.includes(BlockSection::Pipelines));
+        assert!(!o.includes(BlockSection::Skills));
+        assert_eq!(o.pointer, ".wai/AGENTS.md");
+        assert_eq!(o.max_chars, None);
+    }
+
+    #[test]
+    fn exclude_drops_pipelines_and_ro5() {
+        let o = opts("exclude = [\"pipelines\", \"ro5\"]");
+        let output = wai_block_inner(Path::new("."), &[], &["ro5"], &[pipeline()], &o);
+        assert!(!output.contains("## Available Pipelines"));
+        assert!(!output.contains("**Ro5**"));
+        assert!(output.contains("## Autonomous Work Policy"));
+    }
+
+    #[test]
+    fn skills_section_is_opt_in_and_sorted() {
+        let skills = ["zeta", "alpha"];
+        let default = wai_block_inner(Path::new("."), &[], &skills, &[], &BlockOptions::default());
+        assert!(!default.contains("## Installed Skills"));
+
+        let o = opts("sections = [\"skills\"]");
+        let output = wai_block_inner(Path::new("."), &[], &skills, &[], &o);
+        let alpha = output.find("`alpha`").expect("alpha listed");
+        let zeta = output.find("`zeta`").expect("zeta listed");
+        assert!(alpha < zeta);
+    }
+
+    #[test]
+    fn openspec_section_controls_detailed_checklist() {
+        let o = opts("exclude = [\"openspec\"]");
+        let output = wai_detailed_content(Path::new("."), &["openspec"], &[], &[], &o);
+        assert!(!output.contains("openspec tasks.md"));
+        assert!(!output.contains("### openspec"));
+        // Detection itself is still reported.
+        assert!(output.contains("| Propose system changes | openspec |"));
+    }
+
+    #[test]
+    fn pointer_detail_is_terse_and_uses_configured_pointer() {
+        let o = opts("detail = \"pointer\"\npointer = \"AGENTS.md\"");
+        let output = wai_block_inner(Path::new("."), &["beads"], &[], &[pipeline()], &o);
+        assert!(output.contains("**`AGENTS.md`**"));
+        assert!(!output.contains("## Quick Start"));
+        assert!(!output.contains("## Available Pipelines"));
+        assert!(output.contains("Keep this managed block"));
+    }
+
+    #[test]
+    fn full_detail_inlines_reference() {
+        let o = opts("detail = \"full\"");
+        let output = wai_block_inner(Path::new("."), &[], &[], &[pipeline()], &o);
+        assert!(output.contains("## Quick Reference"));
+        assert!(output.contains("## Ending a Session"));
+        assert!(output.contains("## Available Pipelines"));
+        assert!(output.contains("## Autonomous Work Policy"));
+        assert!(!output.contains("# wai Workflow Reference"));
+    }
+
+    #[test]
+    fn max_tokens_converts_to_chars_and_takes_tighter_cap() {
+        assert_eq!(opts("max_tokens = 100").max_chars, Some(400));
+        assert_eq!(
+            opts("max_tokens = 100\nmax_chars = 300").max_chars,
+            Some(300)
+        );
+    }
+
+    #[test]
+    fn budget_drops_optional_sections_before_detail() {
+        // No ubiquitous language index here, so Ro5 is the first section dropped.
+        let dir = TempDir::new().unwrap();
+        let full = wai_block_inner(
+            dir.path(),
+            &[],
+            &["ro5"],
+            &[pipeline()],
+            &BlockOptions::default(),
+        );
+        let without_ro5 = wai_block_inner(
+            dir.path(),
+            &[],
+            &["ro5"],
+            &[pipeline()],
+            &opts("exclude = [\"ro5\"]"),
+        );
+        let o = BlockOptions {
+            max_chars: Some(without_ro5.chars().count()),
+            ..BlockOptions::default()
+        };
+        assert!(full.chars().count() > without_ro5.chars().count());
+
+        let output = wai_block_inner(dir.path(), &[], &["ro5"], &[pipeline()], &o);
+        assert_eq!(output, without_ro5);
+    }
+
+    #[test]
+    fn budget_falls_back_to_pointer_when_sections_are_not_enough() {
+        let o = BlockOptions {
+            max_chars: Some(500),
+            ..BlockOptions::default()
+        };
+        let output = wai_block_inner(Path::new("."), &["beads"], &[], &[pipeline()], &o);
+        assert_eq!(output, pointer_block(".wai/AGENTS.md"));
+        assert!(output.chars().count() <= 500);
+    }
+
+    #[test]
+    fn target_overrides_apply_per_file() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join(".wai")).unwrap();
+        std::fs::write(
+            dir.path().join(".wai/config.toml"),
+            "[project]\nname = \"demo\"\n\n\
+             [managed_block]\nexclude = [\"ro5\"]\n\n\
+             [managed_block.targets.\"CLAUDE.md\"]\ndetail = \"pointer\"\npointer = \"AGENTS.md\"\n\n\
+             [managed_block.targets.\"AGENTS.md\"]\ndetail = \"full\"\n",
+        )
+        .unwrap();
+
+        let claude = dir.path().join("CLAUDE.md");
+        let agents = dir.path().join("AGENTS.md");
+        inject_managed_block(&claude, &[], &["ro5"], &[]).unwrap();
+        inject_managed_block(&agents, &[], &["ro5"], &[]).unwrap();
+
+        let claude = std::fs::read_to_string(claude).unwrap();
+        let agents = std::fs::read_to_string(agents).unwrap();
+        assert!(claude.contains("read **`AGENTS.md`**"));
+        assert!(!claude.contains("## Quick Start"));
+        assert!(agents.contains("## Quick Reference"));
+        assert!(
+            !agents.contains("**Ro5**"),
+            "top-level exclude still applies"
+        );
+
+        let config = ProjectConfig::load(dir.path()).unwrap();
+        let mb: ManagedBlockConfig = config.managed_block.unwrap();
+        assert_eq!(mb.for_target("AGENTS.md").detail, Some(BlockDetail::Full));
+        assert_eq!(mb.for_target("README.md").detail, None);
+    }
+}
+
+#[cfg(test)]
+mod target_tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn workspace(targets: &str) -> TempDir {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join(".wai")).unwrap();
+        std::fs::write(
+            dir.path().join(".wai/config.toml"),
+            format!("[project]\nname = \"demo\"\n\n{}", targets),
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn comment_style_detected_from_file_name() {
+        assert_eq!(comment_style_for("GEMINI.md"), CommentStyle::Html);
+        assert_eq!(comment_style_for(".cursorrules"), CommentStyle::Html);
+        assert_eq!(
+            comment_style_for(".github/workflows/ci.yml"),
+            CommentStyle::Hash
+        );
+        assert_eq!(comment_style_for("justfile"), CommentStyle::Hash);
+        assert_eq!(comment_style_for(".editorconfig"), CommentStyle::Hash);
+        assert_eq!(comment_style_for("src/lib.rs"), CommentStyle::Slash);
+    }
+
+    #[test]
+    fn managed_targets_lists_configured_files_after_root_files() {
+        let dir = workspace(
+            "[managed_block.targets.\"justfile\"]\n\
+             [managed_block.targets.\"CLAUDE.md\"]\ndetail = \"pointer\"\n\
+             [managed_block.targets.\".wai/AGENTS.md\"]\n",
+        );
+        assert_eq!(
+            managed_targets(dir.path()),
+            vec!["AGENTS.md", "CLAUDE.md", "justfile"]
+        );
+    }
+
+    #[test]
+    fn yaml_target_gets_hash_comment_pointer_block() {
+        let dir = workspace("[managed_block.targets.\".github/workflows/ci.yml\"]\n");
+        let target = ".github/workflows/ci.yml";
+        let path = dir.path().join(target);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "name: ci\non: push\n").unwrap();
+
+        inject_target_block(dir.path(), target, &[], &[], &[]).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# WAI:START\n# # Workflow Tools\n#\n"));
+        assert!(content.contains("# WAI:END\n\nname: ci\non: push\n"));
+        assert!(!content.contains("<!--"), "no HTML markers in YAML");
+        assert!(
+            !content.contains("Quick Start"),
+            "non-prose targets default to pointer"
+        );
+
+        let expected = target_block_content(dir.path(), target, &[], &[], &[]).unwrap();
+        assert_eq!(read_target_block(dir.path(), target), Some(expected));
+
+        // A second run updates in place.
+        inject_target_block(dir.path(), target, &[], &[], &[]).unwrap();
+        let again = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(again, content);
+    }
+
+    #[test]
+    fn source_is_injected_verbatim() {
+        let dir = workspace(
+            "[managed_block.targets.\"justfile\"]\nsource = \".wai/resources/templates/wai.just\"\n",
+        );
+        std::fs::create_dir_all(dir.path().join(".wai/resources/templates")).unwrap();
+        std::fs::write(
+            dir.path().join(".wai/resources/templates/wai.just"),
+            "prime:\n    wai prime\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("justfile"), "build:\n    cargo build\n").unwrap();
+
+        inject_target_block(dir.path(), "justfile", &[], &[], &[]).unwrap();
+        let content = std::fs::read_to_string(dir.path().join("justfile")).unwrap();
+        assert_eq!(
+            content,
+            "# WAI:START\nprime:\n    wai prime\n# WAI:END\n\nbuild:\n    cargo build\n"
+        );
+    }
+
+    #[test]
+    fn missing_source_is_an_error() {
+        let dir = workspace("[managed_block.targets.\"justfile\"]\nsource = \"nope.just\"\n");
+        let err = target_block_content(dir.path(), "justfile", &[], &[], &[]).unwrap_err();
+        assert!(err.to_string().contains("nope.just"));
+    }
+
+    #[test]
+    fn markdown_target_keeps_html_markers_and_reflect_ref() {
+        let dir = workspace("[managed_block.targets.\"GEMINI.md\"]\n");
+        inject_target_block(dir.path(), "GEMINI.md", &[], &[], &[]).unwrap();
+        let content = std::fs::read_to_string(dir.path().join("GEMINI.md")).unwrap();
+        assert!(content.starts_with("<!-- WAI:START --># Workflow Tools"));
+        assert!(content.contains("## Quick Start"));
+        assert!(content.contains(REFLECT_REF_START));
+    }
+}
+
 #[cfg(test)]
 mod reflect_ref_tests {
     use super::*;
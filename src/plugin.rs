@@ -27,11 +27,22 @@ pub struct TrustEntry {
     pub approved_at: String,
 }
 
-/// The plugin trust store — a list of approved hook digests stored in
-/// user-owned XDG state outside the repository.
+/// A public key trusted to sign skill archives (`wai resource export --sign`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustedKey {
+    pub label: String,
+    /// Hex-encoded ed25519 public key.
+    pub public_key: String,
+    pub added_at: String,
+}
+
+/// The plugin trust store — a list of approved hook digests and trusted
+/// archive signing keys stored in user-owned XDG state outside the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TrustStore {
     entries: Vec<TrustEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    keys: Vec<TrustedKey>,
 }
 
 impl TrustStore {
@@ -82,24 +93,54 @@ impl TrustStore {
     pub fn list(&self) -> &[TrustEntry] {
         &self.entries
     }
+
+    /// Look up a trusted archive signing key by its hex-encoded public key.
+    pub fn trusted_key(&self, public_key: &str) -> Option<&TrustedKey> {
+        self.keys
+            .iter()
+            .find(|k| k.public_key.eq_ignore_ascii_case(public_key))
+    }
+
+    /// Trust an archive signing key. Replaces any existing entry for the same key.
+    pub fn trust_key(&mut self, key: TrustedKey) {
+        self.keys
+            .retain(|k| !k.public_key.eq_ignore_ascii_case(&key.public_key));
+        self.keys.push(key);
+    }
+
+    /// Stop trusting an archive signing key.
+    pub fn revoke_key(&mut self, public_key: &str) -> bool {
+        let len = self.keys.len();
+        self.keys
+            .retain(|k| !k.public_key.eq_ignore_ascii_case(public_key));
+        self.keys.len() < len
+    }
+
+    /// List all trusted archive signing keys.
+    pub fn keys(&self) -> &[TrustedKey] {
+        &self.keys
+    }
 }
 
-/// Get the path to the trust store file.
+/// Get the wai data directory for user-owned state (trust store, signing key).
 /// Uses `WAI_DATA_DIR` env var override, then `XDG_DATA_HOME`, then `~/.local/share/wai/`.
-pub fn trust_store_path() -> PathBuf {
+pub fn wai_data_dir() -> PathBuf {
     if let Ok(dir) = std::env::var("WAI_DATA_DIR") {
-        return PathBuf::from(dir).join(TRUST_STORE_FILE);
+        return PathBuf::from(dir);
     }
 
-    let data_dir = if let Ok(xdg_data) = std::env::var("XDG_DATA_HOME") {
+    if let Ok(xdg_data) = std::env::var("XDG_DATA_HOME") {
         PathBuf::from(xdg_data).join("wai")
     } else if let Some(home) = dirs::home_dir() {
         home.join(".local").join("share").join("wai")
     } else {
         PathBuf::from(".wai-data")
-    };
+    }
+}
 
-    data_dir.join(TRUST_STORE_FILE)
+/// Get the path to the trust store file inside [`wai_data_dir`].
+pub fn trust_store_path() -> PathBuf {
+    wai_data_dir().join(TRUST_STORE_FILE)
 }
 
 /// Compute a SHA-256 digest for a plugin hook.
@@ -117,6 +158,11 @@ pub fn compute_hook_digest(plugin: &PluginDef, hook_name: &str, hook: &HookDef)
     hasher.update(hook_name.as_bytes());
     hasher.update(hook.command.as_bytes());
     hasher.update(hook.inject_as.as_bytes());
+    // Only JSON hooks contribute the protocol, so approvals of existing
+    // plain-text hooks keep their digest.
+    if hook.protocol == HookProtocol::Json {
+        hasher.update(b"protocol=json");
+    }
     format!("{:x}", hasher.finalize())
 }
 
@@ -130,8 +176,13 @@ pub enum PluginSource {
 /// Data returned by a plugin hook execution.
 #[derive(Debug, Default)]
 pub struct HookOutput {
+    /// Name of the plugin that produced this output.
+    pub plugin: String,
     pub label: String,
+    /// Free-form text (plain stdout, or the `text` field of a JSON response).
     pub content: String,
+    /// Structured response from a hook using the JSON protocol.
+    pub data: Option<HookResponse>,
 }
 
 /// A plugin command that passes through to an external CLI.
@@ -149,6 +200,103 @@ pub struct PluginCommand {
 pub struct HookDef {
     pub command: String,
     pub inject_as: String,
+    /// Wire protocol: `text` (default) captures stdout verbatim; `json` sends a
+    /// [`HookContext`] on stdin and parses stdout as a [`HookResponse`].
+    #[serde(default)]
+    pub protocol: HookProtocol,
+}
+
+/// How wai talks to a hook command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HookProtocol {
+    #[default]
+    Text,
+    Json,
+}
+
+/// Version of the JSON hook protocol sent in [`HookContext::protocol_version`].
+pub const HOOK_PROTOCOL_VERSION: u32 = 1;
+
+/// Event context written to the stdin of JSON-protocol hooks.
+#[derive(Debug, Clone, Serialize)]
+pub struct HookContext {
+    pub protocol_version: u32,
+    pub event: String,
+    pub project_root: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    /// Artifact paths of the project, relative to the project root.
+    pub artifacts: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pipeline_run: Option<HookPipelineRun>,
+}
+
+/// The active pipeline run, as seen by a JSON-protocol hook.
+#[derive(Debug, Clone, Serialize)]
+pub struct HookPipelineRun {
+    pub run_id: String,
+    pub pipeline: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    /// Zero-based index of the current step.
+    pub current_step: usize,
+}
+
+/// Structured data returned on stdout by a JSON-protocol hook.
+///
+/// Every field is optional so hooks only emit what they contribute.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HookResponse {
+    /// Free-form text, shown the same way as a plain-text hook's stdout.
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Key/value lines merged into `wai status` and `wai prime`.
+    #[serde(default)]
+    pub status: Vec<HookStatusEntry>,
+    /// Suggested next commands merged into status/prime suggestions.
+    #[serde(default)]
+    pub suggestions: Vec<HookSuggestion>,
+    /// Health checks merged into `wai doctor`.
+    #[serde(default)]
+    pub doctor_checks: Vec<HookDoctorCheck>,
+    /// Short facts merged into the memories section of `wai prime`.
+    #[serde(default)]
+    pub memories: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HookStatusEntry {
+    pub label: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HookSuggestion {
+    pub label: String,
+    pub command: String,
+}
+
+/// Outcome of a plugin-contributed doctor check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HookCheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HookDoctorCheck {
+    pub name: String,
+    pub status: HookCheckStatus,
+    #[serde(default)]
+    pub message: String,
+    /// Human-readable remediation hint.
+    #[serde(default)]
+    pub fix: Option<String>,
 }
 
 /// Plugin configuration loaded from TOML.
@@ -167,13 +315,263 @@ pub struct PluginDef {
     pub commands: Vec<PluginCommand>,
     #[serde(default)]
     pub hooks: HashMap<String, HookDef>,
+    /// Custom checks reported by `wai doctor` or `wai way`.
+    #[serde(default)]
+    pub checks: Vec<PluginCheckDef>,
+}
+
+/// Which report a plugin check contributes to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PluginCheckKind {
+    #[default]
+    Doctor,
+    Way,
 }
 
+/// A check declared by a custom plugin under `[[checks]]`.
+///
+/// The check passes when `command` exits with `expect_exit` and, if
+/// `expect_json` is set, its stdout is JSON matching that schema.
 #[derive(Debug, Clone, Deserialize)]
+pub struct PluginCheckDef {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub kind: PluginCheckKind,
+    pub command: String,
+    #[serde(default)]
+    pub expect_exit: i32,
+    /// JSON Schema subset (`type`, `required`, `properties`, `items`, `enum`,
+    /// `const`, `minimum`, `maximum`, `minItems`, `maxItems`).
+    #[serde(default)]
+    pub expect_json: Option<toml::Value>,
+    /// Status reported when the check does not pass.
+    #[serde(default = "default_check_severity")]
+    pub severity: HookCheckStatus,
+    /// Message shown when the check does not pass.
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Command run by `wai doctor --fix` to repair a failing check.
+    #[serde(default)]
+    pub fix: Option<String>,
+}
+
+fn default_check_severity() -> HookCheckStatus {
+    HookCheckStatus::Warn
+}
+
+/// Rule deciding whether a plugin is active.
+///
+/// Leaf rules set `type`:
+/// - `directory` / `file` — `path` exists relative to the workspace root
+/// - `glob` — at least one file matches the glob in `path` (`*`, `?`, `**`)
+/// - `binary` / `command` — the executable named by `path` (first word for
+///   `command`) is on `PATH`
+/// - `env` — the variable named by `path` is set (and matches `pattern`)
+/// - `content` — the file at `path` contains a match for the regex `pattern`
+///
+/// Composite rules leave `type` empty and list nested rules under `all` or
+/// `any`.
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct DetectorDef {
-    #[serde(rename = "type")]
+    #[serde(rename = "type", default)]
     pub detector_type: String,
+    #[serde(default)]
     pub path: String,
+    #[serde(default)]
+    pub pattern: Option<String>,
+    #[serde(default)]
+    pub all: Vec<DetectorDef>,
+    #[serde(default)]
+    pub any: Vec<DetectorDef>,
+}
+
+/// Outcome of evaluating a [`DetectorDef`], with a human-readable reason.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectorMatch {
+    pub matched: bool,
+    pub reason: String,
+}
+
+impl DetectorMatch {
+    fn new(matched: bool, reason: impl Into<String>) -> Self {
+        Self {
+            matched,
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Directories never descended into when matching `glob` detectors.
+const GLOB_SKIP_DIRS: &[&str] = &[".git", "node_modules", "target"];
+
+impl DetectorDef {
+    /// Evaluate this rule against the workspace at `project_root`.
+    pub fn evaluate(&self, project_root: &Path) -> DetectorMatch {
+        if !self.all.is_empty() {
+            let results: Vec<DetectorMatch> =
+                self.all.iter().map(|d| d.evaluate(project_root)).collect();
+            return match results.iter().find(|r| !r.matched) {
+                Some(failed) => DetectorMatch::new(false, failed.reason.clone()),
+                None => DetectorMatch::new(true, join_reasons(&results, " and ")),
+            };
+        }
+        if !self.any.is_empty() {
+            let results: Vec<DetectorMatch> =
+                self.any.iter().map(|d| d.evaluate(project_root)).collect();
+            return match results.iter().find(|r| r.matched) {
+                Some(hit) => DetectorMatch::new(true, hit.reason.clone()),
+                None => DetectorMatch::new(false, join_reasons(&results, " and ")),
+            };
+        }
+
+        let path = self.path.as_str();
+        match self.detector_type.as_str() {
+            "directory" | "file" => {
+                let kind = self.detector_type.as_str();
+                if project_root.join(path).exists() {
+                    DetectorMatch::new(true, format!("{} `{}` exists", kind, path))
+                } else {
+                    DetectorMatch::new(false, format!("{} `{}` not found", kind, path))
+                }
+            }
+            "glob" => match glob_match_first(project_root, path) {
+                Some(found) => DetectorMatch::new(true, format!("`{}` matches `{}`", found, path)),
+                None => DetectorMatch::new(false, format!("no files match `{}`", path)),
+            },
+            "binary" | "command" => {
+                let binary = path.split_whitespace().next().unwrap_or_default();
+                if find_on_path(binary).is_some() {
+                    DetectorMatch::new(true, format!("`{}` found on PATH", binary))
+                } else {
+                    DetectorMatch::new(false, format!("`{}` not found on PATH", binary))
+                }
+            }
+            "env" => match (std::env::var(path), &self.pattern) {
+                (Err(_), _) => DetectorMatch::new(false, format!("${} is not set", path)),
+                (Ok(_), None) => DetectorMatch::new(true, format!("${} is set", path)),
+                (Ok(value), Some(pattern)) => match regex::Regex::new(pattern) {
+                    Ok(re) if re.is_match(&value) => {
+                        DetectorMatch::new(true, format!("${} matches `{}`", path, pattern))
+                    }
+                    Ok(_) => {
+                        DetectorMatch::new(false, format!("${} does not match `{}`", path, pattern))
+                    }
+                    Err(e) => invalid_pattern(pattern, &e),
+                },
+            },
+            "content" => {
+                let Some(pattern) = &self.pattern else {
+                    return DetectorMatch::new(
+                        false,
+                        format!("content detector for `{}` has no pattern", path),
+                    );
+                };
+                let re = match regex::Regex::new(pattern) {
+                    Ok(re) => re,
+                    Err(e) => return invalid_pattern(pattern, &e),
+                };
+                match std::fs::read_to_string(project_root.join(path)) {
+                    Ok(content) if re.is_match(&content) => {
+                        DetectorMatch::new(true, format!("`{}` matches `{}`", path, pattern))
+                    }
+                    Ok(_) => DetectorMatch::new(
+                        false,
+                        format!("`{}` does not match `{}`", path, pattern),
+                    ),
+                    Err(_) => DetectorMatch::new(false, format!("file `{}` not found", path)),
+                }
+            }
+            "" => DetectorMatch::new(false, "detector has no type, `all`, or `any` rules"),
+            other => DetectorMatch::new(false, format!("unknown detector type `{}`", other)),
+        }
+    }
+}
+
+fn join_reasons(results: &[DetectorMatch], sep: &str) -> String {
+    results
+        .iter()
+        .map(|r| r.reason.as_str())
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+fn invalid_pattern(pattern: &str, err: &regex::Error) -> DetectorMatch {
+    DetectorMatch::new(false, format!("invalid pattern `{}`: {}", pattern, err))
+}
+
+/// Translate a glob (`*`, `?`, `**`) into an anchored regex over `/`-separated
+/// relative paths.
+fn glob_to_regex(glob: &str) -> Option<regex::Regex> {
+    let mut re = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    re.push_str("(?:.*/)?");
+                } else {
+                    re.push_str(".*");
+                }
+            }
+            '*' => re.push_str("[^/]*"),
+            '?' => re.push_str("[^/]"),
+            other => re.push_str(&regex::escape(&other.to_string())),
+        }
+    }
+    re.push('$');
+    regex::Regex::new(&re).ok()
+}
+
+/// Return the first path (relative to `project_root`) matching `glob`.
+fn glob_match_first(project_root: &Path, glob: &str) -> Option<String> {
+    let re = glob_to_regex(glob)?;
+    // Start the walk at the longest wildcard-free directory prefix.
+    let prefix: Vec<&str> = glob
+        .split('/')
+        .take_while(|part| !part.contains(['*', '?']))
+        .collect();
+    let prefix_len = prefix.len().min(glob.split('/').count().saturating_sub(1));
+    let start = project_root.join(prefix[..prefix_len].join("/"));
+
+    walkdir::WalkDir::new(&start)
+        .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0
+                || !(e.file_type().is_dir()
+                    && GLOB_SKIP_DIRS.contains(&e.file_name().to_string_lossy().as_ref()))
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.depth() > 0)
+        .find_map(|e| {
+            let rel = e.path().strip_prefix(project_root).ok()?;
+            let rel = rel
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            re.is_match(&rel).then_some(rel)
+        })
+}
+
+/// Locate an executable named `binary` in the directories listed on `PATH`.
+fn find_on_path(binary: &str) -> Option<PathBuf> {
+    if binary.is_empty() {
+        return None;
+    }
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths).find_map(|dir| {
+        let candidate = dir.join(binary);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        let exe = dir.join(format!("{}.exe", binary));
+        (cfg!(windows) && exe.is_file()).then_some(exe)
+    })
 }
 
 /// Represents a detected and active plugin at runtime.
@@ -181,6 +579,8 @@ pub struct ActivePlugin {
     pub def: PluginDef,
     pub detected: bool,
     pub source: PluginSource,
+    /// Why the plugin is (or is not) active.
+    pub reason: String,
 }
 
 /// Built-in plugin definitions.
@@ -194,14 +594,17 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
             detector: Some(DetectorDef {
                 detector_type: "directory".to_string(),
                 path: ".git".to_string(),
+                ..Default::default()
             }),
             commands: vec![],
+            checks: Vec::new(),
             hooks: HashMap::from([
                 (
                     "on_handoff_generate".to_string(),
                     HookDef {
                         command: "git status --short".to_string(),
                         inject_as: "git_status".to_string(),
+                        protocol: HookProtocol::Text,
                     },
                 ),
                 (
@@ -209,6 +612,7 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
                     HookDef {
                         command: "git log --oneline -5".to_string(),
                         inject_as: "recent_commits".to_string(),
+                        protocol: HookProtocol::Text,
                     },
                 ),
             ]),
@@ -221,6 +625,7 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
             detector: Some(DetectorDef {
                 detector_type: "directory".to_string(),
                 path: ".beads".to_string(),
+                ..Default::default()
             }),
             commands: vec![
                 PluginCommand {
@@ -242,12 +647,14 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
                     read_only: true,
                 },
             ],
+            checks: Vec::new(),
             hooks: HashMap::from([
                 (
                     "on_handoff_generate".to_string(),
                     HookDef {
                         command: "bd list --status=open".to_string(),
                         inject_as: "open_issues".to_string(),
+                        protocol: HookProtocol::Text,
                     },
                 ),
                 (
@@ -255,6 +662,7 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
                     HookDef {
                         command: "bd stats".to_string(),
                         inject_as: "beads_stats".to_string(),
+                        protocol: HookProtocol::Text,
                     },
                 ),
             ]),
@@ -267,8 +675,10 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
             detector: Some(DetectorDef {
                 detector_type: "directory".to_string(),
                 path: "openspec".to_string(),
+                ..Default::default()
             }),
             commands: vec![],
+            checks: Vec::new(),
             hooks: HashMap::new(),
         },
         PluginDef {
@@ -279,6 +689,7 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
             detector: Some(DetectorDef {
                 detector_type: "file".to_string(),
                 path: "testaruda.toml".to_string(),
+                ..Default::default()
             }),
             commands: vec![PluginCommand {
                 name: "select".to_string(),
@@ -286,11 +697,13 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
                 passthrough: "testaruda select".to_string(),
                 read_only: true,
             }],
+            checks: Vec::new(),
             hooks: HashMap::from([(
                 "on_status".to_string(),
                 HookDef {
                     command: "testaruda metrics".to_string(),
                     inject_as: "testaruda_metrics".to_string(),
+                    protocol: HookProtocol::Text,
                 },
             )]),
         },
@@ -302,6 +715,7 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
             detector: Some(DetectorDef {
                 detector_type: "directory".to_string(),
                 path: ".espectacular".to_string(),
+                ..Default::default()
             }),
             commands: vec![PluginCommand {
                 name: "check".to_string(),
@@ -309,11 +723,13 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
                 passthrough: "ah check".to_string(),
                 read_only: true,
             }],
+            checks: Vec::new(),
             hooks: HashMap::from([(
                 "on_status".to_string(),
                 HookDef {
                     command: "sh -c 'ah doctor || true'".to_string(),
                     inject_as: "espectacular_doctor".to_string(),
+                    protocol: HookProtocol::Text,
                 },
             )]),
         },
@@ -325,6 +741,7 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
             detector: Some(DetectorDef {
                 detector_type: "directory".to_string(),
                 path: ".dont".to_string(),
+                ..Default::default()
             }),
             commands: vec![PluginCommand {
                 name: "check".to_string(),
@@ -332,11 +749,13 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
                 passthrough: "dont check".to_string(),
                 read_only: true,
             }],
+            checks: Vec::new(),
             hooks: HashMap::from([(
                 "on_status".to_string(),
                 HookDef {
                     command: "dont check".to_string(),
                     inject_as: "dont_check".to_string(),
+                    protocol: HookProtocol::Text,
                 },
             )]),
         },
@@ -348,6 +767,7 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
             detector: Some(DetectorDef {
                 detector_type: "file".to_string(),
                 path: "pretender.toml".to_string(),
+                ..Default::default()
             }),
             commands: vec![PluginCommand {
                 name: "check".to_string(),
@@ -355,11 +775,13 @@ pub fn builtin_plugins() -> Vec<PluginDef> {
                 passthrough: "pretender check".to_string(),
                 read_only: true,
             }],
+            checks: Vec::new(),
             hooks: HashMap::from([(
                 "on_status".to_string(),
                 HookDef {
                     command: "sh -c 'pretender doctor || true'".to_string(),
                     inject_as: "pretender_doctor".to_string(),
+                    protocol: HookProtocol::Text,
                 },
             )]),
         },
@@ -372,15 +794,15 @@ pub fn detect_plugins(project_root: &Path) -> Vec<ActivePlugin> {
 
     // Load built-in plugins
     for def in builtin_plugins() {
-        let detected = if let Some(ref detector) = def.detector {
-            project_root.join(&detector.path).exists()
-        } else {
-            false
+        let detection = match def.detector {
+            Some(ref detector) => detector.evaluate(project_root),
+            None => DetectorMatch::new(false, "no detector"),
         };
         plugins.push(ActivePlugin {
             def,
-            detected,
+            detected: detection.matched,
             source: PluginSource::BuiltIn,
+            reason: detection.reason,
         });
     }
 
@@ -400,15 +822,15 @@ pub fn detect_plugins(project_root: &Path) -> Vec<ActivePlugin> {
                 && let Ok(content) = std::fs::read_to_string(&path)
                 && let Ok(def) = toml::from_str::<PluginDef>(&content)
             {
-                let detected = if let Some(ref detector) = def.detector {
-                    project_root.join(&detector.path).exists()
-                } else {
-                    true
+                let detection = match def.detector {
+                    Some(ref detector) => detector.evaluate(project_root),
+                    None => DetectorMatch::new(true, "no detector (always active)"),
                 };
                 plugins.push(ActivePlugin {
                     def,
-                    detected,
+                    detected: detection.matched,
                     source: PluginSource::Custom,
+                    reason: detection.reason,
                 });
             }
         }
@@ -420,24 +842,93 @@ pub fn detect_plugins(project_root: &Path) -> Vec<ActivePlugin> {
 /// Execute a plugin hook and return its output.
 ///
 /// Enforces a 30-second timeout: if the child process does not complete within
-/// that window it is killed and `None` is returned.
+/// that window it is killed and `None` is returned. JSON-protocol hooks receive
+/// `context` on stdin and must print a [`HookResponse`] on stdout.
 pub fn execute_hook(project_root: &Path, hook: &HookDef) -> Option<HookOutput> {
+    execute_hook_with_context(project_root, "", hook, None)
+}
+
+fn execute_hook_with_context(
+    project_root: &Path,
+    plugin_name: &str,
+    hook: &HookDef,
+    context: Option<&HookContext>,
+) -> Option<HookOutput> {
+    let stdin = match (hook.protocol, context) {
+        (HookProtocol::Json, Some(ctx)) => Some(serde_json::to_vec(ctx).ok()?),
+        (HookProtocol::Json, None) => Some(b"{}".to_vec()),
+        (HookProtocol::Text, _) => None,
+    };
+    let stdout = run_hook_command(project_root, &hook.command, stdin)?;
+
+    match hook.protocol {
+        HookProtocol::Text => Some(HookOutput {
+            plugin: plugin_name.to_string(),
+            label: hook.inject_as.clone(),
+            content: stdout,
+            data: None,
+        }),
+        HookProtocol::Json => match serde_json::from_str::<HookResponse>(&stdout) {
+            Ok(response) => Some(HookOutput {
+                plugin: plugin_name.to_string(),
+                label: hook.inject_as.clone(),
+                content: response.text.clone().unwrap_or_default(),
+                data: Some(response),
+            }),
+            Err(e) => {
+                warn_invalid_hook_response(plugin_name, &hook.inject_as, &e.to_string());
+                None
+            }
+        },
+    }
+}
+
+/// Spawn a hook command, optionally feeding `stdin`, and return its stdout when
+/// it exits successfully with non-empty output.
+fn run_hook_command(project_root: &Path, command: &str, stdin: Option<Vec<u8>>) -> Option<String> {
+    let (status, content) = run_plugin_command(project_root, command, stdin)?;
+    if !status.success() || content.is_empty() {
+        return None;
+    }
+    Some(content)
+}
+
+/// Spawn a plugin command under [`PLUGIN_TIMEOUT`] and return its exit status
+/// and stdout. Returns `None` if it cannot be spawned or times out.
+fn run_plugin_command(
+    project_root: &Path,
+    command: &str,
+    stdin: Option<Vec<u8>>,
+) -> Option<(std::process::ExitStatus, String)> {
     use std::sync::{Arc, Mutex};
 
-    let parts = shell_words::split(&hook.command).ok()?;
+    let parts = shell_words::split(command).ok()?;
     if parts.is_empty() {
         return None;
     }
 
-    let child = Command::new(&parts[0])
+    let mut child = Command::new(&parts[0])
         .args(&parts[1..])
         .current_dir(project_root)
+        .stdin(if stdin.is_some() {
+            std::process::Stdio::piped()
+        } else {
+            std::process::Stdio::inherit()
+        })
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::null())
         .spawn()
         .ok()?;
 
-    let _pid = child.id();
+    // Write the event context from a separate thread so a hook that never
+    // reads stdin cannot block us; dropping the handle closes the pipe.
+    if let (Some(input), Some(mut pipe)) = (stdin, child.stdin.take()) {
+        std::thread::spawn(move || {
+            use std::io::Write;
+            let _ = pipe.write_all(&input);
+        });
+    }
+
     let child = Arc::new(Mutex::new(child));
     let child_thread = Arc::clone(&child);
 
@@ -476,13 +967,7 @@ pub fn execute_hook(project_root: &Path, hook: &HookDef) -> Option<HookOutput> {
             } else {
                 String::new()
             };
-            if !status.success() || content.is_empty() {
-                return None;
-            }
-            Some(HookOutput {
-                label: hook.inject_as.clone(),
-                content,
-            })
+            Some((status, content))
         }
         Ok(Err(_)) => None,
         Err(_) => {
@@ -497,6 +982,109 @@ pub fn execute_hook(project_root: &Path, hook: &HookDef) -> Option<HookOutput> {
     }
 }
 
+impl HookContext {
+    /// Gather the event context for JSON-protocol hooks.
+    ///
+    /// `project` overrides project detection; otherwise `WAI_PROJECT` is used,
+    /// falling back to the only project when exactly one exists.
+    pub fn gather(project_root: &Path, event: &str, project: Option<&str>) -> Self {
+        let projects_dir = crate::config::projects_dir(project_root);
+        let project = project.map(str::to_string).or_else(|| {
+            if let Ok(name) = std::env::var("WAI_PROJECT")
+                && !name.is_empty()
+                && projects_dir.join(&name).is_dir()
+            {
+                return Some(name);
+            }
+            let names: Vec<String> = std::fs::read_dir(&projects_dir)
+                .ok()?
+                .flatten()
+                .filter(|e| e.path().is_dir())
+                .filter_map(|e| e.file_name().to_str().map(str::to_string))
+                .collect();
+            (names.len() == 1).then(|| names[0].clone())
+        });
+
+        let (phase, artifacts) = match &project {
+            Some(name) => {
+                let proj_dir = projects_dir.join(name);
+                let phase =
+                    crate::state::ProjectState::load(&proj_dir.join(crate::config::STATE_FILE))
+                        .ok()
+                        .map(|state| state.current.to_string());
+                let mut artifacts = Vec::new();
+                collect_artifact_paths(project_root, &proj_dir, &mut artifacts);
+                artifacts.sort();
+                (phase, artifacts)
+            }
+            None => (None, Vec::new()),
+        };
+
+        HookContext {
+            protocol_version: HOOK_PROTOCOL_VERSION,
+            event: event.to_string(),
+            project_root: project_root.display().to_string(),
+            project,
+            phase,
+            artifacts,
+            pipeline_run: active_pipeline_run(project_root),
+        }
+    }
+}
+
+/// Recursively collect markdown artifact paths under `dir`, relative to `root`.
+fn collect_artifact_paths(root: &Path, dir: &Path, out: &mut Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_artifact_paths(root, &path, out);
+        } else if path.extension().is_some_and(|e| e == "md") {
+            out.push(
+                path.strip_prefix(root)
+                    .unwrap_or(&path)
+                    .display()
+                    .to_string(),
+            );
+        }
+    }
+}
+
+/// Read the active pipeline run (`WAI_PIPELINE_RUN`, then `.last-run`).
+fn active_pipeline_run(project_root: &Path) -> Option<HookPipelineRun> {
+    #[derive(Deserialize)]
+    struct RunState {
+        run_id: String,
+        pipeline: String,
+        #[serde(default)]
+        topic: Option<String>,
+        #[serde(default)]
+        current_step: usize,
+    }
+
+    let run_id = std::env::var("WAI_PIPELINE_RUN")
+        .ok()
+        .filter(|id| !id.is_empty())
+        .or_else(|| {
+            std::fs::read_to_string(crate::config::last_run_path(project_root))
+                .ok()
+                .map(|s| s.trim().to_string())
+                .filter(|id| !id.is_empty())
+        })?;
+    let run_path = crate::config::wai_dir(project_root)
+        .join("pipeline-runs")
+        .join(format!("{}.yml", run_id));
+    let run: RunState = serde_yml::from_str(&std::fs::read_to_string(run_path).ok()?).ok()?;
+    Some(HookPipelineRun {
+        run_id: run.run_id,
+        pipeline: run.pipeline,
+        topic: run.topic,
+        current_step: run.current_step,
+    })
+}
+
 /// Run all hooks for a given event across all detected plugins.
 ///
 /// Custom (repository-owned) plugins are only executed when their hook digest
@@ -504,36 +1092,349 @@ pub fn execute_hook(project_root: &Path, hook: &HookDef) -> Option<HookOutput> {
 /// warning; they are never executed and never prompt. Built-in plugins are
 /// always trusted.
 pub fn run_hooks(project_root: &Path, event: &str) -> Vec<HookOutput> {
+    run_project_hooks(project_root, event, None)
+}
+
+/// Like [`run_hooks`], but tells JSON-protocol hooks which project the event
+/// concerns instead of relying on `WAI_PROJECT` / single-project detection.
+pub fn run_project_hooks(
+    project_root: &Path,
+    event: &str,
+    project: Option<&str>,
+) -> Vec<HookOutput> {
     let plugins = detect_plugins(project_root);
     let mut outputs = Vec::new();
     let trust_store = TrustStore::load();
+    // Gathered lazily: only JSON-protocol hooks need it.
+    let mut context: Option<HookContext> = None;
 
     for plugin in &plugins {
         if !plugin.detected {
             continue;
         }
-        if let Some(hook) = plugin.def.hooks.get(event) {
-            match plugin.source {
-                PluginSource::BuiltIn => {
-                    if let Some(output) = execute_hook(project_root, hook) {
-                        outputs.push(output);
-                    }
+        let Some(hook) = plugin.def.hooks.get(event) else {
+            continue;
+        };
+        if plugin.source == PluginSource::Custom {
+            let digest = compute_hook_digest(&plugin.def, event, hook);
+            if !trust_store.is_approved(&digest) {
+                warn_skipped_hook(&plugin.def.name, event, &hook.inject_as);
+                continue;
+            }
+        }
+        if hook.protocol == HookProtocol::Json && context.is_none() {
+            context = Some(HookContext::gather(project_root, event, project));
+        }
+        if let Some(output) =
+            execute_hook_with_context(project_root, &plugin.def.name, hook, context.as_ref())
+        {
+            outputs.push(output);
+        }
+    }
+
+    outputs
+}
+
+// ── Plugin checks ──────────────────────────────────────────────────────────────
+
+/// Compute the trust digest for a plugin check.
+///
+/// Covers everything that determines what runs or how the result is judged,
+/// so editing the command, fix, or expectations requires re-approval.
+pub fn compute_check_digest(plugin: &PluginDef, check: &PluginCheckDef) -> String {
+    use sha2::Digest;
+
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(plugin.name.as_bytes());
+    hasher.update(check_trust_name(check).as_bytes());
+    hasher.update(check.command.as_bytes());
+    hasher.update(check.expect_exit.to_le_bytes());
+    if let Some(ref schema) = check.expect_json {
+        hasher.update(schema.to_string().as_bytes());
+    }
+    if let Some(ref fix) = check.fix {
+        hasher.update(b"fix=");
+        hasher.update(fix.as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Name under which a check is recorded in the trust store (`check:<name>`),
+/// keeping it distinct from hook names.
+pub fn check_trust_name(check: &PluginCheckDef) -> String {
+    format!("check:{}", check.name)
+}
+
+/// Outcome of running one plugin check.
+#[derive(Debug, Clone)]
+pub struct PluginCheckResult {
+    pub plugin: String,
+    pub intent: Option<String>,
+    pub success_criteria: Option<String>,
+    pub name: String,
+    pub status: HookCheckStatus,
+    pub message: String,
+    /// Fix command, only set for trusted checks that did not pass.
+    pub fix: Option<String>,
+}
+
+/// Run every `[[checks]]` entry of `kind` declared by detected custom plugins.
+///
+/// Checks whose digest is not in the trust store are not executed; they are
+/// reported as warnings telling the user how to approve them.
+pub fn run_plugin_checks(project_root: &Path, kind: PluginCheckKind) -> Vec<PluginCheckResult> {
+    let trust_store = TrustStore::load();
+    let mut results = Vec::new();
+
+    for plugin in detect_plugins(project_root) {
+        if !plugin.detected || plugin.source != PluginSource::Custom {
+            continue;
+        }
+        for check in plugin.def.checks.iter().filter(|c| c.kind == kind) {
+            let mut result = PluginCheckResult {
+                plugin: plugin.def.name.clone(),
+                intent: plugin.def.intent.clone(),
+                success_criteria: plugin.def.success_criteria.clone(),
+                name: check.name.clone(),
+                status: HookCheckStatus::Pass,
+                message: String::new(),
+                fix: None,
+            };
+            if !trust_store.is_approved(&compute_check_digest(&plugin.def, check)) {
+                result.status = HookCheckStatus::Warn;
+                result.message = format!(
+                    "Skipped: not trusted (approve with `wai plugin trust {}`)",
+                    plugin.def.name
+                );
+                results.push(result);
+                continue;
+            }
+            match evaluate_check(project_root, check) {
+                Ok(message) => {
+                    result.message = message;
                 }
-                PluginSource::Custom => {
-                    let digest = compute_hook_digest(&plugin.def, event, hook);
-                    if trust_store.is_approved(&digest) {
-                        if let Some(output) = execute_hook(project_root, hook) {
-                            outputs.push(output);
-                        }
-                    } else {
-                        warn_skipped_hook(&plugin.def.name, event, &hook.inject_as);
-                    }
+                Err(reason) => {
+                    result.status = check.severity;
+                    result.message = match check.message {
+                        Some(ref message) => format!("{} ({})", message, reason),
+                        None => reason,
+                    };
+                    result.fix = check.fix.clone();
                 }
             }
+            results.push(result);
         }
     }
+    results
+}
+
+/// Run a check command and judge it against its expectations.
+///
+/// Returns the pass message, or the reason the check did not pass.
+fn evaluate_check(project_root: &Path, check: &PluginCheckDef) -> Result<String, String> {
+    let (status, stdout) = run_plugin_command(project_root, &check.command, None)
+        .ok_or_else(|| format!("`{}` could not be run or timed out", check.command))?;
+
+    match status.code() {
+        Some(code) if code == check.expect_exit => {}
+        Some(code) => {
+            return Err(format!(
+                "`{}` exited with {} (expected {})",
+                check.command, code, check.expect_exit
+            ));
+        }
+        None => return Err(format!("`{}` was terminated by a signal", check.command)),
+    }
+
+    if let Some(ref schema) = check.expect_json {
+        let schema = serde_json::to_value(schema).map_err(|e| e.to_string())?;
+        let output: serde_json::Value = serde_json::from_str(stdout.trim())
+            .map_err(|e| format!("output is not valid JSON: {}", e))?;
+        let mut errors = Vec::new();
+        validate_json_schema(&output, &schema, "$", &mut errors);
+        if let Some(first) = errors.first() {
+            return Err(format!("output does not match expected JSON: {}", first));
+        }
+    }
+
+    Ok(if check.description.is_empty() {
+        "OK".to_string()
+    } else {
+        check.description.clone()
+    })
+}
+
+/// Validate `value` against a small JSON Schema subset, collecting errors.
+fn validate_json_schema(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    path: &str,
+    errors: &mut Vec<String>,
+) {
+    use serde_json::Value;
+
+    let Some(schema) = schema.as_object() else {
+        return;
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let types: Vec<&str> = match expected {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        let matches = types.iter().any(|t| match *t {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "number" => value.is_number(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            _ => false,
+        });
+        if !types.is_empty() && !matches {
+            errors.push(format!("{} should be {}", path, types.join(" or ")));
+            return;
+        }
+    }
+    if let Some(expected) = schema.get("const")
+        && value != expected
+    {
+        errors.push(format!("{} should equal {}", path, expected));
+    }
+    if let Some(Value::Array(options)) = schema.get("enum")
+        && !options.contains(value)
+    {
+        errors.push(format!(
+            "{} should be one of {}",
+            path,
+            Value::Array(options.clone())
+        ));
+    }
+    if let Some(n) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64)
+            && n < min
+        {
+            errors.push(format!("{} should be >= {}", path, min));
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64)
+            && n > max
+        {
+            errors.push(format!("{} should be <= {}", path, max));
+        }
+    }
+    if let Some(object) = value.as_object() {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(key) {
+                    errors.push(format!("{} is missing required key '{}'", path, key));
+                }
+            }
+        }
+        if let Some(Value::Object(properties)) = schema.get("properties") {
+            for (key, sub_schema) in properties {
+                if let Some(sub_value) = object.get(key) {
+                    validate_json_schema(
+                        sub_value,
+                        sub_schema,
+                        &format!("{}.{}", path, key),
+                        errors,
+                    );
+                }
+            }
+        }
+    }
+    if let Some(items) = value.as_array() {
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+            && (items.len() as u64) < min
+        {
+            errors.push(format!("{} should have at least {} item(s)", path, min));
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+            && (items.len() as u64) > max
+        {
+            errors.push(format!("{} should have at most {} item(s)", path, max));
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (i, item) in items.iter().enumerate() {
+                validate_json_schema(item, item_schema, &format!("{}[{}]", path, i), errors);
+            }
+        }
+    }
+}
+
+/// Run a trusted check's fix command, failing if it exits non-zero.
+pub fn run_check_fix(project_root: &Path, fix: &str) -> Result<(), String> {
+    let (status, _) = run_plugin_command(project_root, fix, None)
+        .ok_or_else(|| format!("`{}` could not be run or timed out", fix))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("`{}` exited with {}", fix, status))
+    }
+}
+
+/// Status lines from JSON-protocol hook responses, tagged with their plugin.
+pub fn collect_plugin_status(outputs: &[HookOutput]) -> Vec<crate::json::PluginStatusEntry> {
+    outputs
+        .iter()
+        .filter_map(|o| o.data.as_ref().map(|d| (o, d)))
+        .flat_map(|(o, d)| {
+            d.status.iter().map(|e| crate::json::PluginStatusEntry {
+                plugin: o.plugin.clone(),
+                label: e.label.clone(),
+                value: e.value.clone(),
+            })
+        })
+        .collect()
+}
 
+/// Suggested commands from JSON-protocol hook responses.
+pub fn collect_plugin_suggestions(outputs: &[HookOutput]) -> Vec<crate::json::Suggestion> {
     outputs
+        .iter()
+        .filter_map(|o| o.data.as_ref())
+        .flat_map(|d| {
+            d.suggestions.iter().map(|s| crate::json::Suggestion {
+                label: s.label.clone(),
+                command: s.command.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Memories from JSON-protocol hook responses.
+pub fn collect_plugin_memories(outputs: &[HookOutput]) -> Vec<String> {
+    outputs
+        .iter()
+        .filter_map(|o| o.data.as_ref())
+        .flat_map(|d| d.memories.iter().cloned())
+        .collect()
+}
+
+/// Emit a warning about a JSON hook whose stdout could not be parsed.
+fn warn_invalid_hook_response(plugin_name: &str, inject_as: &str, error: &str) {
+    let context = current_context();
+    if context.json {
+        let warning = serde_json::json!({
+            "level": "warning",
+            "code": "plugin_hook_invalid_json",
+            "plugin": plugin_name,
+            "inject_as": inject_as,
+            "message": format!("Plugin hook '{}' returned invalid JSON: {}", inject_as, error),
+        });
+        println!("{}", warning);
+    } else {
+        use owo_colors::OwoColorize;
+        eprintln!(
+            "  {} Plugin '{}' hook '{}' returned invalid JSON: {}",
+            "!".yellow(),
+            plugin_name,
+            inject_as,
+            error
+        );
+    }
 }
 
 /// Emit a warning about a skipped (untrusted) hook.
@@ -580,6 +1481,44 @@ pub fn find_plugin_command<'a>(
     None
 }
 
+// ── bd issue helpers ──────────────────────────────────────────────────────────
+
+/// Fetch up to `limit` ready (unblocked) issues as `(id, title)` pairs by
+/// shelling out to `bd ready --json`.
+///
+/// Returns an empty list if beads is not detected, `bd` is not on PATH, or the
+/// output cannot be parsed.
+pub fn fetch_ready_issues(project_root: &Path, limit: usize) -> Vec<(String, String)> {
+    if !project_root.join(".beads").exists() {
+        return Vec::new();
+    }
+    let Some(output) = Command::new("bd")
+        .args(["ready", "--json"])
+        .current_dir(project_root)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+    else {
+        return Vec::new();
+    };
+    let Ok(serde_json::Value::Array(issues)) = serde_json::from_slice(&output.stdout) else {
+        return Vec::new();
+    };
+    issues
+        .iter()
+        .filter_map(|issue| {
+            let id = issue.get("id")?.as_str()?.to_string();
+            let title = issue
+                .get("title")
+                .and_then(|t| t.as_str())
+                .unwrap_or_default()
+                .to_string();
+            Some((id, title))
+        })
+        .take(limit)
+        .collect()
+}
+
 // ── bd memory helpers ─────────────────────────────────────────────────────────
 
 /// Character budget for bd memories injected into LLM prompts.
@@ -808,6 +1747,12 @@ mod tests {
         assert!(big.is_char_boundary(truncated.len()));
     }
 
+    #[test]
+    fn fetch_ready_issues_empty_when_no_beads_dir() {
+        let tmp = TempDir::new().unwrap();
+        assert!(fetch_ready_issues(tmp.path(), 5).is_empty());
+    }
+
     // ── store_memory ──────────────────────────────────────────────────────────
 
     #[test]
@@ -829,6 +1774,7 @@ mod tests {
             success_criteria: None,
             detector: None,
             commands: vec![],
+            checks: Vec::new(),
             hooks: HashMap::new(),
         }
     }
@@ -839,6 +1785,7 @@ mod tests {
         let hook = HookDef {
             command: "echo hi".to_string(),
             inject_as: "greeting".to_string(),
+            protocol: HookProtocol::Text,
         };
         let d1 = compute_hook_digest(&def, "on_status", &hook);
         let d2 = compute_hook_digest(&def, "on_status", &hook);
@@ -848,6 +1795,7 @@ mod tests {
         let hook2 = HookDef {
             command: "echo bye".to_string(),
             inject_as: "greeting".to_string(),
+            protocol: HookProtocol::Text,
         };
         assert_ne!(d1, compute_hook_digest(&def, "on_status", &hook2));
 
@@ -886,6 +1834,27 @@ mod tests {
         assert!(!store.revoke(&digest));
     }
 
+    #[test]
+    fn trust_store_keys_trust_lookup_revoke() {
+        let mut store = TrustStore::default();
+        store.trust_key(TrustedKey {
+            label: "team".to_string(),
+            public_key: "ABCD".to_string(),
+            added_at: "t1".to_string(),
+        });
+        store.trust_key(TrustedKey {
+            label: "team-renamed".to_string(),
+            public_key: "abcd".to_string(),
+            added_at: "t2".to_string(),
+        });
+        assert_eq!(store.keys().len(), 1, "same key must not create duplicates");
+        assert_eq!(store.trusted_key("AbCd").unwrap().label, "team-renamed");
+
+        assert!(store.revoke_key("abcd"));
+        assert!(store.trusted_key("abcd").is_none());
+        assert!(!store.revoke_key("abcd"));
+    }
+
     #[test]
     fn trust_store_approve_replaces_same_digest() {
         let mut store = TrustStore::default();
@@ -1070,6 +2039,7 @@ inject_as = "evil_marker"
         let hook = HookDef {
             command: "sleep 60".to_string(),
             inject_as: "timeout_test".to_string(),
+            protocol: HookProtocol::Text,
         };
         let tmp = TempDir::new().unwrap();
         let start = std::time::Instant::now();
@@ -1092,10 +2062,314 @@ inject_as = "evil_marker"
         let hook = HookDef {
             command: "echo 'hello'".to_string(),
             inject_as: "fast_test".to_string(),
+            protocol: HookProtocol::Text,
         };
         let tmp = TempDir::new().unwrap();
         let result = execute_hook(tmp.path(), &hook);
         assert!(result.is_some());
         assert_eq!(result.unwrap().label, "fast_test");
     }
+
+    // ── JSON protocol ─────────────────────────────────────────────────────────
+
+    fn json_hook(command: &str) -> HookDef {
+        HookDef {
+            command: command.to_string(),
+            inject_as: "json_test".to_string(),
+            protocol: HookProtocol::Json,
+        }
+    }
+
+    #[test]
+    fn hook_digest_only_changes_for_json_protocol() {
+        let plugin = builtin_plugins().into_iter().next().unwrap();
+        let text = HookDef {
+            command: "echo hi".to_string(),
+            inject_as: "x".to_string(),
+            protocol: HookProtocol::Text,
+        };
+        let mut json = text.clone();
+        json.protocol = HookProtocol::Json;
+        assert_ne!(
+            compute_hook_digest(&plugin, "on_status", &text),
+            compute_hook_digest(&plugin, "on_status", &json)
+        );
+    }
+
+    #[test]
+    fn execute_json_hook_receives_context_and_parses_response() {
+        let tmp = TempDir::new().unwrap();
+        let context = HookContext {
+            protocol_version: HOOK_PROTOCOL_VERSION,
+            event: "on_status".to_string(),
+            project_root: tmp.path().display().to_string(),
+            project: Some("demo".to_string()),
+            phase: None,
+            artifacts: Vec::new(),
+            pipeline_run: None,
+        };
+        let hook = json_hook(
+            r#"sh -c 'grep -q "\"project\":\"demo\"" && echo "{\"text\":\"hi\",\"memories\":[\"m1\"]}"'"#,
+        );
+        let output = execute_hook_with_context(tmp.path(), "p", &hook, Some(&context)).unwrap();
+        assert_eq!(output.plugin, "p");
+        assert_eq!(output.content, "hi");
+        assert_eq!(output.data.unwrap().memories, vec!["m1".to_string()]);
+    }
+
+    #[test]
+    fn execute_json_hook_rejects_invalid_json() {
+        let tmp = TempDir::new().unwrap();
+        let hook = json_hook("echo not-json");
+        assert!(execute_hook_with_context(tmp.path(), "p", &hook, None).is_none());
+    }
+
+    // ── plugin checks ─────────────────────────────────────────────────────────
+
+    fn sample_check(command: &str) -> PluginCheckDef {
+        toml::from_str(&format!(
+            "name = \"sample\"\ncommand = {:?}\nseverity = \"fail\"\n",
+            command
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn json_schema_subset_reports_mismatches() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "required": ["owners", "ok"],
+            "properties": {
+                "ok": { "const": true },
+                "owners": { "type": "array", "minItems": 1, "items": { "type": "string" } }
+            }
+        });
+        let mut errors = Vec::new();
+        validate_json_schema(
+            &serde_json::json!({"ok": true, "owners": ["@team"]}),
+            &schema,
+            "$",
+            &mut errors,
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        validate_json_schema(
+            &serde_json::json!({"ok": false, "owners": [1]}),
+            &schema,
+            "$",
+            &mut errors,
+        );
+        assert_eq!(
+            errors,
+            vec![
+                "$.ok should equal true".to_string(),
+                "$.owners[0] should be string".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn evaluate_check_compares_exit_code_and_json() {
+        let tmp = TempDir::new().unwrap();
+        assert!(evaluate_check(tmp.path(), &sample_check("true")).is_ok());
+
+        let err = evaluate_check(tmp.path(), &sample_check("false")).unwrap_err();
+        assert!(err.contains("exited with 1 (expected 0)"), "{}", err);
+
+        let mut check = sample_check(r#"echo '{"count": 0}'"#);
+        check.expect_json =
+            Some(toml::from_str("type = \"object\"\nrequired = [\"missing\"]").unwrap());
+        let err = evaluate_check(tmp.path(), &check).unwrap_err();
+        assert!(err.contains("missing required key 'missing'"), "{}", err);
+    }
+
+    #[test]
+    fn check_digest_covers_fix_command() {
+        let plugin = sample_plugin("team");
+        let mut check = sample_check("true");
+        let before = compute_check_digest(&plugin, &check);
+        check.fix = Some("touch CODEOWNERS".to_string());
+        assert_ne!(before, compute_check_digest(&plugin, &check));
+    }
+
+    #[serial_test::serial]
+    #[test]
+    fn hook_context_gathers_phase_artifacts_and_pipeline_run() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        let proj = root.join(".wai/projects/demo");
+        std::fs::create_dir_all(proj.join("research")).unwrap();
+        std::fs::write(proj.join("research/2026-01-01-notes.md"), "x").unwrap();
+        crate::state::ProjectState::default()
+            .save(&proj.join(crate::config::STATE_FILE))
+            .unwrap();
+        let runs = root.join(".wai/pipeline-runs");
+        std::fs::create_dir_all(&runs).unwrap();
+        std::fs::write(
+            runs.join("r-1.yml"),
+            "run_id: r-1\npipeline: feature\ntopic: auth\ncreated_at: now\ncurrent_step: 2\n",
+        )
+        .unwrap();
+
+        // SAFETY: test-only env var, single-threaded tests.
+        let saved = std::env::var_os("WAI_PIPELINE_RUN");
+        unsafe { std::env::set_var("WAI_PIPELINE_RUN", "r-1") };
+
+        let ctx = HookContext::gather(root, "on_status", None);
+        assert_eq!(ctx.project.as_deref(), Some("demo"));
+        assert_eq!(ctx.phase.as_deref(), Some("research"));
+        assert_eq!(
+            ctx.artifacts,
+            vec![".wai/projects/demo/research/2026-01-01-notes.md".to_string()]
+        );
+        let run = ctx.pipeline_run.unwrap();
+        assert_eq!((run.pipeline.as_str(), run.current_step), ("feature", 2));
+
+        match saved {
+            Some(v) => unsafe { std::env::set_var("WAI_PIPELINE_RUN", v) },
+            None => unsafe { std::env::remove_var("WAI_PIPELINE_RUN") },
+        }
+    }
+
+    // ── detectors ─────────────────────────────────────────────────────────────
+
+    fn leaf(detector_type: &str, path: &str, pattern: Option<&str>) -> DetectorDef {
+        DetectorDef {
+            detector_type: detector_type.to_string(),
+            path: path.to_string(),
+            pattern: pattern.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn glob_to_regex_handles_wildcards() {
+        let re = glob_to_regex("src/**/*.rs").unwrap();
+        assert!(re.is_match("src/main.rs"));
+        assert!(re.is_match("src/commands/mod.rs"));
+        assert!(!re.is_match("tests/main.rs"));
+        assert!(!re.is_match("src/main.rsx"));
+
+        let re = glob_to_regex("*.t?ml").unwrap();
+        assert!(re.is_match("Cargo.toml"));
+        assert!(!re.is_match("crates/Cargo.toml"));
+    }
+
+    #[test]
+    fn detector_glob_and_content_rules() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::create_dir_all(tmp.path().join("tests/features")).unwrap();
+        std::fs::write(tmp.path().join("tests/features/login.feature"), "").unwrap();
+        std::fs::write(
+            tmp.path().join("Cargo.toml"),
+            "[dev-dependencies]\ncucumber = \"0.21\"\n",
+        )
+        .unwrap();
+
+        let glob = leaf("glob", "tests/**/*.feature", None).evaluate(tmp.path());
+        assert!(glob.matched);
+        assert!(glob.reason.contains("tests/features/login.feature"));
+        assert!(
+            !leaf("glob", "**/*.spec.ts", None)
+                .evaluate(tmp.path())
+                .matched
+        );
+
+        let content = leaf("content", "Cargo.toml", Some(r"(?m)^cucumber\s*="));
+        assert!(content.evaluate(tmp.path()).matched);
+        let missing = leaf("content", "package.json", Some("cucumber")).evaluate(tmp.path());
+        assert!(!missing.matched);
+        assert!(missing.reason.contains("not found"));
+        let invalid = leaf("content", "Cargo.toml", Some("(")).evaluate(tmp.path());
+        assert!(!invalid.matched);
+        assert!(invalid.reason.contains("invalid pattern"));
+    }
+
+    #[test]
+    fn detector_combinators_explain_outcome() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("Cargo.toml"), "").unwrap();
+
+        let all = DetectorDef {
+            all: vec![
+                leaf("file", "Cargo.toml", None),
+                leaf("directory", "features", None),
+            ],
+            ..Default::default()
+        };
+        let result = all.evaluate(tmp.path());
+        assert!(!result.matched);
+        assert_eq!(result.reason, "directory `features` not found");
+
+        let any = DetectorDef {
+            any: vec![
+                leaf("file", "package.json", None),
+                leaf("file", "Cargo.toml", None),
+            ],
+            ..Default::default()
+        };
+        let result = any.evaluate(tmp.path());
+        assert!(result.matched);
+        assert_eq!(result.reason, "file `Cargo.toml` exists");
+
+        let nested: DetectorDef = toml::from_str(
+            r#"
+            [[all]]
+            type = "file"
+            path = "Cargo.toml"
+
+            [[all]]
+            [[all.any]]
+            type = "binary"
+            path = "definitely-not-a-real-binary-xyz"
+
+            [[all.any]]
+            type = "glob"
+            path = "*.toml"
+            "#,
+        )
+        .unwrap();
+        let result = nested.evaluate(tmp.path());
+        assert!(result.matched, "{}", result.reason);
+        assert_eq!(
+            result.reason,
+            "file `Cargo.toml` exists and `Cargo.toml` matches `*.toml`"
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn detector_env_rule_checks_presence_and_pattern() {
+        let tmp = TempDir::new().unwrap();
+        let prev = std::env::var("WAI_TEST_DETECTOR_ENV").ok();
+        unsafe { std::env::set_var("WAI_TEST_DETECTOR_ENV", "staging") };
+
+        assert!(
+            leaf("env", "WAI_TEST_DETECTOR_ENV", None)
+                .evaluate(tmp.path())
+                .matched
+        );
+        assert!(
+            leaf("env", "WAI_TEST_DETECTOR_ENV", Some("^stag"))
+                .evaluate(tmp.path())
+                .matched
+        );
+        let mismatch = leaf("env", "WAI_TEST_DETECTOR_ENV", Some("^prod")).evaluate(tmp.path());
+        assert!(!mismatch.matched);
+        assert_eq!(
+            mismatch.reason,
+            "$WAI_TEST_DETECTOR_ENV does not match `^prod`"
+        );
+
+        unsafe { std::env::remove_var("WAI_TEST_DETECTOR_ENV") };
+        assert!(
+            !leaf("env", "WAI_TEST_DETECTOR_ENV", None)
+                .evaluate(tmp.path())
+                .matched
+        );
+
+        if let Some(v) = prev {
+            unsafe { std::env::set_var("WAI_TEST_DETECTOR_ENV", v) };
+        }
+    }
 }
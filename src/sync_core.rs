@@ -451,6 +451,31 @@ fn parse_skill_for_projection(path: &Path) -> Option<(String, String, String)> {
     Some((fm.name, fm.description, body_str))
 }
 
+/// Render the `.claude/commands/<category>/<action>.md` file for a hierarchical skill.
+///
+/// Shared by the claude-code projection and `wai resource lint` golden tests so
+/// both always agree on the projected output. Returns `None` when the SKILL.md
+/// frontmatter is missing or invalid.
+pub(crate) fn render_claude_code_command(
+    category: &str,
+    action: &str,
+    skill_file: &Path,
+) -> Option<String> {
+    let (_name, description, body) = parse_skill_for_projection(skill_file)?;
+    let cc_name = translate_skill_name(category, action);
+    Some(format!(
+        "---\n\
+         # Auto-generated by wai — do not edit directly\n\
+         name: \"{}\"\n\
+         description: \"{}\"\n\
+         category: \"{}\"\n\
+         ---\n\
+         \n\
+         {}",
+        cc_name, description, category, body
+    ))
+}
+
 /// Execute a claude-code projection: translate wai hierarchical skills to Claude Code commands.
 ///
 /// Scans `config_dir/skills/<category>/<action>/SKILL.md` for all hierarchical skills
@@ -510,7 +535,7 @@ pub(crate) fn execute_claude_code(project_root: &Path, config_dir: &Path) -> Res
                 continue;
             }
 
-            let Some((_name, description, body)) = parse_skill_for_projection(&skill_file) else {
+            let Some(output) = render_claude_code_command(&category, &action, &skill_file) else {
                 log::warning(format!(
                     "Skipping {}/{}: missing or invalid frontmatter",
                     category, action
@@ -519,19 +544,6 @@ pub(crate) fn execute_claude_code(project_root: &Path, config_dir: &Path) -> Res
                 continue;
             };
 
-            let cc_name = translate_skill_name(&category, &action);
-            let output = format!(
-                "---\n\
-                 # Auto-generated by wai — do not edit directly\n\
-                 name: \"{}\"\n\
-                 description: \"{}\"\n\
-                 category: \"{}\"\n\
-                 ---\n\
-                 \n\
-                 {}",
-                cc_name, description, category, body
-            );
-
             let target = project_root
                 .join(".claude")
                 .join("commands")
@@ -0,0 +1,119 @@
+//! Amp adapter: `~/.amp/file-changes/<thread-id>/*.json`.
+//!
+//! Amp keeps file-change snapshots but no conversation log, so every thread
+//! is normalized as a `diff-only` trace.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use super::{Fidelity, FileChange, Trace, TraceSource, parse_timestamp, within_repo};
+
+pub(super) fn discover(store: &Path, repo_root: &Path) -> Vec<Trace> {
+    let Ok(threads) = std::fs::read_dir(store.join("file-changes")) else {
+        return Vec::new();
+    };
+    threads
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .filter_map(|thread| parse_thread(&thread.path(), repo_root))
+        .collect()
+}
+
+fn parse_thread(dir: &Path, repo_root: &Path) -> Option<Trace> {
+    let mut changes: Vec<FileChange> = Vec::new();
+    for entry in std::fs::read_dir(dir).ok()?.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        let Some(change) = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|c| serde_json::from_str::<Value>(&c).ok())
+        else {
+            continue;
+        };
+        if change.get("reverted").and_then(|v| v.as_bool()) == Some(true) {
+            continue;
+        }
+        let Some(file) = change
+            .get("uri")
+            .or_else(|| change.get("filePath"))
+            .and_then(|v| v.as_str())
+            .map(|s| PathBuf::from(s.strip_prefix("file://").unwrap_or(s)))
+        else {
+            continue;
+        };
+        if !within_repo(&file, repo_root) {
+            continue;
+        }
+        let rel = file
+            .strip_prefix(repo_root)
+            .unwrap_or(&file)
+            .display()
+            .to_string();
+        let diff = match change.get("diff").and_then(|v| v.as_str()) {
+            Some(diff) => diff.to_string(),
+            None if change.get("isNewFile").and_then(|v| v.as_bool()) == Some(true) => {
+                "(new file)".to_string()
+            }
+            None => String::new(),
+        };
+        changes.push(FileChange {
+            path: rel,
+            timestamp: change.get("timestamp").and_then(parse_timestamp),
+            diff,
+        });
+    }
+    if changes.is_empty() {
+        return None;
+    }
+    changes.sort_by_key(|c| c.timestamp);
+
+    Some(Trace {
+        source: TraceSource::Amp,
+        session_id: dir.file_name()?.to_string_lossy().to_string(),
+        path: dir.to_path_buf(),
+        workspace: repo_root.to_path_buf(),
+        fidelity: Fidelity::DiffOnly,
+        turns: Vec::new(),
+        file_changes: changes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn threads_touching_repo_become_diff_only_traces() {
+        let home = TempDir::new().unwrap();
+        let thread = home.path().join("file-changes/T-1234");
+        std::fs::create_dir_all(&thread).unwrap();
+        std::fs::write(
+            thread.join("a.json"),
+            r#"{"uri":"file:///work/repo/src/lib.rs","diff":"-a\n+b","timestamp":1790000000000}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            thread.join("b.json"),
+            r#"{"uri":"file:///work/repo/README.md","diff":"+x","reverted":true}"#,
+        )
+        .unwrap();
+        let other = home.path().join("file-changes/T-9999");
+        std::fs::create_dir_all(&other).unwrap();
+        std::fs::write(
+            other.join("a.json"),
+            r#"{"uri":"file:///elsewhere/main.rs","diff":"+y"}"#,
+        )
+        .unwrap();
+
+        let traces = discover(home.path(), Path::new("/work/repo"));
+        assert_eq!(traces.len(), 1);
+        let trace = &traces[0];
+        assert_eq!(trace.id(), "amp:T-1234");
+        assert_eq!(trace.fidelity, Fidelity::DiffOnly);
+        assert!(trace.turns.is_empty());
+        assert_eq!(trace.file_changes.len(), 1);
+        assert_eq!(trace.file_changes[0].path, "src/lib.rs");
+        assert!(trace.file_changes[0].timestamp.is_some());
+    }
+}
@@ -0,0 +1,188 @@
+//! Claude Code adapter: `~/.claude/projects/<encoded-cwd>/<session>.jsonl`.
+
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use super::{
+    Fidelity, Role, ToolCall, Trace, TraceSource, attach_tool_output, parse_timestamp,
+    push_tool_call, push_turn, value_text, within_repo,
+};
+
+/// Claude Code names project directories after the working directory with
+/// every non-alphanumeric character replaced by `-`.
+fn encode_project_dir(path: &Path) -> String {
+    path.to_string_lossy()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+pub(super) fn discover(store: &Path, repo_root: &Path) -> Vec<Trace> {
+    let Ok(entries) = std::fs::read_dir(store.join("projects")) else {
+        return Vec::new();
+    };
+    let prefixes = [
+        encode_project_dir(repo_root),
+        encode_project_dir(&std::fs::canonicalize(repo_root).unwrap_or_default()),
+    ];
+
+    let mut traces = Vec::new();
+    for dir in entries.filter_map(|e| e.ok()) {
+        let name = dir.file_name().to_string_lossy().to_string();
+        // Sessions started in a subdirectory share the repo's prefix.
+        if !prefixes
+            .iter()
+            .any(|p| !p.is_empty() && name.starts_with(p))
+        {
+            continue;
+        }
+        let Ok(files) = std::fs::read_dir(dir.path()) else {
+            continue;
+        };
+        for file in files.filter_map(|e| e.ok()) {
+            let path = file.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("jsonl") {
+                continue;
+            }
+            if let Some(trace) = parse_session(&path)
+                && within_repo(&trace.workspace, repo_root)
+            {
+                traces.push(trace);
+            }
+        }
+    }
+    traces
+}
+
+fn parse_session(path: &Path) -> Option<Trace> {
+    let file = std::fs::File::open(path).ok()?;
+    let mut workspace: Option<PathBuf> = None;
+    let mut session_id: Option<String> = None;
+    let mut turns = Vec::new();
+
+    for line in std::io::BufReader::new(file).lines() {
+        let Ok(line) = line else { continue };
+        let Ok(entry) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        if workspace.is_none()
+            && let Some(cwd) = entry.get("cwd").and_then(|v| v.as_str())
+        {
+            workspace = Some(PathBuf::from(cwd));
+        }
+        if session_id.is_none() {
+            session_id = entry
+                .get("sessionId")
+                .and_then(|v| v.as_str())
+                .map(str::to_string);
+        }
+        if entry.get("isMeta").and_then(|v| v.as_bool()) == Some(true) {
+            continue;
+        }
+        let timestamp = entry.get("timestamp").and_then(parse_timestamp);
+        let role = match entry.get("type").and_then(|v| v.as_str()) {
+            Some("user") => Role::User,
+            Some("assistant") => Role::Assistant,
+            _ => continue,
+        };
+        let Some(content) = entry.get("message").and_then(|m| m.get("content")) else {
+            continue;
+        };
+
+        match content {
+            Value::String(text) => push_turn(&mut turns, role, timestamp, text.clone()),
+            Value::Array(blocks) => {
+                let mut text = Vec::new();
+                for block in blocks {
+                    match block.get("type").and_then(|v| v.as_str()) {
+                        Some("text") => {
+                            if let Some(t) = block.get("text").and_then(|v| v.as_str()) {
+                                text.push(t.to_string());
+                            }
+                        }
+                        Some("tool_use") => push_tool_call(
+                            &mut turns,
+                            timestamp,
+                            ToolCall {
+                                id: block.get("id").and_then(|v| v.as_str()).map(str::to_string),
+                                name: block
+                                    .get("name")
+                                    .and_then(|v| v.as_str())
+                                    .unwrap_or("tool")
+                                    .to_string(),
+                                input: block.get("input").map(value_text).unwrap_or_default(),
+                                output: None,
+                            },
+                        ),
+                        Some("tool_result") => attach_tool_output(
+                            &mut turns,
+                            block.get("tool_use_id").and_then(|v| v.as_str()),
+                            block.get("content").map(value_text).unwrap_or_default(),
+                        ),
+                        _ => {}
+                    }
+                }
+                if !text.is_empty() {
+                    push_turn(&mut turns, role, timestamp, text.join("\n"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Some(Trace {
+        source: TraceSource::ClaudeCode,
+        session_id: session_id
+            .or_else(|| path.file_stem().map(|s| s.to_string_lossy().to_string()))?,
+        path: path.to_path_buf(),
+        workspace: workspace?,
+        fidelity: Fidelity::FullTranscript,
+        turns,
+        file_changes: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn discovers_sessions_for_repo_and_normalizes_tool_calls() {
+        let home = TempDir::new().unwrap();
+        let repo = Path::new("/work/my.repo");
+        let dir = home.path().join("projects").join("-work-my-repo");
+        std::fs::create_dir_all(&dir).unwrap();
+        let lines = [
+            r#"{"type":"user","cwd":"/work/my.repo","sessionId":"s-1","timestamp":"2026-10-01T10:00:00Z","message":{"role":"user","content":"fix the build"}}"#,
+            r#"{"type":"assistant","cwd":"/work/my.repo","sessionId":"s-1","timestamp":"2026-10-01T10:00:05Z","message":{"role":"assistant","content":[{"type":"text","text":"Running cargo."},{"type":"tool_use","id":"tu1","name":"Bash","input":{"command":"cargo build"}}]}}"#,
+            r#"{"type":"user","cwd":"/work/my.repo","sessionId":"s-1","timestamp":"2026-10-01T10:00:09Z","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"tu1","content":"error[E0308]"}]}}"#,
+            r#"{"type":"user","isMeta":true,"cwd":"/work/my.repo","message":{"role":"user","content":"<command-name>/clear</command-name>"}}"#,
+            "not json",
+        ];
+        std::fs::write(dir.join("s-1.jsonl"), lines.join("\n")).unwrap();
+
+        // A sibling repo sharing the prefix must not match.
+        let other = home.path().join("projects").join("-work-my-repo-other");
+        std::fs::create_dir_all(&other).unwrap();
+        std::fs::write(
+            other.join("s-2.jsonl"),
+            r#"{"type":"user","cwd":"/work/my.repo-other","sessionId":"s-2","message":{"role":"user","content":"hi"}}"#,
+        )
+        .unwrap();
+
+        let traces = discover(home.path(), repo);
+        assert_eq!(traces.len(), 1);
+        let trace = &traces[0];
+        assert_eq!(trace.id(), "claude-code:s-1");
+        assert_eq!(trace.fidelity, Fidelity::FullTranscript);
+        assert_eq!(trace.turns.len(), 2);
+        assert_eq!(trace.turns[0].text, "fix the build");
+        let call = &trace.turns[1].tool_calls[0];
+        assert_eq!(call.name, "Bash");
+        assert!(call.input.contains("cargo build"));
+        assert_eq!(call.output.as_deref(), Some("error[E0308]"));
+    }
+}
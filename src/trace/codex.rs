@@ -0,0 +1,199 @@
+//! Codex adapter: `~/.codex/sessions/YYYY/MM/DD/rollout-*.jsonl`.
+
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde_json::Value;
+use walkdir::WalkDir;
+
+use super::{
+    Fidelity, Role, ToolCall, Trace, TraceSource, attach_tool_output, parse_timestamp,
+    push_tool_call, push_turn, value_text, within_repo,
+};
+
+static CWD_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<cwd>([^<]+)</cwd>").unwrap());
+
+pub(super) fn discover(store: &Path, repo_root: &Path) -> Vec<Trace> {
+    WalkDir::new(store.join("sessions"))
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| {
+            e.file_type().is_file()
+                && e.file_name().to_string_lossy().starts_with("rollout-")
+                && e.path().extension().and_then(|x| x.to_str()) == Some("jsonl")
+        })
+        .filter_map(|e| parse_session(e.path()))
+        .filter(|t| within_repo(&t.workspace, repo_root))
+        .collect()
+}
+
+fn parse_session(path: &Path) -> Option<Trace> {
+    let file = std::fs::File::open(path).ok()?;
+    let mut workspace: Option<PathBuf> = None;
+    let mut session_id: Option<String> = None;
+    let mut turns = Vec::new();
+    let mut saw_tool_calls = false;
+
+    for line in std::io::BufReader::new(file).lines() {
+        let Ok(line) = line else { continue };
+        let Ok(entry) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        let timestamp = entry.get("timestamp").and_then(parse_timestamp);
+        // Newer rollouts wrap each record in `{type, payload}`; older ones
+        // write the record itself.
+        let item = entry.get("payload").unwrap_or(&entry);
+
+        if entry.get("type").and_then(|v| v.as_str()) == Some("session_meta") {
+            session_id = item.get("id").and_then(|v| v.as_str()).map(str::to_string);
+            workspace = item.get("cwd").and_then(|v| v.as_str()).map(PathBuf::from);
+            continue;
+        }
+        if session_id.is_none()
+            && entry.get("instructions").is_some()
+            && let Some(id) = entry.get("id").and_then(|v| v.as_str())
+        {
+            session_id = Some(id.to_string());
+            continue;
+        }
+
+        match item.get("type").and_then(|v| v.as_str()) {
+            Some("message") => {
+                let role = match item.get("role").and_then(|v| v.as_str()) {
+                    Some("user") => Role::User,
+                    Some("assistant") => Role::Assistant,
+                    _ => continue,
+                };
+                let text = item.get("content").map(value_text).unwrap_or_default();
+                // Environment and instruction preambles are injected by the
+                // harness, not typed by the user.
+                if text.trim_start().starts_with("<environment_context>")
+                    || text.trim_start().starts_with("<user_instructions>")
+                {
+                    if workspace.is_none()
+                        && let Some(cap) = CWD_TAG.captures(&text)
+                    {
+                        workspace = Some(PathBuf::from(cap[1].trim()));
+                    }
+                    continue;
+                }
+                push_turn(&mut turns, role, timestamp, text);
+            }
+            Some("function_call") | Some("local_shell_call") | Some("custom_tool_call") => {
+                saw_tool_calls = true;
+                let input = item
+                    .get("arguments")
+                    .or_else(|| item.get("input"))
+                    .or_else(|| item.get("action"))
+                    .map(value_text)
+                    .unwrap_or_default();
+                push_tool_call(
+                    &mut turns,
+                    timestamp,
+                    ToolCall {
+                        id: item
+                            .get("call_id")
+                            .and_then(|v| v.as_str())
+                            .map(str::to_string),
+                        name: item
+                            .get("name")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("shell")
+                            .to_string(),
+                        input,
+                        output: None,
+                    },
+                );
+            }
+            Some("function_call_output") | Some("custom_tool_call_output") => {
+                let output = match item.get("output") {
+                    Some(Value::Object(o)) => o.get("output").map(value_text).unwrap_or_default(),
+                    Some(other) => value_text(other),
+                    None => String::new(),
+                };
+                attach_tool_output(
+                    &mut turns,
+                    item.get("call_id").and_then(|v| v.as_str()),
+                    output,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    Some(Trace {
+        source: TraceSource::Codex,
+        session_id: session_id
+            .or_else(|| path.file_stem().map(|s| s.to_string_lossy().to_string()))?,
+        path: path.to_path_buf(),
+        workspace: workspace?,
+        fidelity: if saw_tool_calls {
+            Fidelity::FullTranscript
+        } else {
+            Fidelity::PartialTranscript
+        },
+        turns,
+        file_changes: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_rollout(home: &Path, name: &str, lines: &[&str]) {
+        let dir = home.join("sessions/2026/10/01");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(name), lines.join("\n")).unwrap();
+    }
+
+    #[test]
+    fn parses_wrapped_rollout_with_tool_calls() {
+        let home = TempDir::new().unwrap();
+        write_rollout(
+            home.path(),
+            "rollout-2026-10-01T10-00-00-abc.jsonl",
+            &[
+                r#"{"timestamp":"2026-10-01T10:00:00Z","type":"session_meta","payload":{"id":"abc","cwd":"/work/repo"}}"#,
+                r#"{"timestamp":"2026-10-01T10:00:01Z","type":"response_item","payload":{"type":"message","role":"user","content":[{"type":"input_text","text":"<environment_context><cwd>/work/repo</cwd></environment_context>"}]}}"#,
+                r#"{"timestamp":"2026-10-01T10:00:02Z","type":"response_item","payload":{"type":"message","role":"user","content":[{"type":"input_text","text":"add a test"}]}}"#,
+                r#"{"timestamp":"2026-10-01T10:00:03Z","type":"response_item","payload":{"type":"function_call","name":"shell","arguments":"{\"command\":[\"cargo\",\"test\"]}","call_id":"c1"}}"#,
+                r#"{"timestamp":"2026-10-01T10:00:04Z","type":"response_item","payload":{"type":"function_call_output","call_id":"c1","output":"{\"output\":\"ok\"}"}}"#,
+                r#"{"timestamp":"2026-10-01T10:00:05Z","type":"response_item","payload":{"type":"message","role":"assistant","content":[{"type":"output_text","text":"Done."}]}}"#,
+            ],
+        );
+
+        let traces = discover(home.path(), Path::new("/work/repo"));
+        assert_eq!(traces.len(), 1);
+        let trace = &traces[0];
+        assert_eq!(trace.id(), "codex:abc");
+        assert_eq!(trace.fidelity, Fidelity::FullTranscript);
+        assert_eq!(trace.turns[0].text, "add a test");
+        assert_eq!(trace.turns[1].tool_calls[0].name, "shell");
+        assert!(trace.turns[1].tool_calls[0].output.is_some());
+        assert_eq!(trace.turns[1].text, "Done.");
+    }
+
+    #[test]
+    fn legacy_rollout_without_tools_is_partial_and_filtered_by_cwd() {
+        let home = TempDir::new().unwrap();
+        write_rollout(
+            home.path(),
+            "rollout-legacy.jsonl",
+            &[
+                r#"{"id":"legacy-1","timestamp":"2026-09-01T09:00:00Z","instructions":""}"#,
+                r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"<environment_context>\n<cwd>/work/repo/sub</cwd>\n</environment_context>"}]}"#,
+                r#"{"type":"message","role":"user","content":[{"type":"input_text","text":"explain this"}]}"#,
+            ],
+        );
+
+        let traces = discover(home.path(), Path::new("/work/repo"));
+        assert_eq!(traces.len(), 1);
+        assert_eq!(traces[0].session_id, "legacy-1");
+        assert_eq!(traces[0].fidelity, Fidelity::PartialTranscript);
+        assert!(discover(home.path(), Path::new("/elsewhere")).is_empty());
+    }
+}
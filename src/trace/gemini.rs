@@ -0,0 +1,212 @@
+//! Gemini CLI adapter: `~/.gemini/tmp/<sha256(project-root)>/`.
+//!
+//! Saved chats under `chats/` carry messages and tool calls. When only the
+//! prompt log (`logs.json`) exists, sessions are rebuilt from user prompts as
+//! partial transcripts.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::{
+    Fidelity, Role, ToolCall, Trace, TraceSource, parse_timestamp, push_tool_call, push_turn,
+    value_text,
+};
+
+fn project_hash(path: &Path) -> String {
+    hex::encode(Sha256::digest(path.to_string_lossy().as_bytes()))
+}
+
+pub(super) fn discover(store: &Path, repo_root: &Path) -> Vec<Trace> {
+    let mut roots = vec![repo_root.to_path_buf()];
+    if let Ok(canonical) = std::fs::canonicalize(repo_root)
+        && canonical != repo_root
+    {
+        roots.push(canonical);
+    }
+
+    let mut traces = Vec::new();
+    for root in roots {
+        let dir = store.join("tmp").join(project_hash(&root));
+        let chats: Vec<Trace> = std::fs::read_dir(dir.join("chats"))
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().and_then(|x| x.to_str()) == Some("json"))
+            .filter_map(|p| parse_chat(&p, &root))
+            .collect();
+        if chats.is_empty() {
+            traces.extend(parse_logs(&dir.join("logs.json"), &root));
+        } else {
+            traces.extend(chats);
+        }
+    }
+    traces
+}
+
+fn parse_chat(path: &Path, workspace: &Path) -> Option<Trace> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let chat: Value = serde_json::from_str(&content).ok()?;
+    let mut turns = Vec::new();
+    let mut saw_tool_calls = false;
+
+    for message in chat.get("messages")?.as_array()? {
+        let timestamp = message.get("timestamp").and_then(parse_timestamp);
+        let role = match message.get("type").and_then(|v| v.as_str()) {
+            Some("user") => Role::User,
+            Some("gemini") | Some("model") => Role::Assistant,
+            _ => continue,
+        };
+        let text = message.get("content").map(value_text).unwrap_or_default();
+        push_turn(&mut turns, role, timestamp, text);
+        for call in message
+            .get("toolCalls")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+        {
+            saw_tool_calls = true;
+            push_tool_call(
+                &mut turns,
+                timestamp,
+                ToolCall {
+                    id: call.get("id").and_then(|v| v.as_str()).map(str::to_string),
+                    name: call
+                        .get("name")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("tool")
+                        .to_string(),
+                    input: call.get("args").map(value_text).unwrap_or_default(),
+                    output: call
+                        .get("resultDisplay")
+                        .or_else(|| call.get("result"))
+                        .map(value_text),
+                },
+            );
+        }
+    }
+
+    Some(Trace {
+        source: TraceSource::Gemini,
+        session_id: chat
+            .get("sessionId")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .or_else(|| path.file_stem().map(|s| s.to_string_lossy().to_string()))?,
+        path: path.to_path_buf(),
+        workspace: workspace.to_path_buf(),
+        fidelity: if saw_tool_calls {
+            Fidelity::FullTranscript
+        } else {
+            Fidelity::PartialTranscript
+        },
+        turns,
+        file_changes: Vec::new(),
+    })
+}
+
+fn parse_logs(path: &Path, workspace: &Path) -> Vec<Trace> {
+    let Some(entries) = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|c| serde_json::from_str::<Vec<Value>>(&c).ok())
+    else {
+        return Vec::new();
+    };
+
+    let mut sessions: BTreeMap<String, Vec<super::Turn>> = BTreeMap::new();
+    for entry in entries {
+        let Some(session) = entry.get("sessionId").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        if entry.get("type").and_then(|v| v.as_str()) != Some("user") {
+            continue;
+        }
+        let turns = sessions.entry(session.to_string()).or_default();
+        push_turn(
+            turns,
+            Role::User,
+            entry.get("timestamp").and_then(parse_timestamp),
+            entry.get("message").map(value_text).unwrap_or_default(),
+        );
+    }
+
+    sessions
+        .into_iter()
+        .map(|(session_id, turns)| Trace {
+            source: TraceSource::Gemini,
+            session_id,
+            path: PathBuf::from(path),
+            workspace: workspace.to_path_buf(),
+            fidelity: Fidelity::PartialTranscript,
+            turns,
+            file_changes: Vec::new(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn reads_chats_for_hashed_project_dir() {
+        let home = TempDir::new().unwrap();
+        let repo = Path::new("/work/repo");
+        let chats = home
+            .path()
+            .join("tmp")
+            .join(project_hash(repo))
+            .join("chats");
+        std::fs::create_dir_all(&chats).unwrap();
+        std::fs::write(
+            chats.join("session-2026-10-01.json"),
+            r#"{"sessionId":"g-1","messages":[
+                {"type":"user","timestamp":"2026-10-01T10:00:00Z","content":"list files"},
+                {"type":"gemini","timestamp":"2026-10-01T10:00:02Z","content":"Listing.",
+                 "toolCalls":[{"id":"t1","name":"list_directory","args":{"path":"."},"resultDisplay":"src/"}]},
+                {"type":"info","content":"ignored"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let traces = discover(home.path(), repo);
+        assert_eq!(traces.len(), 1);
+        assert_eq!(traces[0].id(), "gemini:g-1");
+        assert_eq!(traces[0].fidelity, Fidelity::FullTranscript);
+        assert_eq!(traces[0].turns.len(), 2);
+        assert_eq!(
+            traces[0].turns[1].tool_calls[0].output.as_deref(),
+            Some("src/")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_prompt_log_as_partial_transcripts() {
+        let home = TempDir::new().unwrap();
+        let repo = Path::new("/work/repo");
+        let dir = home.path().join("tmp").join(project_hash(repo));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("logs.json"),
+            r#"[
+                {"sessionId":"a","messageId":0,"type":"user","message":"first","timestamp":"2026-10-01T10:00:00Z"},
+                {"sessionId":"b","messageId":0,"type":"user","message":"second","timestamp":"2026-10-02T10:00:00Z"},
+                {"sessionId":"a","messageId":1,"type":"user","message":"again","timestamp":"2026-10-01T10:05:00Z"}
+            ]"#,
+        )
+        .unwrap();
+
+        let traces = discover(home.path(), repo);
+        assert_eq!(traces.len(), 2);
+        assert!(
+            traces
+                .iter()
+                .all(|t| t.fidelity == Fidelity::PartialTranscript)
+        );
+        assert_eq!(traces[0].turns.len(), 2);
+    }
+}
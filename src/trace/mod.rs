@@ -0,0 +1,574 @@
+//! Discovery and normalization of local agent session traces.
+//!
+//! Each supported tool keeps its own on-disk session store. Source adapters
+//! read those stores and normalize sessions that ran inside the current
+//! repository into a common [`Trace`] model: ordered turns with their tool
+//! calls, or — for tools that only record file edits — a list of file changes.
+
+mod amp;
+mod claude;
+mod codex;
+mod gemini;
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+
+/// Tool outputs longer than this are truncated when rendering transcripts.
+const TOOL_OUTPUT_LIMIT: usize = 400;
+/// Tool inputs longer than this are truncated when rendering transcripts.
+const TOOL_INPUT_LIMIT: usize = 200;
+/// Diffs longer than this are truncated when rendering diff-only traces.
+const DIFF_LIMIT: usize = 1_500;
+
+/// Agent tool that produced a trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TraceSource {
+    ClaudeCode,
+    Codex,
+    Gemini,
+    Amp,
+}
+
+impl TraceSource {
+    pub fn name(self) -> &'static str {
+        match self {
+            TraceSource::ClaudeCode => "claude-code",
+            TraceSource::Codex => "codex",
+            TraceSource::Gemini => "gemini",
+            TraceSource::Amp => "amp",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            TraceSource::ClaudeCode,
+            TraceSource::Codex,
+            TraceSource::Gemini,
+            TraceSource::Amp,
+        ]
+        .into_iter()
+        .find(|s| s.name() == name)
+    }
+}
+
+/// How much of the session a trace captures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Fidelity {
+    /// File-change evidence only; no conversation was recorded.
+    DiffOnly,
+    /// Conversation text without tool-call structure.
+    PartialTranscript,
+    /// User, assistant, and tool-call events.
+    FullTranscript,
+}
+
+impl Fidelity {
+    pub fn name(self) -> &'static str {
+        match self {
+            Fidelity::FullTranscript => "full-transcript",
+            Fidelity::PartialTranscript => "partial-transcript",
+            Fidelity::DiffOnly => "diff-only",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    User,
+    Assistant,
+}
+
+/// A tool invocation made during an assistant turn.
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolCall {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    pub input: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+}
+
+/// One user or assistant message.
+#[derive(Debug, Clone, Serialize)]
+pub struct Turn {
+    pub role: Role,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime<Utc>>,
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tool_calls: Vec<ToolCall>,
+}
+
+/// A file edit recorded by a diff-only source.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileChange {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime<Utc>>,
+    pub diff: String,
+}
+
+/// A normalized session from a local agent tool store.
+#[derive(Debug, Clone, Serialize)]
+pub struct Trace {
+    pub source: TraceSource,
+    pub session_id: String,
+    /// Session log this trace was read from.
+    pub path: PathBuf,
+    /// Working directory the session ran in.
+    pub workspace: PathBuf,
+    pub fidelity: Fidelity,
+    pub turns: Vec<Turn>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub file_changes: Vec<FileChange>,
+}
+
+impl Trace {
+    /// Stable identifier: `<source>:<session-id>`.
+    pub fn id(&self) -> String {
+        format!("{}:{}", self.source.name(), self.session_id)
+    }
+
+    fn timestamps(&self) -> impl Iterator<Item = DateTime<Utc>> + '_ {
+        self.turns
+            .iter()
+            .filter_map(|t| t.timestamp)
+            .chain(self.file_changes.iter().filter_map(|c| c.timestamp))
+    }
+
+    pub fn started(&self) -> Option<DateTime<Utc>> {
+        self.timestamps().min()
+    }
+
+    /// Time of the last recorded event, falling back to the log's mtime.
+    pub fn ended(&self) -> Option<DateTime<Utc>> {
+        self.timestamps().max().or_else(|| {
+            std::fs::metadata(&self.path)
+                .and_then(|m| m.modified())
+                .ok()
+                .map(DateTime::<Utc>::from)
+        })
+    }
+
+    pub fn tool_call_count(&self) -> usize {
+        self.turns.iter().map(|t| t.tool_calls.len()).sum()
+    }
+
+    /// First user message, shortened for listings.
+    pub fn title(&self) -> Option<String> {
+        let first = self
+            .turns
+            .iter()
+            .find(|t| t.role == Role::User && !t.text.trim().is_empty())?;
+        let line = first.text.trim().lines().next().unwrap_or_default();
+        Some(shorten(line, 60))
+    }
+}
+
+/// Directory that tool stores are resolved against.
+///
+/// `WAI_TRACE_HOME` replaces the home directory for every source, which keeps
+/// discovery away from real stores in tests and lets users point wai at a
+/// copied store.
+fn trace_home() -> Option<(PathBuf, bool)> {
+    if let Some(dir) = std::env::var_os("WAI_TRACE_HOME") {
+        return Some((PathBuf::from(dir), true));
+    }
+    dirs::home_dir().map(|h| (h, false))
+}
+
+/// Resolve a tool's store directory, honouring the tool's own override
+/// variable unless `WAI_TRACE_HOME` is set.
+fn store_dir(default_subdir: &str, tool_env: Option<&str>) -> Option<PathBuf> {
+    let (home, overridden) = trace_home()?;
+    if !overridden
+        && let Some(var) = tool_env
+        && let Some(dir) = std::env::var_os(var)
+    {
+        return Some(PathBuf::from(dir));
+    }
+    Some(home.join(default_subdir))
+}
+
+/// Whether `workspace` is the repository root or a directory inside it.
+fn within_repo(workspace: &Path, repo_root: &Path) -> bool {
+    let canonical = |p: &Path| std::fs::canonicalize(p).unwrap_or_else(|_| p.to_path_buf());
+    workspace.starts_with(repo_root) || canonical(workspace).starts_with(canonical(repo_root))
+}
+
+/// Discover all traces recorded for `repo_root`, newest first.
+pub fn discover_traces(repo_root: &Path) -> Vec<Trace> {
+    let mut traces = Vec::new();
+    if let Some(dir) = store_dir(".claude", Some("CLAUDE_CONFIG_DIR")) {
+        traces.extend(claude::discover(&dir, repo_root));
+    }
+    if let Some(dir) = store_dir(".codex", Some("CODEX_HOME")) {
+        traces.extend(codex::discover(&dir, repo_root));
+    }
+    if let Some(dir) = store_dir(".gemini", None) {
+        traces.extend(gemini::discover(&dir, repo_root));
+    }
+    if let Some(dir) = store_dir(".amp", None) {
+        traces.extend(amp::discover(&dir, repo_root));
+    }
+    traces.sort_by_key(|t| std::cmp::Reverse(t.ended()));
+    traces
+}
+
+/// Find a trace by full id (`source:session`) or a unique session-id prefix.
+pub fn find_trace<'a>(traces: &'a [Trace], query: &str) -> Result<&'a Trace, String> {
+    if let Some(t) = traces.iter().find(|t| t.id() == query) {
+        return Ok(t);
+    }
+    let (source, prefix) = match query.split_once(':') {
+        Some((s, p)) => (TraceSource::from_name(s), p),
+        None => (None, query),
+    };
+    let matches: Vec<&Trace> = traces
+        .iter()
+        .filter(|t| source.is_none_or(|s| t.source == s) && t.session_id.starts_with(prefix))
+        .collect();
+    match matches.as_slice() {
+        [one] => Ok(one),
+        [] => Err(format!("no trace matches '{}'", query)),
+        many => Err(format!(
+            "'{}' matches {} traces: {}",
+            query,
+            many.len(),
+            many.iter().map(|t| t.id()).collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+/// Most recent traces worth feeding to `wai reflect`.
+///
+/// Only sessions that ended on or after `since` are considered. Transcript
+/// traces are preferred; diff-only traces are used only when nothing richer
+/// is available.
+pub fn select_for_reflect(traces: &[Trace], since: Option<NaiveDate>, max: usize) -> Vec<&Trace> {
+    let recent: Vec<&Trace> = traces
+        .iter()
+        .filter(|t| match (since, t.ended()) {
+            (Some(since), Some(ended)) => ended.date_naive() >= since,
+            _ => true,
+        })
+        .filter(|t| !t.turns.is_empty() || !t.file_changes.is_empty())
+        .collect();
+    let transcripts: Vec<&Trace> = recent
+        .iter()
+        .copied()
+        .filter(|t| t.fidelity != Fidelity::DiffOnly)
+        .collect();
+    let pool = if transcripts.is_empty() {
+        recent
+    } else {
+        transcripts
+    };
+    pool.into_iter().take(max).collect()
+}
+
+/// Render a trace as plain text suitable for an LLM prompt or an imported
+/// resource. Diff-only traces list file changes and never invent dialogue.
+pub fn render_trace(trace: &Trace) -> String {
+    let mut out = String::new();
+    if trace.fidelity == Fidelity::DiffOnly {
+        out.push_str(
+            "Reduced-fidelity trace (diff-only): file changes only, no conversation was recorded.\n",
+        );
+        for change in &trace.file_changes {
+            out.push_str(&format!(
+                "\n[{}] {}\n{}\n",
+                format_time(change.timestamp),
+                change.path,
+                shorten(change.diff.trim_end(), DIFF_LIMIT)
+            ));
+        }
+        return out;
+    }
+
+    for turn in &trace.turns {
+        let role = match turn.role {
+            Role::User => "user",
+            Role::Assistant => "assistant",
+        };
+        out.push_str(&format!("\n[{}] {}:", format_time(turn.timestamp), role));
+        let text = turn.text.trim();
+        if text.contains('\n') {
+            out.push('\n');
+            out.push_str(text);
+            out.push('\n');
+        } else if !text.is_empty() {
+            out.push(' ');
+            out.push_str(text);
+            out.push('\n');
+        } else {
+            out.push('\n');
+        }
+        for call in &turn.tool_calls {
+            out.push_str(&format!(
+                "  -> {}: {}\n",
+                call.name,
+                shorten(&single_line(&call.input), TOOL_INPUT_LIMIT)
+            ));
+            if let Some(output) = &call.output {
+                out.push_str(&format!(
+                    "  <- {}\n",
+                    shorten(&single_line(output), TOOL_OUTPUT_LIMIT)
+                ));
+            }
+        }
+    }
+    out
+}
+
+pub fn format_time(ts: Option<DateTime<Utc>>) -> String {
+    ts.map(|t| {
+        t.with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+    })
+    .unwrap_or_else(|| "unknown time".to_string())
+}
+
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn shorten(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(max_chars).collect();
+    format!("{}…", cut)
+}
+
+/// Parse an RFC 3339 string or a Unix timestamp in milliseconds.
+fn parse_timestamp(value: &serde_json::Value) -> Option<DateTime<Utc>> {
+    match value {
+        serde_json::Value::String(s) => DateTime::parse_from_rfc3339(s)
+            .ok()
+            .map(|t| t.with_timezone(&Utc)),
+        serde_json::Value::Number(n) => DateTime::from_timestamp_millis(n.as_i64()?),
+        _ => None,
+    }
+}
+
+/// Compact representation of a tool input or output value.
+fn value_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(|item| match item.get("text").and_then(|t| t.as_str()) {
+                Some(text) => text.to_string(),
+                None => value_text(item),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// Attach `output` to the call with `id`, or to the newest call still waiting
+/// for a result.
+fn attach_tool_output(turns: &mut [Turn], id: Option<&str>, output: String) {
+    let calls = turns.iter_mut().rev().flat_map(|t| t.tool_calls.iter_mut());
+    let mut pending = calls.filter(|c| c.output.is_none());
+    let target = match id {
+        Some(id) => pending.find(|c| c.id.as_deref() == Some(id)),
+        None => pending.next(),
+    };
+    if let Some(call) = target {
+        call.output = Some(output);
+    }
+}
+
+/// Push text onto the last turn if it has the same role, else start a new one.
+fn push_turn(turns: &mut Vec<Turn>, role: Role, timestamp: Option<DateTime<Utc>>, text: String) {
+    if let Some(last) = turns.last_mut()
+        && last.role == role
+        && role == Role::Assistant
+    {
+        if !text.is_empty() {
+            if !last.text.is_empty() {
+                last.text.push('\n');
+            }
+            last.text.push_str(&text);
+        }
+        return;
+    }
+    turns.push(Turn {
+        role,
+        timestamp,
+        text,
+        tool_calls: Vec::new(),
+    });
+}
+
+/// Append a tool call to the current assistant turn, starting one if needed.
+fn push_tool_call(turns: &mut Vec<Turn>, timestamp: Option<DateTime<Utc>>, call: ToolCall) {
+    if turns.last().is_none_or(|t| t.role != Role::Assistant) {
+        push_turn(turns, Role::Assistant, timestamp, String::new());
+    }
+    if let Some(last) = turns.last_mut() {
+        last.tool_calls.push(call);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(s: &str) -> Option<DateTime<Utc>> {
+        parse_timestamp(&serde_json::Value::String(s.to_string()))
+    }
+
+    fn trace(source: TraceSource, id: &str, fidelity: Fidelity, when: &str) -> Trace {
+        Trace {
+            source,
+            session_id: id.to_string(),
+            path: PathBuf::from("/nonexistent"),
+            workspace: PathBuf::from("/repo"),
+            fidelity,
+            turns: vec![Turn {
+                role: Role::User,
+                timestamp: ts(when),
+                text: "hello".to_string(),
+                tool_calls: Vec::new(),
+            }],
+            file_changes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn find_trace_accepts_full_id_and_unique_prefix() {
+        let traces = vec![
+            trace(
+                TraceSource::ClaudeCode,
+                "abc123",
+                Fidelity::FullTranscript,
+                "2026-10-01T10:00:00Z",
+            ),
+            trace(
+                TraceSource::Codex,
+                "abd456",
+                Fidelity::FullTranscript,
+                "2026-10-02T10:00:00Z",
+            ),
+        ];
+        assert_eq!(
+            find_trace(&traces, "codex:abd456").unwrap().session_id,
+            "abd456"
+        );
+        assert_eq!(find_trace(&traces, "abc").unwrap().session_id, "abc123");
+        assert_eq!(
+            find_trace(&traces, "claude-code:ab").unwrap().session_id,
+            "abc123"
+        );
+        assert!(find_trace(&traces, "ab").unwrap_err().contains("matches 2"));
+        assert!(find_trace(&traces, "zzz").is_err());
+    }
+
+    #[test]
+    fn select_for_reflect_prefers_recent_transcripts() {
+        let mut diff = trace(
+            TraceSource::Amp,
+            "t-1",
+            Fidelity::DiffOnly,
+            "2026-10-05T10:00:00Z",
+        );
+        diff.turns.clear();
+        diff.file_changes.push(FileChange {
+            path: "src/lib.rs".to_string(),
+            timestamp: ts("2026-10-05T10:00:00Z"),
+            diff: "+x".to_string(),
+        });
+        let traces = vec![
+            diff,
+            trace(
+                TraceSource::ClaudeCode,
+                "new",
+                Fidelity::FullTranscript,
+                "2026-10-04T10:00:00Z",
+            ),
+            trace(
+                TraceSource::Codex,
+                "old",
+                Fidelity::FullTranscript,
+                "2026-09-01T10:00:00Z",
+            ),
+        ];
+
+        let since = NaiveDate::from_ymd_opt(2026, 10, 1);
+        let picked = select_for_reflect(&traces, since, 3);
+        assert_eq!(picked.len(), 1);
+        assert_eq!(picked[0].session_id, "new");
+
+        // Only diff-only evidence left → use it rather than nothing.
+        let picked = select_for_reflect(&traces[..1], since, 3);
+        assert_eq!(picked[0].fidelity, Fidelity::DiffOnly);
+    }
+
+    #[test]
+    fn render_diff_only_trace_has_no_dialogue() {
+        let trace = Trace {
+            source: TraceSource::Amp,
+            session_id: "T-1".to_string(),
+            path: PathBuf::from("/nonexistent"),
+            workspace: PathBuf::from("/repo"),
+            fidelity: Fidelity::DiffOnly,
+            turns: Vec::new(),
+            file_changes: vec![FileChange {
+                path: "src/main.rs".to_string(),
+                timestamp: None,
+                diff: "-old\n+new".to_string(),
+            }],
+        };
+        let text = render_trace(&trace);
+        assert!(text.starts_with("Reduced-fidelity trace (diff-only)"));
+        assert!(text.contains("src/main.rs"));
+        assert!(!text.contains("user:"));
+        assert!(!text.contains("assistant:"));
+    }
+
+    #[test]
+    fn render_transcript_includes_tool_calls_and_outputs() {
+        let mut turns = Vec::new();
+        push_turn(&mut turns, Role::User, None, "run tests".to_string());
+        push_tool_call(
+            &mut turns,
+            None,
+            ToolCall {
+                id: Some("t1".to_string()),
+                name: "Bash".to_string(),
+                input: "{\"command\":\"cargo test\"}".to_string(),
+                output: None,
+            },
+        );
+        attach_tool_output(&mut turns, Some("t1"), "test result: ok".to_string());
+        push_turn(&mut turns, Role::Assistant, None, "All green.".to_string());
+
+        assert_eq!(turns.len(), 2);
+        let trace = Trace {
+            source: TraceSource::ClaudeCode,
+            session_id: "s".to_string(),
+            path: PathBuf::from("/nonexistent"),
+            workspace: PathBuf::from("/repo"),
+            fidelity: Fidelity::FullTranscript,
+            turns,
+            file_changes: Vec::new(),
+        };
+        let text = render_trace(&trace);
+        assert!(text.contains("user: run tests"));
+        assert!(text.contains("-> Bash: {\"command\":\"cargo test\"}"));
+        assert!(text.contains("<- test result: ok"));
+        assert!(text.contains("assistant: All green."));
+    }
+}
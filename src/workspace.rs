@@ -7,7 +7,8 @@ use crate::config::{
     TEMPLATES_DIR, agent_config_dir,
 };
 use crate::managed_block::{
-    InstalledPipeline, describe_inject_result, inject_managed_block, write_detailed_agents_file,
+    InstalledPipeline, describe_inject_result, inject_target_block, managed_targets,
+    write_detailed_agents_file,
 };
 use crate::plugin;
 
@@ -252,23 +253,24 @@ pub fn ensure_workspace_current(project_root: &Path) -> Result<Vec<WorkspaceActi
     // Collect installed pipelines with metadata for managed block
     let installed_pipelines = detect_installed_pipelines(project_root);
 
-    // Inject/update slim managed blocks in root agent instruction files
-    let agent_files = ["AGENTS.md", "CLAUDE.md"];
-    for filename in &agent_files {
-        let path = project_root.join(filename);
-
-        // For AGENTS.md and CLAUDE.md, always ensure they exist.
-        if filename == &"AGENTS.md" || filename == &"CLAUDE.md" || path.exists() {
-            match inject_managed_block(&path, &detected, &skill_name_refs, &installed_pipelines) {
-                Ok(result) => {
-                    actions.push(WorkspaceAction::new(describe_inject_result(
-                        &result, filename,
-                    )));
-                }
-                Err(e) => {
-                    // Log but don't fail - this is a best-effort operation
-                    eprintln!("Warning: Failed to update {}: {}", filename, e);
-                }
+    // Inject/update managed blocks in agent instruction files and any other
+    // targets configured under [managed_block.targets]
+    for target in managed_targets(project_root) {
+        match inject_target_block(
+            project_root,
+            &target,
+            &detected,
+            &skill_name_refs,
+            &installed_pipelines,
+        ) {
+            Ok(result) => {
+                actions.push(WorkspaceAction::new(describe_inject_result(
+                    &result, &target,
+                )));
+            }
+            Err(e) => {
+                // Log but don't fail - this is a best-effort operation
+                eprintln!("Warning: Failed to update {}: {}", target, e);
             }
         }
     }